@@ -0,0 +1,81 @@
+use aoc::grid::Grid;
+use aoc::solution::Result;
+use aoc::Solution;
+
+struct Day09;
+
+impl Day09 {
+    fn low_points(input: &Grid<u32>) -> impl Iterator<Item = ((usize, usize), &u32)> {
+        input.iter_with_pos().filter(|&((x, y), height)| {
+            input
+                .neighbors4(x, y)
+                .all(|neighbor| input[neighbor] > *height)
+        })
+    }
+
+    fn basin_size(input: &Grid<u32>, low_point: (usize, usize)) -> usize {
+        let mut seen = input.map(|_| false);
+        let mut stack = vec![low_point];
+        let mut size = 0;
+
+        while let Some((x, y)) = stack.pop() {
+            if seen[(x, y)] || input[(x, y)] == 9 {
+                continue;
+            }
+            seen[(x, y)] = true;
+            size += 1;
+            stack.extend(input.neighbors4(x, y));
+        }
+
+        size
+    }
+}
+
+impl Solution for Day09 {
+    const TITLE: &'static str = "Smoke Basin";
+    const DAY: u8 = 9;
+    type Input = Grid<u32>;
+    type P1 = u32;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::from_str(input, |c| c.to_digit(10))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(Self::low_points(input).map(|(_, height)| height + 1).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::P2> {
+        let mut sizes: Vec<_> = Self::low_points(input)
+            .map(|(low_point, _)| Self::basin_size(input, low_point))
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        (sizes.len() >= 3).then(|| sizes[..3].iter().product())
+    }
+
+    fn get_input() -> Result<String> {
+        // the example of the puzzle, to run without an input file
+        Ok("2199943210\n3987894921\n9856789892\n8767896789\n9899965678".to_owned())
+    }
+}
+
+fn main() {
+    aoc::solution!(Day09)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09 as day_09;
+    use crate::*;
+
+    aoc::test_common!(day_09);
+
+    aoc::test! {
+        day_09:
+        - "2199943210\n3987894921\n9856789892\n8767896789\n9899965678"
+            => Some(15)
+            => Some(1134)
+    }
+}
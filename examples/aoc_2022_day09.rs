@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use aoc::geom::{Direction, Point2};
+use aoc::solution::Result;
+use aoc::{ResultExt, Solution};
+
+struct Day09;
+
+impl Day09 {
+    /// Number of positions visited by the tail of a rope of `knots` knots.
+    fn tail_visits(motions: &[(Direction, u32)], knots: usize) -> usize {
+        let mut rope = vec![Point2::ORIGIN; knots];
+        let mut visited = HashSet::from([Point2::ORIGIN]);
+
+        for &(direction, steps) in motions {
+            for _ in 0..steps {
+                rope[0] += direction.delta();
+
+                for knot in 1..knots {
+                    let gap = rope[knot - 1] - rope[knot];
+                    if gap.x.abs() > 1 || gap.y.abs() > 1 {
+                        rope[knot] += gap.signum();
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+
+        visited.len()
+    }
+}
+
+impl Solution for Day09 {
+    const TITLE: &'static str = "Rope Bridge";
+    const DAY: u8 = 9;
+    type Input = Vec<(Direction, u32)>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (direction, steps) = line
+                    .split_once(' ')
+                    .or_parse_err_with(|| format!("no steps in {:?}", line))?;
+
+                Ok((direction.parse()?, steps.parse()?))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(Self::tail_visits(input, 2))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::P2> {
+        Some(Self::tail_visits(input, 10))
+    }
+
+    fn get_input() -> Result<String> {
+        // the larger example of the puzzle, to run without an input file
+        Ok("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20".to_owned())
+    }
+}
+
+fn main() {
+    aoc::solution!(Day09)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Day09 as day_09;
+    use crate::*;
+
+    aoc::test_common!(day_09);
+
+    aoc::test! {
+        day_09:
+        [small]
+        - "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2"
+            => Some(13)
+            => Some(1);
+        [large]
+        - "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20"
+            => Some(88)
+            => Some(36);
+    }
+}
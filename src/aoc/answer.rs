@@ -0,0 +1,168 @@
+//! An answer type for the days whose answer may be a number or a text
+//!
+//! Declare `type P1 = Answer` to return either, eg: while a part decoding ASCII art is being
+//! written. The known answers are compared as [Answer]s too. (See [crate::answers])
+
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A numeric or textual answer.
+///
+/// Numbers are equal across variants when their values are, and a text is equal to a number
+/// when it is written like the number displays. Parsing reads a number only when it displays
+/// back the same way, so `"042"` stays a text and every answer displays like it was parsed.
+///
+/// # Example
+/// ```
+/// use aoc::Answer;
+///
+/// assert_eq!("42".parse::<Answer>().unwrap(), Answer::Int(42));
+/// assert_eq!(Answer::from(42_u64), Answer::Int(42));
+/// assert_eq!(Answer::from("42"), Answer::UInt(42));
+/// assert_ne!(Answer::from("042"), Answer::Int(42));
+/// assert_eq!(Answer::from("RGZEHURK").to_string(), "RGZEHURK");
+/// ```
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => Display::fmt(n, f),
+            Answer::UInt(n) => Display::fmt(n, f),
+            Answer::Text(text) => Display::fmt(text, f),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            (Answer::UInt(a), Answer::UInt(b)) => a == b,
+            (Answer::Int(a), Answer::UInt(b)) | (Answer::UInt(b), Answer::Int(a)) => {
+                u64::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Text(text), number) | (number, Answer::Text(text)) => {
+                *text == number.to_string()
+            }
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = match (s.parse::<i64>(), s.parse::<u64>()) {
+            (Ok(n), _) => Answer::Int(n),
+            (_, Ok(n)) => Answer::UInt(n),
+            _ => return Ok(Answer::Text(s.to_owned())),
+        };
+
+        match answer.to_string() == s {
+            true => Ok(answer),
+            false => Ok(Answer::Text(s.to_owned())),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::UInt(n)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::UInt(n as u64)
+    }
+}
+
+impl From<&str> for Answer {
+    /// Same as [Answer::from_str].
+    fn from(s: &str) -> Self {
+        match s.parse() {
+            Ok(answer) => answer,
+            Err(infallible) => match infallible {},
+        }
+    }
+}
+
+impl From<String> for Answer {
+    /// Same as [Answer::from_str], without copying a text.
+    fn from(s: String) -> Self {
+        match Answer::from(s.as_str()) {
+            Answer::Text(_) => Answer::Text(s),
+            number => number,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert!(matches!(Answer::from(-7_i64), Answer::Int(-7)));
+        assert!(matches!(Answer::from(7_u64), Answer::UInt(7)));
+        assert!(matches!(Answer::from(7_usize), Answer::UInt(7)));
+        assert!(matches!(Answer::from("-7"), Answer::Int(-7)));
+        assert!(matches!(Answer::from("7"), Answer::Int(7)));
+        assert!(matches!(
+            Answer::from(u64::MAX.to_string()),
+            Answer::UInt(u64::MAX)
+        ));
+        assert!(matches!(Answer::from("ABC".to_owned()), Answer::Text(t) if t == "ABC"));
+
+        for text in ["", " 7", "+7", "07", "-0", "7.0", "99999999999999999999"] {
+            assert!(
+                matches!(Answer::from(text), Answer::Text(t) if t == text),
+                "{:?}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Answer::Int(42), Answer::UInt(42));
+        assert_eq!(Answer::UInt(42), Answer::Int(42));
+        assert_ne!(Answer::Int(-1), Answer::UInt(u64::MAX));
+        assert_eq!(Answer::Text("42".to_owned()), Answer::Int(42));
+        assert_eq!(Answer::UInt(42), Answer::Text("42".to_owned()));
+        assert_ne!(Answer::Text("042".to_owned()), Answer::Int(42));
+        assert_ne!(Answer::from("ABC"), Answer::from("abc"));
+        assert_eq!(Answer::from("ABC"), Answer::from("ABC".to_owned()));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in ["42", "-42", "18446744073709551615", "042", "", "#..#\n####"] {
+            assert_eq!(Answer::from(text).to_string(), text);
+        }
+
+        for answer in [
+            Answer::Int(i64::MIN),
+            Answer::UInt(u64::MAX),
+            Answer::from("PZGPKPEB"),
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+}
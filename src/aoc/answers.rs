@@ -0,0 +1,220 @@
+//! Known answers of the solved days, to catch a regression while refactoring
+//!
+//! `answers/DAY_07.txt` holds the accepted answers of day 7: part 1 on the first line, part 2
+//! on the second. Keep the directory out of version control, like the inputs:
+//!
+//! ```text
+//! 1792
+//! 3021
+//! ```
+//!
+//! Like the inputs, a day whose [crate::Solution::YEAR] is set prefers the per-year layout
+//! `answers/2022/DAY_07.txt`, and falls back to `answers/DAY_07.txt`.
+//!
+//! When the file exists (and [crate::Solution::VERIFY_ANSWERS] is left on),
+//! [crate::Solution::run] and [crate::Solution::run_par] compare their answers with it. The
+//! result displays a ✓ for a verified answer and a ✗ with the expected answer for a mismatch.
+//! (See [crate::solution::SolutionResult::verification])
+//!
+//! The answers can also live in the code with [crate::Solution::EXPECTED_P1] and
+//! [crate::Solution::EXPECTED_P2], which win over the file for their part.
+//!
+//! A mismatch is only displayed, unless `AOC_STRICT_ANSWERS=1` where [crate::solution!] and
+//! [crate::run_all!] report it as a [SolutionError::WrongAnswer].
+//! (See [crate::solution::SolutionResult::checked])
+
+use std::path::{Path, PathBuf};
+
+use crate::solution::{Result, SolutionError};
+use crate::Answer;
+
+/// Directory of the known answers, relative to the current directory.
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Environment variable failing a run whose answers don't match the known ones when set to `1`.
+pub const STRICT_ANSWERS_ENV: &str = "AOC_STRICT_ANSWERS";
+
+/// Comparison of an answer with the known one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Verification {
+    /// No known answer.
+    #[default]
+    Unknown,
+    /// The answer matches the known one.
+    Verified,
+    /// The answer doesn't match the known one, when it is recorded.
+    Mismatch { expected: Option<String> },
+}
+
+impl Verification {
+    /// Compares `actual` with `expected` as [Answer]s, ignoring the surrounding whitespace.
+    pub fn check(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match expected.map(str::trim) {
+            None => Verification::Unknown,
+            Some(expected) if actual.map(|a| Answer::from(a.trim())) == Some(expected.into()) => {
+                Verification::Verified
+            }
+            Some(expected) => Verification::Mismatch {
+                expected: Some(expected.to_owned()),
+            },
+        }
+    }
+
+    pub fn is_mismatch(&self) -> bool {
+        matches!(self, Verification::Mismatch { .. })
+    }
+}
+
+/// Known answers of a day. See the [module documentation](self)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl ExpectedAnswers {
+    /// Path of the known answers of `year`/`day`: `answers/2022/DAY_07.txt`, or
+    /// `answers/DAY_07.txt` when the year is unknown.
+    pub fn path(dir: &Path, day: u8, year: Option<u16>) -> PathBuf {
+        let file = format!("DAY_{:02}.txt", day);
+        match year {
+            Some(year) => dir.join(year.to_string()).join(file),
+            None => dir.join(file),
+        }
+    }
+
+    /// Loads the known answers of `year`/`day` from `dir`, from the first existing file of the
+    /// per-year and flat layouts. Empty when neither exists.
+    pub fn load(dir: &Path, day: u8, year: Option<u16>) -> Result<Self> {
+        let mut paths = vec![Self::path(dir, day, year)];
+        if year.is_some() {
+            paths.push(Self::path(dir, day, None));
+        }
+
+        for path in paths {
+            match std::fs::read_to_string(path) {
+                Ok(content) => return Ok(Self::parse(&content)),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(SolutionError::from(e)),
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    /// Reads the answers from the first two lines of `content`.
+    /// A missing or blank line is an unknown answer, and the whitespace around them is ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut lines = content
+            .lines()
+            .map(str::trim)
+            .map(|line| (!line.is_empty()).then(|| line.to_owned()));
+
+        ExpectedAnswers {
+            part1: lines.next().flatten(),
+            part2: lines.next().flatten(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+}
+
+/// Whether [STRICT_ANSWERS_ENV] is set to `1`.
+pub fn strict() -> bool {
+    std::env::var(STRICT_ANSWERS_ENV).is_ok_and(|value| value.trim() == "1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn parse_answers() {
+        assert_eq!(
+            ExpectedAnswers::parse("1792  \r\n3021\n"),
+            ExpectedAnswers {
+                part1: Some("1792".to_owned()),
+                part2: Some("3021".to_owned()),
+            }
+        );
+        assert_eq!(
+            ExpectedAnswers::parse("1792"),
+            ExpectedAnswers {
+                part1: Some("1792".to_owned()),
+                part2: None,
+            }
+        );
+        assert_eq!(
+            ExpectedAnswers::parse("\n  \nCRATES\n").part1,
+            None,
+            "blank lines are unknown answers"
+        );
+        assert!(ExpectedAnswers::parse("").is_empty());
+    }
+
+    #[test]
+    fn load_answers() {
+        let dir = temp_dir("answers-load");
+        std::fs::write(dir.join("DAY_07.txt"), "1792\n3021\n").unwrap();
+
+        assert_eq!(
+            ExpectedAnswers::load(&dir, 7, None)
+                .unwrap()
+                .part2
+                .as_deref(),
+            Some("3021")
+        );
+        assert!(ExpectedAnswers::load(&dir, 8, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn load_answers_by_year() {
+        let dir = temp_dir("answers-load-year");
+        std::fs::create_dir_all(dir.join("2022")).unwrap();
+        std::fs::write(dir.join("DAY_07.txt"), "1792\n3021\n").unwrap();
+        std::fs::write(dir.join("2022").join("DAY_07.txt"), "95437\n").unwrap();
+
+        assert_eq!(
+            ExpectedAnswers::path(&dir, 7, Some(2022)),
+            dir.join("2022").join("DAY_07.txt")
+        );
+        assert_eq!(
+            ExpectedAnswers::load(&dir, 7, Some(2022))
+                .unwrap()
+                .part1
+                .as_deref(),
+            Some("95437")
+        );
+        assert_eq!(
+            ExpectedAnswers::load(&dir, 7, Some(2021))
+                .unwrap()
+                .part1
+                .as_deref(),
+            Some("1792")
+        );
+    }
+
+    #[test]
+    fn verifications() {
+        assert_eq!(
+            Verification::check(Some("42"), Some("42")),
+            Verification::Verified
+        );
+        assert_eq!(
+            Verification::check(Some("42 "), Some("41")),
+            Verification::Mismatch {
+                expected: Some("42".to_owned())
+            }
+        );
+        assert!(Verification::check(Some("42"), None).is_mismatch());
+        assert!(Verification::check(Some("42"), Some("042")).is_mismatch());
+        assert_eq!(
+            Verification::check(Some("-1\n"), Some("-1")),
+            Verification::Verified
+        );
+        assert_eq!(Verification::check(None, Some("42")), Verification::Unknown);
+    }
+}
@@ -0,0 +1,194 @@
+//! Storage of the session cookie in the OS keyring
+//!
+//! Only available with the `keyring` feature. The cookie is stored under the `aoc-runner`
+//! service, for the `default` user or a given year, in the platform's keyring: the Keychain on
+//! macOS, the Credential Manager on Windows, and `secret-tool` (libsecret) on Linux and the BSDs.
+//! The cookie is never passed on a command line.
+//!
+//! The `aoc-session` binary wraps these functions:
+//!
+//! ```shell
+//! cargo install aoc-runner --features keyring --bin aoc-session
+//! aoc-session set <session cookie>
+//! aoc-session show
+//! aoc-session clear
+//! ```
+//!
+//! With the `fetch` feature, the stored cookie is used when `AOC_SESSION` is not set.
+//! (See [crate::client::find_session])
+
+use crate::solution::Result;
+
+/// Keyring service the cookie is stored under.
+pub const SERVICE: &str = "aoc-runner";
+
+/// Keyring user of a year's cookie, `default` when no year is given.
+pub fn user(year: Option<u16>) -> String {
+    year.map_or_else(|| "default".to_owned(), |year| year.to_string())
+}
+
+/// Stores the default session cookie. See [store_session_for]
+pub fn store_session(token: &str) -> Result<()> {
+    store_session_for(None, token)
+}
+
+/// Loads the default session cookie, `None` when none is stored. See [load_session_for]
+pub fn load_session() -> Result<Option<String>> {
+    load_session_for(None)
+}
+
+/// Removes the default session cookie. See [clear_session_for]
+pub fn clear_session() -> Result<()> {
+    clear_session_for(None)
+}
+
+/// Stores the session cookie used for `year`, or the default one.
+pub fn store_session_for(year: Option<u16>, token: &str) -> Result<()> {
+    backend::store(&user(year), token.trim())
+}
+
+/// Loads the session cookie stored for `year`, or the default one.
+pub fn load_session_for(year: Option<u16>) -> Result<Option<String>> {
+    Ok(backend::load(&user(year))?
+        .map(|token| token.trim().to_owned())
+        .filter(|token| !token.is_empty()))
+}
+
+/// Removes the session cookie stored for `year`, or the default one.
+pub fn clear_session_for(year: Option<u16>) -> Result<()> {
+    backend::clear(&user(year))
+}
+
+#[cfg(any(target_os = "macos", windows))]
+mod backend {
+    use keyring::{Entry, Error};
+
+    use super::SERVICE;
+    use crate::solution::{Result, SolutionError};
+
+    fn entry(user: &str) -> Result<Entry> {
+        Entry::new(SERVICE, user).map_err(keyring_error)
+    }
+
+    fn keyring_error(e: Error) -> SolutionError {
+        SolutionError::Keyring(e.to_string())
+    }
+
+    pub fn store(user: &str, token: &str) -> Result<()> {
+        entry(user)?.set_password(token).map_err(keyring_error)
+    }
+
+    pub fn load(user: &str) -> Result<Option<String>> {
+        match entry(user)?.get_password() {
+            Ok(token) => Ok(Some(token)),
+            Err(Error::NoEntry) => Ok(None),
+            Err(e) => Err(keyring_error(e)),
+        }
+    }
+
+    pub fn clear(user: &str) -> Result<()> {
+        match entry(user)?.delete_credential() {
+            Ok(()) | Err(Error::NoEntry) => Ok(()),
+            Err(e) => Err(keyring_error(e)),
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod backend {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    use super::SERVICE;
+    use crate::solution::{Result, SolutionError};
+
+    fn run(command: &mut Command, stdin: Option<&str>) -> Result<Option<String>> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| SolutionError::Keyring(format!("{:?}: {}", command.get_program(), e)))?;
+
+        if let Some(input) = stdin {
+            child
+                .stdin
+                .take()
+                .expect("piped stdin")
+                .write_all(input.as_bytes())
+                .map_err(|e| SolutionError::Keyring(e.to_string()))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| SolutionError::Keyring(e.to_string()))?;
+
+        // secret-tool exits with an error when the entry doesn't exist.
+        Ok(output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned()))
+    }
+
+    fn refused() -> SolutionError {
+        SolutionError::Keyring("the keyring refused to store the session".to_owned())
+    }
+
+    fn secret_tool(action: &str, user: &str) -> Command {
+        let mut command = Command::new("secret-tool");
+        command.arg(action);
+        if action == "store" {
+            command.arg(format!("--label=Advent of Code session ({})", user));
+        }
+        command.args(["service", SERVICE, "user", user]);
+        command
+    }
+
+    pub fn store(user: &str, token: &str) -> Result<()> {
+        run(&mut secret_tool("store", user), Some(token))?
+            .ok_or_else(refused)
+            .map(drop)
+    }
+
+    pub fn load(user: &str) -> Result<Option<String>> {
+        run(&mut secret_tool("lookup", user), None)
+    }
+
+    pub fn clear(user: &str) -> Result<()> {
+        run(&mut secret_tool("clear", user), None).map(drop)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod backend {
+    use crate::solution::{Result, SolutionError};
+
+    fn unsupported<T>() -> Result<T> {
+        Err(SolutionError::Keyring(
+            "no keyring support on this platform".to_owned(),
+        ))
+    }
+
+    pub fn store(_user: &str, _token: &str) -> Result<()> {
+        unsupported()
+    }
+
+    pub fn load(_user: &str) -> Result<Option<String>> {
+        unsupported()
+    }
+
+    pub fn clear(_user: &str) -> Result<()> {
+        unsupported()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users() {
+        assert_eq!(user(None), "default");
+        assert_eq!(user(Some(2022)), "2022");
+    }
+}
@@ -0,0 +1,311 @@
+//! History of the benchmarks of the days, to notice when a change makes a day slower
+//!
+//! [crate::Solution::bench_and_record] runs a day several times and appends the median durations
+//! of its phases to a CSV file, one line per benchmark:
+//!
+//! ```text
+//! year,day,commit,parse_ns,part1_ns,part2_ns
+//! 2022,7,3f2a9c1,120500,48211,50320
+//! ```
+//!
+//! The commit is read from `GIT_COMMIT`. With [vergen](https://docs.rs/vergen), pass it on with
+//! `GIT_COMMIT=$VERGEN_GIT_SHA`, or set it from `env!("VERGEN_GIT_SHA")` before benchmarking.
+//!
+//! A benchmark whose total median is slower than the previous one of the same day by more than
+//! the given threshold is reported as a [Regression], and warned about.
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use humantime::format_duration;
+
+use crate::solution::{Result, SolutionError, Timings};
+
+/// Environment variable holding the commit of the benchmarked code.
+pub const COMMIT_ENV: &str = "GIT_COMMIT";
+
+/// Commit recorded when [COMMIT_ENV] is not set.
+pub const UNKNOWN_COMMIT: &str = "unknown";
+
+const HEADER: &str = "year,day,commit,parse_ns,part1_ns,part2_ns";
+
+/// A line of the history: the median durations of a day at a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchRecord {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub commit: String,
+    pub median: Timings,
+}
+
+/// A benchmark slower than the previous one of the day. See [BenchReport::regression]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// The previous benchmark of the day.
+    pub previous: BenchRecord,
+    /// How much slower the total median is, in percent.
+    pub slowdown: f64,
+}
+
+/// Outcome of [crate::Solution::bench_and_record].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// The benchmark, as appended to the history.
+    pub record: BenchRecord,
+    /// The regression from the previous benchmark of the day, if any.
+    pub regression: Option<Regression>,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1}% slower than at commit {} ({})",
+            self.slowdown,
+            self.previous.commit,
+            format_duration(self.previous.median.total())
+        )
+    }
+}
+
+impl BenchRecord {
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.year.map(|year| year.to_string()).unwrap_or_default(),
+            self.day,
+            self.commit,
+            self.median.parse.as_nanos(),
+            self.median.part1.as_nanos(),
+            self.median.part2.as_nanos()
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let fields: Vec<_> = line.trim().split(',').collect();
+        let [year, day, commit, parse, part1, part2] = fields[..] else {
+            return None;
+        };
+        let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+
+        Some(BenchRecord {
+            year: match year {
+                "" => None,
+                year => Some(year.parse().ok()?),
+            },
+            day: day.parse().ok()?,
+            commit: commit.to_owned(),
+            median: Timings {
+                parse: nanos(parse)?,
+                part1: nanos(part1)?,
+                part2: nanos(part2)?,
+            },
+        })
+    }
+
+    /// The regression of this benchmark from `previous`, when its total median is more than
+    /// `threshold` percent slower.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc::bench::BenchRecord;
+    /// use aoc::solution::Timings;
+    ///
+    /// let record = |commit: &str, part1| BenchRecord {
+    ///     year: None,
+    ///     day: 7,
+    ///     commit: commit.to_owned(),
+    ///     median: Timings { part1: Duration::from_millis(part1), ..Timings::default() },
+    /// };
+    ///
+    /// let regression = record("b", 130).regression_from(&record("a", 100), 10.0).unwrap();
+    /// assert_eq!(regression.to_string(), "30.0% slower than at commit a (100ms)");
+    /// assert!(record("b", 105).regression_from(&record("a", 100), 10.0).is_none());
+    /// ```
+    pub fn regression_from(&self, previous: &BenchRecord, threshold: f64) -> Option<Regression> {
+        let before = previous.median.total().as_secs_f64();
+        if before == 0.0 {
+            return None;
+        }
+
+        let slowdown = (self.median.total().as_secs_f64() / before - 1.0) * 100.0;
+
+        (slowdown > threshold).then(|| Regression {
+            previous: previous.clone(),
+            slowdown,
+        })
+    }
+}
+
+/// The commit of the benchmarked code, from [COMMIT_ENV].
+pub fn commit() -> String {
+    std::env::var(COMMIT_ENV)
+        .ok()
+        .map(|commit| commit.trim().to_owned())
+        .filter(|commit| !commit.is_empty())
+        .unwrap_or_else(|| UNKNOWN_COMMIT.to_owned())
+}
+
+/// The benchmarks of the history at `path`, oldest first. Empty when the file doesn't exist.
+///
+/// The lines that aren't benchmarks, like the header, are skipped.
+pub fn read_history(path: &Path) -> Result<Vec<BenchRecord>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(content.lines().filter_map(BenchRecord::from_csv).collect()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(io_error(path, e)),
+    }
+}
+
+/// Appends `record` to the history at `path`, creating the file with its header if needed.
+pub fn append(path: &Path, record: &BenchRecord) -> Result<()> {
+    let write = || -> std::io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        if file.metadata()?.len() == 0 {
+            writeln!(file, "{}", HEADER)?;
+        }
+
+        writeln!(file, "{}", record.to_csv())
+    };
+
+    write().map_err(|e| io_error(path, e))
+}
+
+/// Records `record` in the history at `path`, compared with the previous benchmark of its day.
+pub(crate) fn record(path: &Path, record: BenchRecord, threshold: f64) -> Result<BenchReport> {
+    let regression = read_history(path)?
+        .iter()
+        .rev()
+        .find(|previous| (previous.year, previous.day) == (record.year, record.day))
+        .and_then(|previous| record.regression_from(previous, threshold));
+
+    append(path, &record)?;
+
+    Ok(BenchReport { record, regression })
+}
+
+/// The median of `durations`, the mean of the two middle ones for an even count.
+pub(crate) fn median(durations: &mut [Duration]) -> Duration {
+    durations.sort_unstable();
+
+    match durations.len() {
+        0 => Duration::ZERO,
+        len if len % 2 == 1 => durations[len / 2],
+        len => (durations[len / 2 - 1] + durations[len / 2]) / 2,
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> SolutionError {
+    SolutionError::Storage {
+        path: path.to_owned(),
+        source: Arc::new(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{env_lock, temp_dir};
+
+    fn bench(commit: &str, day: u8, millis: u64) -> BenchRecord {
+        BenchRecord {
+            year: Some(2022),
+            day,
+            commit: commit.to_owned(),
+            median: Timings {
+                parse: Duration::from_millis(millis),
+                part1: Duration::from_nanos(1),
+                part2: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn medians() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(median(&mut []), Duration::ZERO);
+        assert_eq!(median(&mut [ms(3), ms(1), ms(2)]), ms(2));
+        assert_eq!(median(&mut [ms(4), ms(1), ms(100), ms(2)]), ms(3));
+    }
+
+    #[test]
+    fn history() {
+        let path = temp_dir("bench-history").join("bench/history.csv");
+        assert_eq!(read_history(&path).unwrap(), []);
+
+        let first = record(&path, bench("a", 7, 100), 10.0).unwrap();
+        assert_eq!(first.regression, None);
+
+        record(&path, bench("a", 8, 1), 10.0).unwrap();
+        let slower = record(&path, bench("b", 7, 150), 10.0).unwrap();
+        let regression = slower.regression.unwrap();
+        assert_eq!(regression.previous, bench("a", 7, 100));
+        assert!((regression.slowdown - 50.0).abs() < 0.01);
+
+        let faster = record(&path, bench("c", 7, 90), 10.0).unwrap();
+        assert_eq!(faster.regression, None);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content
+            .starts_with("year,day,commit,parse_ns,part1_ns,part2_ns\n2022,7,a,100000000,1,0\n"));
+        assert_eq!(content.lines().count(), 5);
+        assert_eq!(
+            read_history(&path).unwrap(),
+            [
+                bench("a", 7, 100),
+                bench("a", 8, 1),
+                bench("b", 7, 150),
+                bench("c", 7, 90)
+            ]
+        );
+    }
+
+    #[test]
+    fn history_errors() {
+        let dir = temp_dir("bench-history-errors");
+
+        let error = read_history(&dir).unwrap_err();
+
+        assert_eq!(error, crate::solution::ErrorKind::Storage);
+        assert!(
+            error.to_string().starts_with("Couldn't access"),
+            "{}",
+            error
+        );
+        assert!(append(&dir, &bench("a", 7, 1)).is_err_and(|e| e == error.kind()));
+    }
+
+    #[test]
+    fn records_without_year() {
+        let record = BenchRecord {
+            year: None,
+            ..bench("a", 1, 5)
+        };
+
+        assert_eq!(BenchRecord::from_csv(&record.to_csv()), Some(record));
+        assert_eq!(BenchRecord::from_csv(HEADER), None);
+        assert_eq!(BenchRecord::from_csv("2022,7,a,1,2"), None);
+    }
+
+    #[test]
+    fn commits() {
+        let _lock = env_lock();
+
+        std::env::set_var(COMMIT_ENV, " 3f2a9c1\n");
+        assert_eq!(commit(), "3f2a9c1");
+
+        std::env::remove_var(COMMIT_ENV);
+        assert_eq!(commit(), UNKNOWN_COMMIT);
+    }
+}
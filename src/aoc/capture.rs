@@ -0,0 +1,100 @@
+//! Capture of the output printed by a solution
+//!
+//! Import the [crate::print!] and [crate::println!] macros of this crate in a day's file to
+//! shadow the std ones:
+//!
+//! ```
+//! use aoc::println;
+//!
+//! println!("Printed on stdout, or captured by Solution::run_captured");
+//! ```
+//!
+//! They print on stdout as usual, except while [crate::Solution::run_captured] runs the day:
+//! the output is then stored on the result, to be printed after the day completes.
+//! This keeps the output of the days running concurrently in [crate::runner::run_all_par]
+//! from interleaving.
+
+use std::cell::RefCell;
+use std::fmt::{Arguments, Write};
+
+thread_local! {
+    static BUFFER: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, capturing what it prints with [crate::print!] and [crate::println!] on the
+/// current thread.
+pub fn captured<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let previous = BUFFER.with(|buffer| buffer.replace(Some(String::new())));
+    let result = f();
+    let output = BUFFER.with(|buffer| buffer.replace(previous));
+
+    (result, output.unwrap_or_default())
+}
+
+/// Prints to the capture buffer when active, on stdout otherwise. Used by the macros.
+#[doc(hidden)]
+pub fn print(args: Arguments) {
+    let printed = BUFFER.with(|buffer| match buffer.borrow_mut().as_mut() {
+        Some(buffer) => buffer.write_fmt(args).is_ok(),
+        None => false,
+    });
+
+    if !printed {
+        std::print!("{}", args);
+    }
+}
+
+/// Same as [std::print!], but captured by [crate::Solution::run_captured]. See [crate::capture]
+#[macro_export]
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::capture::print(format_args!($($arg)*))
+    };
+}
+
+/// Same as [std::println!], but captured by [crate::Solution::run_captured].
+/// See [crate::capture]
+#[macro_export]
+macro_rules! println {
+    () => {
+        $crate::capture::print(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::capture::print(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture() {
+        let (value, output) = captured(|| {
+            crate::print!("a{}", 1);
+            crate::println!();
+            crate::println!("b{}", 2);
+
+            42
+        });
+
+        assert_eq!(value, 42);
+        assert_eq!(output, "a1\nb2\n");
+    }
+
+    #[test]
+    fn nested_capture() {
+        let ((_, inner), outer) = captured(|| {
+            crate::println!("outer");
+            captured(|| crate::println!("inner"))
+        });
+
+        assert_eq!(inner, "inner\n");
+        assert_eq!(outer, "outer\n");
+    }
+
+    #[test]
+    fn nothing_printed() {
+        assert_eq!(captured(|| ()).1, "");
+    }
+}
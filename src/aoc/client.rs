@@ -0,0 +1,1756 @@
+//! Client for the adventofcode.com website
+//!
+//! Only available with the `fetch` feature.
+//!
+//! Every request is authenticated with the session cookie of your Advent of Code account.
+//! It can be found in your browser's devtools once logged in on the website.
+//!
+//! With the `download` feature, the default [crate::Solution::get_input] downloads a missing
+//! input file when [crate::Solution::YEAR] is set and a session is available. (See [session])
+//! The file is saved, so the website is only queried once per puzzle.
+//!
+//! An empty [crate::Solution::TITLE] is also looked up on the puzzle page, when
+//! [crate::Solution::YEAR] is set. (See [fetch_title])
+//!
+//! With `AOC_WAIT=1`, downloading a puzzle that isn't unlocked yet waits for its unlock
+//! instead of failing. (See [wait_for_unlock])
+//!
+//! Private leaderboards are fetched with [fetch_leaderboard].
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "download")]
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::dotenv;
+use crate::guesses::{self, Guess, GuessCheck, GuessHistory};
+use crate::input;
+#[cfg(feature = "download")]
+use crate::input::MissingInput;
+use crate::leaderboard::Leaderboard;
+use crate::logging;
+pub use crate::runner::unlock_time;
+use crate::solution::{Result, SolutionError};
+
+/// Root of the Advent of Code website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// User-Agent sent with every request, as asked by the Advent of Code maintainers.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")"
+);
+
+/// Environment variable holding the session cookie. See [session]
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable making downloads wait for the puzzle unlock, when set to `1`.
+pub const WAIT_ENV: &str = "AOC_WAIT";
+
+/// Session cookie along with where it was found. See [find_session]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    pub token: String,
+    /// Human readable origin of the token, mentioned when the website refuses it.
+    pub source: String,
+}
+
+/// Session cookie token. See [find_session]
+pub fn session() -> Option<String> {
+    find_session().map(|session| session.token)
+}
+
+/// Session cookie read, in order of precedence, from:
+/// - the `AOC_SESSION` environment variable
+/// - the OS keyring, with the `keyring` feature (See [crate::auth])
+/// - `AOC_SESSION` in a `.env` or `.aoc.env` file of the current directory or its ancestors,
+///   up to the workspace root
+/// - the `~/.config/aoc/session` file
+pub fn find_session() -> Option<Session> {
+    find_session_for(None)
+}
+
+/// Same as [find_session], but the keyring entry of `year` is preferred to the default one.
+pub fn find_session_for(year: Option<u16>) -> Option<Session> {
+    session_from(&std::env::current_dir().unwrap_or_default(), year)
+}
+
+/// A source of session cookie, tried in order by [first_session].
+type SessionProvider<'a> = &'a dyn Fn() -> Option<Session>;
+
+fn first_session(providers: &[SessionProvider]) -> Option<Session> {
+    providers.iter().find_map(|provider| provider())
+}
+
+fn found(token: String, source: String) -> Option<Session> {
+    let token = token.trim().to_owned();
+
+    (!token.is_empty()).then_some(Session { token, source })
+}
+
+/// A keyring entry, if any. A keyring failure is noted on stderr and skipped.
+#[cfg_attr(not(feature = "keyring"), allow(dead_code))]
+fn from_keyring(user: &str, entry: Result<Option<String>>) -> Option<Session> {
+    match entry {
+        Ok(token) => found(token?, format!("the {} keyring entry", user)),
+        Err(e) => {
+            logging::warn(format_args!(
+                "Note: {}, trying the other session sources",
+                e
+            ));
+            None
+        }
+    }
+}
+
+fn session_from(dir: &Path, year: Option<u16>) -> Option<Session> {
+    let env = || {
+        let token = std::env::var(SESSION_ENV).ok()?;
+
+        found(token, format!("the {} environment variable", SESSION_ENV))
+    };
+    let keyring = || keyring_session(year);
+    let dotenv = || {
+        let (token, path) = dotenv::lookup(SESSION_ENV, dir)?;
+
+        found(token, path.display().to_string())
+    };
+    let file = || {
+        let path = crate::input::home_dir()?.join(".config/aoc/session");
+
+        found(
+            std::fs::read_to_string(&path).ok()?,
+            path.display().to_string(),
+        )
+    };
+
+    first_session(&[&env, &keyring, &dotenv, &file])
+}
+
+#[cfg(feature = "keyring")]
+fn keyring_session(year: Option<u16>) -> Option<Session> {
+    use crate::auth;
+
+    year.and_then(|year| from_keyring(&auth::user(Some(year)), auth::load_session_for(Some(year))))
+        .or_else(|| from_keyring(&auth::user(None), auth::load_session()))
+}
+
+#[cfg(not(feature = "keyring"))]
+fn keyring_session(_year: Option<u16>) -> Option<Session> {
+    None
+}
+
+/// Outcome of an answer submission. See [submit]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    /// The answer is correct.
+    Correct,
+    /// The answer is wrong.
+    Incorrect,
+    /// The answer is wrong, and too high.
+    TooHigh,
+    /// The answer is wrong, and too low.
+    TooLow,
+    /// An answer was submitted too recently. `wait` is the remaining time, when known.
+    TooRecent { wait: Option<Duration> },
+    /// The website asked to wait before submitting again, and `remaining` is left to wait.
+    /// The answer was not sent.
+    CoolingDown { remaining: Duration },
+    /// The part was already solved, or is not unlocked yet.
+    AlreadySolved,
+}
+
+impl std::fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Incorrect => write!(f, "wrong"),
+            SubmitOutcome::TooHigh => write!(f, "too high"),
+            SubmitOutcome::TooLow => write!(f, "too low"),
+            SubmitOutcome::TooRecent { wait: Some(wait) } => {
+                write!(f, "too recent, wait {}", humantime::format_duration(*wait))
+            }
+            SubmitOutcome::TooRecent { wait: None } => write!(f, "too recent"),
+            SubmitOutcome::CoolingDown { remaining } => write!(
+                f,
+                "cooling down, wait {}",
+                humantime::format_duration(*remaining)
+            ),
+            SubmitOutcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Authenticated client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+    source: Option<String>,
+    agent: ureq::Agent,
+    retry_delay: Duration,
+    throttle: Duration,
+    wait: bool,
+    answers_dir: PathBuf,
+    force: bool,
+    now: Box<dyn Fn() -> SystemTime + Send + Sync>,
+    sleep: Box<dyn Fn(Duration) + Send + Sync>,
+}
+
+/// Attempts of an input download before giving up on server errors and timeouts.
+pub const DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// Default minimum delay between two downloads of the same input. See [Client::download]
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(60);
+
+/// Minimum delay between two fetches of a private leaderboard, as asked by its API.
+/// See [fetch_leaderboard]
+pub const LEADERBOARD_THROTTLE: Duration = Duration::from_secs(15 * 60);
+
+/// Response to an input request.
+enum Fetched {
+    Modified { body: String, etag: Option<String> },
+    NotModified,
+}
+
+/// Download metadata stored next to an input file, in `<input file>.download`.
+#[derive(Debug, Default, PartialEq)]
+struct DownloadMeta {
+    etag: Option<String>,
+    /// Unix time of the last download attempt, in seconds.
+    attempted: u64,
+}
+
+impl DownloadMeta {
+    fn path(input: &Path) -> PathBuf {
+        let mut name = input.file_name().unwrap_or_default().to_owned();
+        name.push(".download");
+
+        input.with_file_name(name)
+    }
+
+    fn read(input: &Path) -> Self {
+        let content = std::fs::read_to_string(Self::path(input)).unwrap_or_default();
+        let mut meta = Self::default();
+
+        for (key, value) in content.lines().filter_map(|line| line.split_once(": ")) {
+            match key {
+                "etag" => meta.etag = Some(value.to_owned()),
+                "attempted" => meta.attempted = value.parse().unwrap_or_default(),
+                _ => {}
+            }
+        }
+
+        meta
+    }
+
+    fn write(&self, input: &Path) -> Result<()> {
+        let mut content = format!("attempted: {}\n", self.attempted);
+        if let Some(etag) = &self.etag {
+            content.push_str(&format!("etag: {}\n", etag));
+        }
+
+        std::fs::write(Self::path(input), content)?;
+
+        Ok(())
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Waits for the unlock of a puzzle, with a countdown on stderr. See [unlock_time]
+///
+/// Returns immediately when the puzzle is already unlocked,
+/// and fails when the unlock is more than a day away.
+///
+/// ```no_run
+/// aoc::client::wait_for_unlock(2024, 1)?;
+/// // Unlocking 2024 day 1 in 00:00:09
+///# Ok::<(), aoc::solution::SolutionError>(())
+/// ```
+pub fn wait_for_unlock(year: u16, day: u8) -> Result<()> {
+    wait_for_unlock_with(
+        year,
+        day,
+        SystemTime::now,
+        std::thread::sleep,
+        &mut std::io::stderr(),
+    )
+}
+
+/// Same as [wait_for_unlock], with the clock, the sleep and the countdown output provided.
+pub fn wait_for_unlock_with(
+    year: u16,
+    day: u8,
+    mut now: impl FnMut() -> SystemTime,
+    mut sleep: impl FnMut(Duration),
+    out: &mut impl Write,
+) -> Result<()> {
+    let unlock = unlock_time(year, day);
+    let mut counting = false;
+
+    while let Ok(remaining) = unlock.duration_since(now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        if remaining > Duration::from_secs(24 * 3_600) {
+            return Err(SolutionError::Network(format!(
+                "{} day {} unlocks in more than a day",
+                year, day
+            )));
+        }
+
+        // Rounded up, so the countdown ends on 00:00:01.
+        let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+        let _ = write!(
+            out,
+            "\rUnlocking {} day {} in {:02}:{:02}:{:02}",
+            year,
+            day,
+            secs / 3_600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        let _ = out.flush();
+        counting = true;
+
+        sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    if counting {
+        let _ = writeln!(out);
+    }
+
+    Ok(())
+}
+
+impl Client {
+    /// Creates a client for [BASE_URL] authenticated with the `session` cookie.
+    pub fn new(session: &str) -> Self {
+        Self {
+            base_url: BASE_URL.to_owned(),
+            session: session.trim().to_owned(),
+            source: None,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            retry_delay: Duration::from_secs(1),
+            throttle: DEFAULT_THROTTLE,
+            wait: std::env::var(WAIT_ENV).is_ok_and(|value| value.trim() == "1"),
+            answers_dir: PathBuf::from(guesses::ANSWERS_DIR),
+            force: guesses::forced(),
+            now: Box::new(SystemTime::now),
+            sleep: Box::new(std::thread::sleep),
+        }
+    }
+
+    /// Creates a client for [BASE_URL] authenticated with a [Session] from [find_session].
+    pub fn from_session(session: Session) -> Self {
+        Self {
+            source: Some(session.source),
+            ..Self::new(&session.token)
+        }
+    }
+
+    /// Points the client at another server. Mostly useful for testing.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Delay before retrying a failed download, doubled after each attempt. Defaults to 1s.
+    pub fn with_retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    /// Minimum delay between two downloads of the same input. Defaults to [DEFAULT_THROTTLE].
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Waits for the unlock of a puzzle not available yet before downloading it, and for the
+    /// end of the cooldown before submitting an answer.
+    /// Defaults to `true` when `AOC_WAIT=1`. See [wait_for_unlock] and [submit]
+    pub fn with_wait(mut self, wait: bool) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// Directory of the guess histories. Defaults to [guesses::ANSWERS_DIR].
+    pub fn with_answers_dir(mut self, dir: &Path) -> Self {
+        self.answers_dir = dir.to_path_buf();
+        self
+    }
+
+    /// Submits an answer contradicting a known bound anyway.
+    /// Defaults to `true` when `AOC_FORCE=1`. See [crate::guesses]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Replaces the system clock used by the submission cooldown. Mostly useful for testing.
+    pub fn with_clock(
+        mut self,
+        now: impl Fn() -> SystemTime + Send + Sync + 'static,
+        sleep: impl Fn(Duration) + Send + Sync + 'static,
+    ) -> Self {
+        self.now = Box::new(now);
+        self.sleep = Box::new(sleep);
+        self
+    }
+
+    /// Submits `answer` for the given puzzle part. See [submit]
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<SubmitOutcome> {
+        let mut history = GuessHistory::load(&self.answers_dir, year, day, part)?;
+
+        match history.check(answer) {
+            GuessCheck::New => {}
+            GuessCheck::Repeated(Guess {
+                outcome: SubmitOutcome::Correct | SubmitOutcome::AlreadySolved,
+                ..
+            }) => return Ok(SubmitOutcome::AlreadySolved),
+            GuessCheck::Repeated(guess) => {
+                return Err(SolutionError::RefusedAnswer(format!(
+                    "{} was already submitted on {} and was {}",
+                    guess.answer,
+                    guess.tried_at(),
+                    guess.outcome
+                )))
+            }
+            GuessCheck::OutOfBounds(guess) => {
+                let message = format!(
+                    "{} contradicts {}, submitted on {} and {}",
+                    answer.trim(),
+                    guess.answer,
+                    guess.tried_at(),
+                    guess.outcome
+                );
+                if !self.force {
+                    return Err(SolutionError::RefusedAnswer(format!(
+                        "{}. Set {}=1 to submit it anyway",
+                        message,
+                        guesses::FORCE_ENV
+                    )));
+                }
+                logging::warn(format_args!("WARNING: {}", message));
+            }
+        }
+
+        let mut retried = false;
+        loop {
+            if let Some(remaining) = self.cooldown() {
+                if !self.wait {
+                    return Ok(SubmitOutcome::CoolingDown { remaining });
+                }
+                logging::warn(format_args!(
+                    "Note: waiting {} before submitting",
+                    humantime::format_duration(remaining)
+                ));
+                (self.sleep)(remaining);
+            }
+
+            let outcome = self.post_answer(year, day, part, answer)?;
+
+            match outcome {
+                SubmitOutcome::TooRecent { wait: Some(_) } if self.wait && !retried => {
+                    retried = true;
+                }
+                outcome => {
+                    history.record(answer, &outcome, (self.now)());
+                    history.save()?;
+
+                    return Ok(outcome);
+                }
+            }
+        }
+    }
+
+    /// Sends an answer, recording the cooldown asked by the response.
+    fn post_answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<SubmitOutcome> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let body = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer.trim())])
+            .map_err(|e| SolutionError::Network(e.to_string()))?
+            .into_string()
+            .map_err(|e| SolutionError::Network(e.to_string()))?;
+
+        let outcome = parse_outcome(&body)?;
+
+        if let Some(wait) = parse_wait(&body) {
+            guesses::save_cooldown(&self.answers_dir, (self.now)() + wait)?;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Fetches the puzzle input of `year`/`day`.
+    ///
+    /// Server errors and timeouts are retried, up to [DOWNLOAD_ATTEMPTS] attempts.
+    /// A locked puzzle and an invalid session are reported as [SolutionError::Network].
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        match self.fetch_input(year, day, None)? {
+            Fetched::Modified { body, .. } => Ok(body),
+            Fetched::NotModified => Err(SolutionError::Network(
+                "unexpected 304 Not Modified response".to_owned(),
+            )),
+        }
+    }
+
+    /// Fetches the puzzle input of `year`/`day` and saves it to `path`.
+    ///
+    /// To be polite with the website:
+    /// - the `ETag` of the input is stored next to it, in `<path>.download`, so that
+    ///   downloading it again doesn't rewrite an unchanged file
+    /// - an input is downloaded at most once per [Client::with_throttle] delay.
+    ///   Within that delay, the saved input is returned, or an error when there is none.
+    pub fn download(&self, year: u16, day: u8, path: &Path) -> Result<String> {
+        let cached = std::fs::read_to_string(path).ok();
+        let mut meta = DownloadMeta::read(path);
+        let elapsed = Duration::from_secs(unix_now().saturating_sub(meta.attempted));
+
+        if elapsed < self.throttle {
+            return cached.ok_or_else(|| {
+                SolutionError::Network(format!(
+                    "download of {} day {} throttled, retry in {}s",
+                    year,
+                    day,
+                    (self.throttle - elapsed).as_secs().max(1)
+                ))
+            });
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        meta.attempted = unix_now();
+        meta.write(path)?;
+
+        let etag = meta.etag.as_deref().filter(|_| cached.is_some());
+
+        match (self.fetch_input(year, day, etag)?, cached) {
+            (Fetched::NotModified, Some(cached)) => Ok(cached),
+            (Fetched::NotModified, None) => {
+                DownloadMeta { etag: None, ..meta }.write(path)?;
+
+                Err(SolutionError::Network(format!(
+                    "unexpected 304 Not Modified response for {} day {} without a saved input",
+                    year, day
+                )))
+            }
+            (Fetched::Modified { body, etag }, _) => {
+                std::fs::write(path, &body)?;
+                DownloadMeta { etag, ..meta }.write(path)?;
+
+                Ok(body)
+            }
+        }
+    }
+
+    fn fetch_input(&self, year: u16, day: u8, etag: Option<&str>) -> Result<Fetched> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+        let mut waited = false;
+
+        loop {
+            let mut request = self
+                .agent
+                .get(&url)
+                .set("Cookie", &format!("session={}", self.session));
+            if let Some(etag) = etag {
+                request = request.set("If-None-Match", etag);
+            }
+
+            let response = match request.call() {
+                Ok(response) => response,
+                Err(ureq::Error::Status(404, _)) if self.wait && !waited => {
+                    wait_for_unlock(year, day)?;
+                    waited = true;
+                    continue;
+                }
+                Err(ureq::Error::Status(500..=599, _) | ureq::Error::Transport(_))
+                    if attempt < DOWNLOAD_ATTEMPTS =>
+                {
+                    std::thread::sleep(delay);
+                    delay *= 2;
+                    attempt += 1;
+                    continue;
+                }
+                Err(e) => return Err(self.input_error(e, year, day)),
+            };
+
+            if response.status() == 304 {
+                return Ok(Fetched::NotModified);
+            }
+
+            let etag = response.header("ETag").map(str::to_owned);
+            let body = response
+                .into_string()
+                .map_err(|e| SolutionError::Network(e.to_string()))?;
+
+            return Ok(Fetched::Modified { body, etag });
+        }
+    }
+
+    /// Fetches the title of a puzzle from its page, without the `--- Day N: ` decoration.
+    pub fn title(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let mut request = self.agent.get(&url);
+        if !self.session.is_empty() {
+            request = request.set("Cookie", &format!("session={}", self.session));
+        }
+
+        let page = request
+            .call()
+            .map_err(|e| self.input_error(e, year, day))?
+            .into_string()
+            .map_err(|e| SolutionError::Network(e.to_string()))?;
+
+        parse_title(&page).ok_or_else(|| {
+            SolutionError::Network(format!("no title in the page of {} day {}", year, day))
+        })
+    }
+
+    /// Fetches the standings of the private leaderboard `board_id` for `year`.
+    /// See [fetch_leaderboard], which caches them.
+    pub fn leaderboard(&self, year: u16, board_id: u64) -> Result<Leaderboard> {
+        Leaderboard::parse(&self.leaderboard_json(year, board_id)?)
+    }
+
+    fn leaderboard_json(&self, year: u16, board_id: u64) -> Result<String> {
+        let url = format!(
+            "{}/{}/leaderboard/private/view/{}.json",
+            self.base_url, year, board_id
+        );
+
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(404, _) => SolutionError::Network(format!(
+                    "no private leaderboard {} for {}",
+                    board_id, year
+                )),
+                e => self.request_error(e),
+            })?
+            .into_string()
+            .map_err(|e| SolutionError::Network(e.to_string()))
+    }
+
+    fn input_error(&self, e: ureq::Error, year: u16, day: u8) -> SolutionError {
+        match e {
+            ureq::Error::Status(404, _) => {
+                SolutionError::Network(format!("puzzle not unlocked yet: {} day {}", year, day))
+            }
+            e => self.request_error(e),
+        }
+    }
+
+    fn request_error(&self, e: ureq::Error) -> SolutionError {
+        match e {
+            ureq::Error::Status(400, _) => SolutionError::Network(match &self.source {
+                Some(source) => format!(
+                    "invalid session: the cookie from {} was refused, set a fresh one",
+                    source
+                ),
+                None => format!(
+                    "invalid session: the cookie was refused, set a fresh one in {}",
+                    SESSION_ENV
+                ),
+            }),
+            e => SolutionError::Network(e.to_string()),
+        }
+    }
+
+    /// Unix time of [Client::with_clock], in seconds.
+    fn unix_now(&self) -> u64 {
+        (self.now)()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+
+    /// Time left before submitting again, from the persisted cooldown.
+    fn cooldown(&self) -> Option<Duration> {
+        guesses::load_cooldown(&self.answers_dir)?
+            .duration_since((self.now)())
+            .ok()
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+/// Submits `answer` for the `part` (1 or 2) of the puzzle of `year`/`day`.
+///
+/// When the website asks to wait before submitting again (after a wrong answer for instance),
+/// the deadline is saved in `answers/cooldown` and further submissions are refused locally
+/// with [SubmitOutcome::CoolingDown] until the delay is over. With `AOC_WAIT=1`
+/// (see [Client::with_wait]), the submission waits for the end of the cooldown instead, and
+/// is retried once when the website still asks to wait.
+///
+/// Submitted answers are recorded in `answers/`, and an answer known to be wrong is refused
+/// with [SolutionError::RefusedAnswer]. Submitting a correct answer again returns
+/// [SubmitOutcome::AlreadySolved] without reaching the website. (See [crate::guesses])
+///
+/// # Example
+/// ```no_run
+/// use aoc::client::{submit, SubmitOutcome};
+///
+/// match submit(2022, 1, 1, "<session cookie>", "24000").expect("Submission failed") {
+///     SubmitOutcome::Correct => println!("⭐"),
+///     outcome => println!("{:?}", outcome),
+/// }
+/// ```
+pub fn submit(year: u16, day: u8, part: u8, session: &str, answer: &str) -> Result<SubmitOutcome> {
+    Client::new(session).submit(year, day, part, answer)
+}
+
+/// Downloads a missing input to its preferred path, when the year and a [session] are known.
+/// Otherwise the input is still missing.
+#[cfg(feature = "download")]
+pub(crate) fn download_missing(
+    year: Option<u16>,
+    day: u8,
+    missing: MissingInput,
+) -> Result<String> {
+    let (Some(year), Some(session), Some(path)) = (year, find_session(), missing.attempted.first())
+    else {
+        return Err(SolutionError::MissingInput(missing));
+    };
+
+    logging::warn(format_args!(
+        "Note: downloading the input of {} day {} to {}",
+        year,
+        day,
+        path.display()
+    ));
+
+    Client::from_session(session).download(year, day, path)
+}
+
+/// Title of a puzzle, read from `inputs/titles/{year}.toml` or fetched with [Client::title]
+/// and saved there.
+///
+/// The file is under the input directory (See [crate::input::input_dir]),
+/// with one `day = "title"` line per puzzle.
+pub fn fetch_title(year: u16, day: u8) -> Result<String> {
+    let client = match find_session_for(Some(year)) {
+        Some(session) => Client::from_session(session),
+        None => Client::new(""),
+    };
+
+    cached_title(&client, &input::input_dir(), year, day)
+}
+
+fn cached_title(client: &Client, dir: &Path, year: u16, day: u8) -> Result<String> {
+    let path = dir.join("titles").join(format!("{}.toml", year));
+    let mut titles = std::fs::read_to_string(&path)
+        .map(|content| parse_titles(&content))
+        .unwrap_or_default();
+
+    if let Some(title) = titles.get(&day) {
+        return Ok(title.clone());
+    }
+
+    let title = client.title(year, day)?;
+    titles.insert(day, title.clone());
+
+    std::fs::create_dir_all(path.parent().expect("titles directory"))?;
+    std::fs::write(&path, format_titles(&titles))?;
+
+    Ok(title)
+}
+
+/// Standings of the private leaderboard `board_id` for `year`, authenticated with the
+/// [session] of the year. See [crate::leaderboard]
+///
+/// The response is cached in `inputs/leaderboards/{year}_{board_id}.json`, and the website
+/// is queried at most once per [LEADERBOARD_THROTTLE], failed queries included: within that
+/// delay, the cached standings are returned, or an error when there are none. When a query
+/// fails, the cached standings are returned with a note on stderr.
+pub fn fetch_leaderboard(year: u16, board_id: u64) -> Result<Leaderboard> {
+    let session = find_session_for(Some(year)).ok_or_else(|| {
+        SolutionError::Network(format!(
+            "no session to fetch the leaderboard, set {}",
+            SESSION_ENV
+        ))
+    })?;
+
+    cached_leaderboard(
+        &Client::from_session(session),
+        &input::input_dir().join("leaderboards"),
+        year,
+        board_id,
+    )
+}
+
+fn cached_leaderboard(
+    client: &Client,
+    dir: &Path,
+    year: u16,
+    board_id: u64,
+) -> Result<Leaderboard> {
+    let path = dir.join(format!("{}_{}.json", year, board_id));
+    let cached = std::fs::read_to_string(&path).ok();
+    let mut meta = DownloadMeta::read(&path);
+    let now = client.unix_now();
+    let elapsed = Duration::from_secs(now.saturating_sub(meta.attempted));
+
+    if elapsed < LEADERBOARD_THROTTLE {
+        return match cached {
+            Some(cached) => Leaderboard::parse(&cached),
+            None => Err(SolutionError::Network(format!(
+                "fetch of the leaderboard {} for {} throttled, retry in {}s",
+                board_id,
+                year,
+                (LEADERBOARD_THROTTLE - elapsed).as_secs().max(1)
+            ))),
+        };
+    }
+
+    std::fs::create_dir_all(dir)?;
+    meta.attempted = now;
+    meta.write(&path)?;
+
+    let fetched = client
+        .leaderboard_json(year, board_id)
+        .and_then(|json| Ok((Leaderboard::parse(&json)?, json)));
+
+    match (fetched, cached) {
+        (Ok((leaderboard, json)), _) => {
+            std::fs::write(&path, json)?;
+
+            Ok(leaderboard)
+        }
+        (Err(e), Some(cached)) => {
+            logging::warn(format_args!(
+                "Note: {}, showing the cached standings of the leaderboard {}",
+                e, board_id
+            ));
+
+            Leaderboard::parse(&cached)
+        }
+        (Err(e), None) => Err(e),
+    }
+}
+
+/// Title displayed for a puzzle without [crate::Solution::TITLE], looked up once per run.
+///
+/// Empty when it can't be found, with a note on stderr.
+#[cfg(feature = "download")]
+pub(crate) fn display_title(year: u16, day: u8) -> &'static str {
+    static TITLES: Mutex<BTreeMap<(u16, u8), &'static str>> = Mutex::new(BTreeMap::new());
+
+    remembered_title(&TITLES, year, day, || fetch_title(year, day))
+}
+
+/// The title of `titles`, or the one of `fetch`, remembered for the next lookups.
+///
+/// The lock is not held while fetching, so that the other days don't wait for the website.
+#[cfg(feature = "download")]
+fn remembered_title(
+    titles: &Mutex<BTreeMap<(u16, u8), &'static str>>,
+    year: u16,
+    day: u8,
+    fetch: impl FnOnce() -> Result<String>,
+) -> &'static str {
+    let lock = || titles.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(title) = lock().get(&(year, day)) {
+        return title;
+    }
+
+    let title = fetch().unwrap_or_else(|e| {
+        logging::warn(format_args!(
+            "Note: no title for {} day {}: {}",
+            year, day, e
+        ));
+        String::new()
+    });
+
+    // Another thread may have fetched it meanwhile: the first title is kept.
+    lock().entry((year, day)).or_insert_with(|| {
+        // Leaked once per puzzle, to fit the `&'static str` of the results.
+        Box::leak(title.into_boxed_str())
+    })
+}
+
+/// Extracts `Title` from the `<h2>--- Day 7: Title ---</h2>` heading of a puzzle page.
+fn parse_title(page: &str) -> Option<String> {
+    let start = page.find("<h2>--- Day ")?;
+    let heading = &page[start..];
+    let heading = &heading[..heading.find("</h2>")?];
+    let heading = &heading[heading.find(": ")? + 2..];
+    let title = heading.trim_end_matches('-').trim();
+
+    Some(
+        title
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+fn parse_titles(content: &str) -> BTreeMap<u8, String> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (day, title) = line.split_once('=')?;
+            let title = title.trim().strip_prefix('"')?.strip_suffix('"')?;
+
+            let mut unescaped = String::new();
+            let mut chars = title.chars();
+            while let Some(c) = chars.next() {
+                unescaped.push(if c == '\\' { chars.next()? } else { c });
+            }
+
+            Some((day.trim().parse().ok()?, unescaped))
+        })
+        .collect()
+}
+
+fn format_titles(titles: &BTreeMap<u8, String>) -> String {
+    titles
+        .iter()
+        .map(|(day, title)| {
+            let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+            format!("{} = \"{}\"\n", day, title)
+        })
+        .collect()
+}
+
+fn parse_outcome(body: &str) -> Result<SubmitOutcome> {
+    if body.contains("That's the right answer") {
+        Ok(SubmitOutcome::Correct)
+    } else if body.contains("That's not the right answer") {
+        if body.contains("your answer is too high") {
+            Ok(SubmitOutcome::TooHigh)
+        } else if body.contains("your answer is too low") {
+            Ok(SubmitOutcome::TooLow)
+        } else {
+            Ok(SubmitOutcome::Incorrect)
+        }
+    } else if body.contains("You gave an answer too recently") {
+        Ok(SubmitOutcome::TooRecent {
+            wait: parse_wait(body),
+        })
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(SubmitOutcome::AlreadySolved)
+    } else {
+        Err(SolutionError::Network(
+            "Unexpected response to the answer submission".to_owned(),
+        ))
+    }
+}
+
+/// Extracts the delay from either `You have 1m 30s left to wait`
+/// or `please wait 5 minutes before trying again`.
+fn parse_wait(body: &str) -> Option<Duration> {
+    if let Some(end) = body.find(" left to wait") {
+        let start = body[..end].rfind("You have ")? + "You have ".len();
+
+        return body[start..end]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let start = body.find("please wait ")? + "please wait ".len();
+    let mut words = body[start..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    words
+        .next()
+        .filter(|unit| unit.starts_with("minute"))
+        .map(|_| Duration::from_secs(minutes * 60))
+}
+
+/// Minimal HTTP server answering canned responses, for testing the client without the website.
+#[cfg(test)]
+pub(crate) mod mock_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{channel, Receiver};
+    use std::thread;
+
+    /// A request received by the mock server.
+    #[derive(Debug)]
+    pub struct Request {
+        pub request_line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Canned `(status, headers, body)` response.
+    pub type Response<'a> = (u16, Vec<(&'a str, &'a str)>, &'a str);
+
+    /// Serves each response in order, then stops.
+    ///
+    /// Returns the base url of the server and a receiver of the requests it got.
+    pub fn serve(responses: Vec<Response>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<_> = responses
+            .into_iter()
+            .map(|(status, headers, body)| {
+                let headers: Vec<_> = headers
+                    .into_iter()
+                    .map(|(k, v)| format!("{}: {}\r\n", k, v))
+                    .collect();
+                format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n{}",
+                    status,
+                    body.len(),
+                    headers.concat(),
+                    body
+                )
+            })
+            .collect();
+        let (sender, receiver) = channel();
+
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = match listener.accept() {
+                    Ok(connection) => connection,
+                    Err(_) => return,
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some((key, value)) = line.split_once(':') {
+                        headers.push((key.trim().to_owned(), value.trim().to_owned()));
+                    }
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.parse().ok())
+                    .unwrap_or(0);
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                stream.write_all(response.as_bytes()).unwrap();
+                let _ = sender.send(Request {
+                    request_line: request_line.trim_end().to_owned(),
+                    headers,
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                });
+            }
+        });
+
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock_server::serve;
+    use super::*;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article></main>";
+    const INCORRECT: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.</p></article></main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article></main>";
+    const ALREADY_SOLVED: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>";
+
+    #[test]
+    fn outcomes() {
+        assert_eq!(parse_outcome(CORRECT).unwrap(), SubmitOutcome::Correct);
+        assert_eq!(parse_outcome(INCORRECT).unwrap(), SubmitOutcome::Incorrect);
+        assert_eq!(
+            parse_outcome(&INCORRECT.replace(".  If", "; your answer is too low.  If")).unwrap(),
+            SubmitOutcome::TooLow
+        );
+        assert_eq!(
+            parse_outcome(TOO_RECENT).unwrap(),
+            SubmitOutcome::TooRecent {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        assert_eq!(
+            parse_outcome(ALREADY_SOLVED).unwrap(),
+            SubmitOutcome::AlreadySolved
+        );
+        assert!(parse_outcome("<html>Puzzle inputs differ by user.</html>").is_err());
+    }
+
+    #[test]
+    fn wait_messages() {
+        assert_eq!(parse_wait(INCORRECT), Some(Duration::from_secs(60)));
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_wait("You have 34s left to wait."),
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(parse_wait(CORRECT), None);
+    }
+
+    #[test]
+    fn download_saves_the_input() {
+        let (url, requests) = serve(vec![(200, vec![], "1721\n979\n")]);
+        let path = crate::test_util::temp_dir("download").join("2020/DAY_01.txt");
+
+        let input = Client::new("download")
+            .with_base_url(&url)
+            .download(2020, 1, &path)
+            .unwrap();
+
+        assert_eq!(input, "1721\n979\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), input);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "GET /2020/day/1/input HTTP/1.1");
+        assert_eq!(request.header("cookie"), Some("session=download"));
+        assert_eq!(request.header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn download_errors() {
+        let (url, _requests) = serve(vec![
+            (
+                404,
+                vec![],
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                vec![],
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+        ]);
+        let path = crate::test_util::temp_dir("download-errors").join("DAY_25.txt");
+        let client = Client::new("download-errors")
+            .with_base_url(&url)
+            .with_throttle(Duration::ZERO);
+
+        let locked = client.download(2030, 25, &path).unwrap_err().to_string();
+        let refused = client.download(2030, 25, &path).unwrap_err().to_string();
+
+        assert!(locked.contains("puzzle not unlocked yet"), "{}", locked);
+        assert!(refused.contains("invalid session"), "{}", refused);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn download_retries_server_errors() {
+        let (url, requests) = serve(vec![(500, vec![], "oops"), (200, vec![], "42\n")]);
+        let path = crate::test_util::temp_dir("download-retry").join("DAY_02.txt");
+
+        let input = Client::new("download-retry")
+            .with_base_url(&url)
+            .with_retry_delay(Duration::from_millis(1))
+            .download(2020, 2, &path)
+            .unwrap();
+
+        assert_eq!(input, "42\n");
+        assert_eq!(requests.iter().take(2).count(), 2);
+    }
+
+    #[test]
+    fn download_sends_the_etag() {
+        let (url, requests) = serve(vec![
+            (200, vec![("ETag", "\"v1\"")], "cached\n"),
+            (304, vec![], ""),
+        ]);
+        let path = crate::test_util::temp_dir("download-etag").join("DAY_03.txt");
+        let client = Client::new("download-etag")
+            .with_base_url(&url)
+            .with_throttle(Duration::ZERO);
+
+        client.download(2020, 3, &path).unwrap();
+        let first = requests.recv().unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        let input = client.download(2020, 3, &path).unwrap();
+        let second = requests.recv().unwrap();
+
+        assert_eq!(first.header("if-none-match"), None);
+        assert_eq!(second.header("if-none-match"), Some("\"v1\""));
+        assert_eq!(input, "cached\n");
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified
+        );
+    }
+
+    #[test]
+    fn download_not_modified_without_input() {
+        let (url, requests) = serve(vec![(304, vec![], ""), (304, vec![], "")]);
+        let path = crate::test_util::temp_dir("download-not-modified").join("DAY_05.txt");
+        let client = Client::new("download-not-modified")
+            .with_base_url(&url)
+            .with_throttle(Duration::ZERO);
+
+        let error = client.download(2020, 5, &path).unwrap_err();
+
+        assert!(error.to_string().contains("304 Not Modified"), "{}", error);
+        requests.recv().unwrap();
+        assert!(requests.try_recv().is_err());
+        assert_eq!(DownloadMeta::read(&path).etag, None);
+    }
+
+    #[test]
+    fn download_throttle() {
+        let (url, requests) = serve(vec![(200, vec![], "first\n"), (200, vec![], "second\n")]);
+        let path = crate::test_util::temp_dir("download-throttle").join("DAY_04.txt");
+        let client = Client::new("download-throttle").with_base_url(&url);
+
+        assert_eq!(client.download(2020, 4, &path).unwrap(), "first\n");
+        assert_eq!(client.download(2020, 4, &path).unwrap(), "first\n");
+
+        std::fs::remove_file(&path).unwrap();
+        let error = client.download(2020, 4, &path).unwrap_err();
+
+        assert!(error.to_string().contains("throttled"), "{}", error);
+        requests.recv().unwrap();
+        assert!(requests.try_recv().is_err());
+
+        DownloadMeta::default().write(&path).unwrap();
+        assert_eq!(client.download(2020, 4, &path).unwrap(), "second\n");
+    }
+
+    #[test]
+    fn unlock_times() {
+        let secs = |year, day| {
+            unlock_time(year, day)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+
+        // 2022-12-01T05:00:00Z and 2024-12-25T05:00:00Z
+        assert_eq!(secs(2022, 1), 1_669_870_800);
+        assert_eq!(secs(2024, 25), 1_735_102_800);
+    }
+
+    #[test]
+    fn wait_countdown() {
+        let unlock = unlock_time(2023, 5);
+        let clock = std::cell::Cell::new(unlock - Duration::from_millis(2_500));
+        let mut sleeps = Vec::new();
+        let mut out = Vec::new();
+
+        wait_for_unlock_with(
+            2023,
+            5,
+            || clock.get(),
+            |duration| {
+                sleeps.push(duration);
+                clock.set(clock.get() + duration);
+            },
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(
+            sleeps,
+            [1_000, 1_000, 500].map(Duration::from_millis).to_vec()
+        );
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\rUnlocking 2023 day 5 in 00:00:03\rUnlocking 2023 day 5 in 00:00:02\rUnlocking 2023 day 5 in 00:00:01\n"
+        );
+    }
+
+    #[test]
+    fn wait_after_unlock() {
+        let mut out = Vec::new();
+        let after = unlock_time(2023, 5) + Duration::from_secs(1);
+
+        wait_for_unlock_with(2023, 5, || after, |_| panic!("no wait"), &mut out).unwrap();
+
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn wait_too_long() {
+        let before = unlock_time(2023, 5) - Duration::from_secs(2 * 24 * 3_600);
+
+        let error = wait_for_unlock_with(2023, 5, || before, |_| (), &mut Vec::new()).unwrap_err();
+
+        assert!(error.to_string().contains("more than a day"), "{}", error);
+    }
+
+    #[test]
+    fn download_waits_for_unlock() {
+        let (url, requests) = serve(vec![
+            (404, vec![], ""),
+            (404, vec![], ""),
+            (200, vec![], "unlocked\n"),
+        ]);
+        let client = Client::new("download-wait").with_base_url(&url);
+
+        assert!(client.input(2020, 5).is_err());
+        assert_eq!(client.with_wait(true).input(2020, 5).unwrap(), "unlocked\n");
+        assert_eq!(requests.iter().take(3).count(), 3);
+    }
+
+    const PUZZLE: &str = "<main><article class=\"day-desc\"><h2>--- Day 7: No Space Left &amp; \"More\" ---</h2><p>...</p></article></main>";
+
+    #[test]
+    fn titles() {
+        assert_eq!(
+            parse_title(PUZZLE).as_deref(),
+            Some("No Space Left & \"More\"")
+        );
+        assert_eq!(parse_title("<main></main>"), None);
+        assert_eq!(parse_title("<h2>--- Day 7 ---</h2><p>Note: ...</p>"), None);
+
+        let titles = BTreeMap::from([
+            (7, "a \"quoted\" \\ title".to_owned()),
+            (25, "x".to_owned()),
+        ]);
+        assert_eq!(parse_titles(&format_titles(&titles)), titles);
+    }
+
+    #[test]
+    fn fetch_and_cache_title() {
+        let (url, requests) = serve(vec![(200, vec![], PUZZLE)]);
+        let dir = crate::test_util::temp_dir("titles");
+        let client = Client::new("").with_base_url(&url);
+
+        let title = cached_title(&client, &dir, 2022, 7).unwrap();
+        let request = requests.recv().unwrap();
+        let cached = cached_title(&client, &dir, 2022, 7).unwrap();
+
+        assert_eq!(title, "No Space Left & \"More\"");
+        assert_eq!(cached, title);
+        assert_eq!(request.request_line, "GET /2022/day/7 HTTP/1.1");
+        assert_eq!(request.header("cookie"), None);
+        assert!(requests.try_recv().is_err());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("titles/2022.toml")).unwrap(),
+            "7 = \"No Space Left & \\\"More\\\"\"\n"
+        );
+    }
+
+    #[test]
+    fn title_offline() {
+        let dir = crate::test_util::temp_dir("titles-offline");
+        let client = Client::new("").with_base_url("http://127.0.0.1:1");
+
+        assert!(cached_title(&client, &dir, 2022, 7).is_err());
+        assert!(!dir.join("titles").exists());
+    }
+
+    #[test]
+    #[cfg(feature = "download")]
+    fn display_title_offline() {
+        let dir = crate::test_util::temp_dir("display-title-offline");
+        let client = Client::new("").with_base_url("http://127.0.0.1:1");
+
+        let titles = Mutex::new(BTreeMap::new());
+        let title = remembered_title(&titles, 2022, 7, || cached_title(&client, &dir, 2022, 7));
+        let view = crate::output::ResultView::new(title, 7);
+
+        assert_eq!(title, "");
+        assert!(view.to_string().contains(" Day 07\n"), "{}", view);
+        assert_eq!(
+            remembered_title(&titles, 2022, 7, || unreachable!("the title is remembered")),
+            ""
+        );
+    }
+
+    #[test]
+    fn session_precedence() {
+        let _lock = crate::test_util::env_lock();
+        let dir = crate::test_util::temp_dir("session");
+        std::fs::create_dir(dir.join(".git")).unwrap();
+        std::fs::write(dir.join(".env"), "AOC_SESSION=\"from-dotenv\"\n").unwrap();
+
+        std::env::set_var(SESSION_ENV, "from-env");
+        let from_env = session_from(&dir, None).unwrap();
+        std::env::remove_var(SESSION_ENV);
+        let from_dotenv = session_from(&dir, None).unwrap();
+
+        assert_eq!(from_env.token, "from-env");
+        assert_eq!(from_dotenv.token, "from-dotenv");
+        assert_eq!(from_dotenv.source, dir.join(".env").display().to_string());
+    }
+
+    #[test]
+    fn session_providers_order() {
+        let session = |token: &str| Session {
+            token: token.to_owned(),
+            source: token.to_owned(),
+        };
+        let env = || Some(session("env"));
+        let keyring = || Some(session("keyring"));
+        let failing_keyring =
+            || from_keyring("default", Err(SolutionError::Keyring("locked".to_owned())));
+        let empty_keyring = || from_keyring("default", Ok(None));
+        let dotenv = || Some(session("dotenv"));
+        let none = || None;
+
+        assert_eq!(
+            first_session(&[&env, &keyring, &dotenv]).unwrap().token,
+            "env"
+        );
+        assert_eq!(
+            first_session(&[&none, &keyring, &dotenv]).unwrap().token,
+            "keyring"
+        );
+        assert_eq!(
+            first_session(&[&none, &failing_keyring, &dotenv])
+                .unwrap()
+                .token,
+            "dotenv"
+        );
+        assert_eq!(
+            first_session(&[&none, &empty_keyring, &dotenv])
+                .unwrap()
+                .token,
+            "dotenv"
+        );
+        assert_eq!(first_session(&[&none, &failing_keyring]), None);
+    }
+
+    #[test]
+    fn refused_session_mentions_its_source() {
+        let (url, _requests) = serve(vec![(400, vec![], "")]);
+        let session = Session {
+            token: "expired".to_owned(),
+            source: "/home/elf/.env".to_owned(),
+        };
+
+        let error = Client::from_session(session)
+            .with_base_url(&url)
+            .input(2022, 1)
+            .unwrap_err();
+
+        assert!(error.to_string().contains("/home/elf/.env"), "{}", error);
+    }
+
+    #[test]
+    fn submit_posts_the_answer() {
+        let (url, requests) = serve(vec![(200, vec![], CORRECT)]);
+
+        let outcome = Client::new("submit-posts")
+            .with_base_url(&url)
+            .with_answers_dir(&crate::test_util::temp_dir("submit-posts"))
+            .submit(2022, 7, 2, "1234\n")
+            .unwrap();
+        assert_eq!(outcome, SubmitOutcome::Correct);
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.request_line, "POST /2022/day/7/answer HTTP/1.1");
+        assert_eq!(request.header("cookie"), Some("session=submit-posts"));
+        assert_eq!(request.header("user-agent"), Some(USER_AGENT));
+        assert_eq!(request.body, "level=2&answer=1234");
+    }
+
+    #[test]
+    fn submit_respects_the_wait_message() {
+        let (url, requests) = serve(vec![(200, vec![], INCORRECT), (200, vec![], CORRECT)]);
+        let client = Client::new("respects-wait")
+            .with_base_url(&url)
+            .with_answers_dir(&crate::test_util::temp_dir("respects-wait"));
+
+        assert_eq!(
+            client.submit(2022, 1, 1, "1").unwrap(),
+            SubmitOutcome::Incorrect
+        );
+        assert!(matches!(
+            client.submit(2022, 1, 1, "2").unwrap(),
+            SubmitOutcome::CoolingDown { remaining } if remaining <= Duration::from_secs(61)
+        ));
+
+        requests.recv().unwrap();
+        assert!(
+            requests.try_recv().is_err(),
+            "second submission should not reach the server"
+        );
+    }
+
+    #[test]
+    fn submit_records_guesses() {
+        let dir = crate::test_util::temp_dir("guess-history");
+        let too_high = INCORRECT.replace(
+            "the right answer.",
+            "the right answer; your answer is too high.",
+        );
+        let (url, requests) = serve(vec![
+            (200, vec![], too_high.as_str()),
+            (200, vec![], CORRECT),
+        ]);
+        let client = |force| {
+            Client::new(&format!("guess-history-{}", force))
+                .with_base_url(&url)
+                .with_answers_dir(&dir)
+                .with_force(force)
+                .with_clock(
+                    move || SystemTime::now() + Duration::from_secs(if force { 120 } else { 0 }),
+                    |_| {},
+                )
+        };
+
+        assert_eq!(
+            client(false).submit(2022, 3, 1, "500").unwrap(),
+            SubmitOutcome::TooHigh
+        );
+        let repeated = client(false).submit(2022, 3, 1, "500\n").unwrap_err();
+        let contradicting = client(false).submit(2022, 3, 1, "600").unwrap_err();
+        let forced = client(true).submit(2022, 3, 1, "600").unwrap();
+
+        assert!(
+            repeated
+                .to_string()
+                .contains("500 was already submitted on 20"),
+            "{}",
+            repeated
+        );
+        assert!(
+            repeated.to_string().ends_with("was too high"),
+            "{}",
+            repeated
+        );
+        assert!(
+            contradicting.to_string().contains("AOC_FORCE=1"),
+            "{}",
+            contradicting
+        );
+        assert_eq!(forced, SubmitOutcome::Correct);
+        assert_eq!(requests.iter().take(2).count(), 2);
+        assert_eq!(
+            GuessHistory::load(&dir, 2022, 3, 1)
+                .unwrap()
+                .guesses()
+                .len(),
+            2
+        );
+    }
+
+    /// A clock starting at the 2022 day 7 unlock, advanced by its `sleep`.
+    fn fake_clock() -> (
+        impl Fn() -> SystemTime + Send + Sync + 'static,
+        impl Fn(Duration) + Send + Sync + 'static,
+    ) {
+        let now = std::sync::Arc::new(std::sync::Mutex::new(unlock_time(2022, 7)));
+        let slept = now.clone();
+
+        (
+            move || *now.lock().unwrap(),
+            move |duration| *slept.lock().unwrap() += duration,
+        )
+    }
+
+    const LEADERBOARD: &str = include_str!("../../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn fetch_leaderboard() {
+        let (url, requests) = serve(vec![(200, vec![], LEADERBOARD)]);
+        let client = Client::new("leaderboard").with_base_url(&url);
+
+        let leaderboard = client.leaderboard(2022, 101).unwrap();
+
+        assert_eq!(leaderboard.members.len(), 4);
+        let request = requests.recv().unwrap();
+        assert_eq!(
+            request.request_line,
+            "GET /2022/leaderboard/private/view/101.json HTTP/1.1"
+        );
+        assert_eq!(request.header("Cookie"), Some("session=leaderboard"));
+    }
+
+    #[test]
+    fn leaderboard_errors() {
+        let (url, _requests) = serve(vec![
+            (404, vec![], ""),
+            (200, vec![], "<html>Log in</html>"),
+        ]);
+        let client = Client::new("leaderboard-errors").with_base_url(&url);
+
+        assert_eq!(
+            client.leaderboard(2022, 1).unwrap_err().to_string(),
+            "Request to Advent of Code failed: no private leaderboard 1 for 2022"
+        );
+        assert!(client
+            .leaderboard(2022, 1)
+            .unwrap_err()
+            .to_string()
+            .contains("invalid leaderboard"));
+    }
+
+    #[test]
+    fn leaderboard_cache_throttle() {
+        let dir = crate::test_util::temp_dir("leaderboard-cache");
+        let (url, requests) = serve(vec![(200, vec![], LEADERBOARD), (200, vec![], LEADERBOARD)]);
+        let (now, sleep) = fake_clock();
+        let client = Client::new("leaderboard-cache")
+            .with_base_url(&url)
+            .with_clock(now, sleep);
+
+        let fetched = cached_leaderboard(&client, &dir, 2022, 101).unwrap();
+        requests.recv().unwrap();
+        assert!(dir.join("2022_101.json").is_file());
+
+        (client.sleep)(LEADERBOARD_THROTTLE - Duration::from_secs(1));
+        assert_eq!(
+            cached_leaderboard(&client, &dir, 2022, 101).unwrap(),
+            fetched
+        );
+        assert!(requests.try_recv().is_err(), "served from the cache");
+
+        (client.sleep)(Duration::from_secs(1));
+        cached_leaderboard(&client, &dir, 2022, 101).unwrap();
+        requests.recv().unwrap();
+    }
+
+    #[test]
+    fn leaderboard_cache_fallback() {
+        let dir = crate::test_util::temp_dir("leaderboard-fallback");
+        let (url, requests) = serve(vec![
+            (500, vec![], ""),
+            (200, vec![], LEADERBOARD),
+            (500, vec![], ""),
+        ]);
+        let (now, sleep) = fake_clock();
+        let client = Client::new("leaderboard-fallback")
+            .with_base_url(&url)
+            .with_clock(now, sleep);
+
+        assert!(cached_leaderboard(&client, &dir, 2022, 101).is_err());
+        requests.recv().unwrap();
+        assert!(cached_leaderboard(&client, &dir, 2022, 101)
+            .unwrap_err()
+            .to_string()
+            .contains("throttled"));
+        assert!(requests.try_recv().is_err(), "failed fetches are throttled");
+
+        (client.sleep)(LEADERBOARD_THROTTLE);
+        let fetched = cached_leaderboard(&client, &dir, 2022, 101).unwrap();
+        requests.recv().unwrap();
+
+        (client.sleep)(LEADERBOARD_THROTTLE);
+        assert_eq!(
+            cached_leaderboard(&client, &dir, 2022, 101).unwrap(),
+            fetched
+        );
+        requests.recv().unwrap();
+    }
+
+    #[test]
+    fn cooldown_is_persisted() {
+        let dir = crate::test_util::temp_dir("cooldown-persisted");
+        let (url, requests) = serve(vec![(200, vec![], TOO_RECENT), (200, vec![], CORRECT)]);
+        let (now, sleep) = fake_clock();
+        let start = now();
+        let client = Client::new("cooldown-persisted")
+            .with_base_url(&url)
+            .with_answers_dir(&dir)
+            .with_wait(false)
+            .with_clock(now, sleep);
+
+        assert_eq!(
+            client.submit(2022, 7, 1, "1").unwrap(),
+            SubmitOutcome::TooRecent {
+                wait: Some(Duration::from_secs(65))
+            }
+        );
+        let restarted = Client::new("cooldown-persisted")
+            .with_base_url(&url)
+            .with_answers_dir(&dir)
+            .with_wait(false)
+            .with_clock(move || start + Duration::from_secs(60), |_| {});
+
+        assert_eq!(
+            restarted.submit(2022, 7, 1, "1").unwrap(),
+            SubmitOutcome::CoolingDown {
+                remaining: Duration::from_secs(5)
+            }
+        );
+        assert_eq!(
+            guesses::load_cooldown(&dir),
+            Some(start + Duration::from_secs(65))
+        );
+        requests.recv().unwrap();
+        assert!(requests.try_recv().is_err(), "refused locally");
+    }
+
+    #[test]
+    fn submit_waits_for_the_cooldown() {
+        let dir = crate::test_util::temp_dir("cooldown-wait");
+        let (url, requests) = serve(vec![(200, vec![], TOO_RECENT), (200, vec![], CORRECT)]);
+        let (now, sleep) = fake_clock();
+        let start = now();
+        guesses::save_cooldown(&dir, start + Duration::from_secs(30)).unwrap();
+        let client = Client::new("cooldown-wait")
+            .with_base_url(&url)
+            .with_answers_dir(&dir)
+            .with_wait(true)
+            .with_clock(now, sleep);
+
+        // Waits 30s, is asked to wait 65s more, then waits and retries once.
+        assert_eq!(
+            client.submit(2022, 7, 2, "1").unwrap(),
+            SubmitOutcome::Correct
+        );
+        assert_eq!((client.now)(), start + Duration::from_secs(95));
+        assert_eq!(requests.iter().take(2).count(), 2);
+
+        // Retried only once.
+        let (url, requests) = serve(vec![(200, vec![], TOO_RECENT), (200, vec![], TOO_RECENT)]);
+        let client = client.with_base_url(&url);
+
+        assert!(matches!(
+            client.submit(2022, 7, 1, "2").unwrap(),
+            SubmitOutcome::TooRecent { .. }
+        ));
+        assert_eq!((client.now)(), start + Duration::from_secs(160));
+        assert_eq!(requests.iter().take(2).count(), 2);
+    }
+}
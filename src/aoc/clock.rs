@@ -0,0 +1,126 @@
+//! Clocks measuring the durations of [crate::time!]
+//!
+//! [RealClock] reads [std::time::Instant] and is the default. [MockClock] only moves when it is
+//! told to, so the tests asserting on durations don't depend on the load of the machine.
+//!
+//! The clock is a type parameter, so the default path costs nothing over using [Instant] directly.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// Source of the time measured by [crate::time!].
+pub trait Clock {
+    /// A point in time of the clock.
+    type Instant: Copy;
+
+    /// The current point in time.
+    fn now() -> Self::Instant;
+
+    /// Time elapsed since `start`, a point in time returned by [Clock::now].
+    fn elapsed(start: Self::Instant) -> Duration;
+}
+
+/// The wall clock: [Instant::now].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealClock;
+
+impl Clock for RealClock {
+    type Instant = Instant;
+
+    fn now() -> Self::Instant {
+        Instant::now()
+    }
+
+    fn elapsed(start: Self::Instant) -> Duration {
+        start.elapsed()
+    }
+}
+
+thread_local! {
+    static MOCK_NOW: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// A clock that only moves with [MockClock::advance].
+///
+/// Its time is kept per thread, so tests running in parallel don't move each other's clock.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use aoc::clock::MockClock;
+///
+/// let (result, duration) = aoc::time!(clock: MockClock, {
+///     MockClock::advance(Duration::from_millis(10));
+///     42
+/// });
+///
+/// assert_eq!(result, 42);
+/// assert_eq!(duration, Duration::from_millis(10));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MockClock;
+
+impl MockClock {
+    /// Moves the clock of the current thread forward.
+    pub fn advance(by: Duration) {
+        MOCK_NOW.with(|now| now.set(now.get() + by));
+    }
+
+    /// Time elapsed since the clock of the current thread started.
+    pub fn current() -> Duration {
+        MOCK_NOW.with(Cell::get)
+    }
+
+    /// Moves the clock of the current thread back to zero.
+    pub fn reset() {
+        MOCK_NOW.with(|now| now.set(Duration::ZERO));
+    }
+}
+
+impl Clock for MockClock {
+    type Instant = Duration;
+
+    fn now() -> Self::Instant {
+        Self::current()
+    }
+
+    fn elapsed(start: Self::Instant) -> Duration {
+        Self::current().saturating_sub(start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_clock() {
+        MockClock::reset();
+        let start = MockClock::now();
+        assert_eq!(MockClock::elapsed(start), Duration::ZERO);
+
+        MockClock::advance(Duration::from_secs(2));
+        MockClock::advance(Duration::from_millis(5));
+        assert_eq!(MockClock::elapsed(start), Duration::from_millis(2_005));
+
+        let (_, nested) = crate::time!(clock: MockClock, {
+            MockClock::advance(Duration::from_micros(3));
+        });
+        assert_eq!(nested, Duration::from_micros(3));
+        assert_eq!(MockClock::elapsed(start), Duration::from_micros(2_005_003));
+
+        MockClock::reset();
+        assert_eq!(MockClock::current(), Duration::ZERO);
+    }
+
+    #[test]
+    fn mock_clock_per_thread() {
+        MockClock::reset();
+        MockClock::advance(Duration::from_secs(1));
+
+        std::thread::spawn(|| assert_eq!(MockClock::current(), Duration::ZERO))
+            .join()
+            .unwrap();
+        assert_eq!(MockClock::current(), Duration::from_secs(1));
+    }
+}
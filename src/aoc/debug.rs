@@ -0,0 +1,268 @@
+//! Tools to debug a day, like shrinking an input that doesn't parse
+//!
+//! [reduce_input] removes parts of an input as long as [crate::Solution::parse_ctx] keeps failing
+//! the same way, to turn a huge puzzle input into a small repro for a bug report or a unit test.
+//!
+//! Set `AOC_REDUCE_ON_PARSE_ERROR=1` to reduce the input of [crate::Solution::run] when it
+//! doesn't parse, the repro is written to `repro_DAY_XX.txt` in the working directory. The
+//! other runners parsing an input ([crate::Solution::run_par], [crate::Solution::run_file]...) do
+//! the same.
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::logging;
+use crate::solution::ErrorKind;
+use crate::Solution;
+
+/// Environment variable enabling the reduction of the inputs that don't parse in
+/// [crate::Solution::run].
+pub const REDUCE_ENV: &str = "AOC_REDUCE_ON_PARSE_ERROR";
+
+/// Maximum number of calls to `parse` made by [reduce_input].
+pub const MAX_ATTEMPTS: usize = 10_000;
+
+/// Smallest input found that fails to parse with the same [ErrorKind] as `input`.
+///
+/// The lines are removed first, by chunks of decreasing size, then the characters of the
+/// remaining lines. Gives up after [MAX_ATTEMPTS] calls to `parse`, returning the smallest input
+/// found so far. An input that parses, or whose `parse` panics, is returned unchanged.
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+/// use aoc::solution::{Result, SolutionError};
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     // -- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = Vec<u32>; type P1 = u32; type P2 = u32;
+///     fn parse(input: &str) -> Result<Self::Input> {
+///         input.lines().map(|l| l.parse().map_err(|_| SolutionError::parse("not a number"))).collect()
+///     }
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+/// }
+///
+/// assert_eq!(aoc::debug::reduce_input::<DayXX>("12\n7\n1O4\n33"), "O");
+/// ```
+pub fn reduce_input<S: Solution + ?Sized>(input: &str) -> String {
+    let Some(kind) = parse_error::<S>(input) else {
+        return input.to_owned();
+    };
+    let attempts = Cell::new(MAX_ATTEMPTS);
+    let fails = |candidate: &str| -> bool {
+        if attempts.get() == 0 {
+            return false;
+        }
+        attempts.set(attempts.get() - 1);
+
+        parse_error::<S>(candidate) == Some(kind)
+    };
+
+    let lines: Vec<&str> = input.lines().collect();
+    let mut lines: Vec<String> = ddmin(lines, &mut |lines| fails(&lines.join("\n")), &attempts)
+        .into_iter()
+        .map(str::to_owned)
+        .collect();
+
+    for i in 0..lines.len() {
+        let chars: Vec<char> = lines[i].chars().collect();
+        let reduced = ddmin(
+            chars,
+            &mut |chars| {
+                let mut candidate = lines.clone();
+                candidate[i] = chars.iter().collect();
+                fails(&candidate.join("\n"))
+            },
+            &attempts,
+        );
+        lines[i] = reduced.into_iter().collect();
+    }
+
+    lines.join("\n")
+}
+
+/// Path of the repro written by [crate::Solution::run] for a day. See [REDUCE_ENV]
+pub fn repro_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("repro_DAY_{:02}.txt", day))
+}
+
+/// Writes the reduced input of `S` to [repro_path] when [REDUCE_ENV] is set. The input is
+/// only read then.
+pub(crate) fn reduce_on_parse_error<S: Solution + ?Sized>(input: impl FnOnce() -> Option<String>) {
+    if !std::env::var(REDUCE_ENV).is_ok_and(|value| value.trim() == "1") {
+        return;
+    }
+
+    if let Some(input) = input() {
+        write_repro::<S>(Path::new("."), &input);
+    }
+}
+
+fn write_repro<S: Solution + ?Sized>(dir: &Path, input: &str) {
+    let path = dir.join(repro_path(S::DAY));
+    match std::fs::write(&path, reduce_input::<S>(input)) {
+        Ok(()) => logging::info(format_args!(
+            "Note: the reduced input of day {} is in {}",
+            S::DAY,
+            path.display()
+        )),
+        Err(e) => logging::warn(format_args!(
+            "WARNING: couldn't write the reduced input of day {}: {}",
+            S::DAY,
+            e
+        )),
+    }
+}
+
+/// Kind of the error of `parse`, `None` when it parses or panics.
+fn parse_error<S: Solution + ?Sized>(input: &str) -> Option<ErrorKind> {
+    panic::catch_unwind(AssertUnwindSafe(|| S::parse_ctx(input, S::DAY, S::YEAR)))
+        .ok()?
+        .err()
+        .map(|e| e.kind())
+}
+
+/// Delta debugging: removes chunks of `items` while `fails` holds, splitting them further when
+/// no chunk can be removed. Stops when `attempts` runs out.
+fn ddmin<T: Clone>(
+    mut items: Vec<T>,
+    fails: &mut dyn FnMut(&[T]) -> bool,
+    attempts: &Cell<usize>,
+) -> Vec<T> {
+    let mut chunks = 2;
+
+    while items.len() > 1 && attempts.get() > 0 {
+        let size = items.len().div_ceil(chunks);
+        let removed = (0..items.len()).step_by(size).find_map(|start| {
+            let candidate: Vec<T> = items[..start]
+                .iter()
+                .chain(&items[(start + size).min(items.len())..])
+                .cloned()
+                .collect();
+
+            fails(&candidate).then_some(candidate)
+        });
+
+        match removed {
+            Some(candidate) => {
+                items = candidate;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks < items.len() => chunks = (chunks * 2).min(items.len()),
+            None => break,
+        }
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Result, SolutionError};
+    use crate::test_util::{env_lock, temp_dir};
+
+    /// Fails on any line containing an "x".
+    struct NoX;
+    impl Solution for NoX {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 7;
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            match input.lines().position(|line| line.contains('x')) {
+                Some(i) => Err(SolutionError::parse(format!("x on line {}", i + 1))),
+                None => Ok(input.lines().count()),
+            }
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+
+        fn get_input() -> Result<String> {
+            Ok(big_input())
+        }
+    }
+
+    fn big_input() -> String {
+        (0..10_000)
+            .map(|i| match i {
+                6_421 => "abc x def".to_owned(),
+                i => format!("{} {}", i, i * 3),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn reduces_to_a_single_line() {
+        assert_eq!(reduce_input::<NoX>(&big_input()), "x");
+        assert_eq!(reduce_input::<NoX>("1\nax\n2\nxx\n3"), "x");
+    }
+
+    #[test]
+    fn parsing_inputs_are_unchanged() {
+        assert_eq!(reduce_input::<NoX>("1\n2\n3"), "1\n2\n3");
+    }
+
+    #[test]
+    fn chunks_are_removed_within_the_attempts() {
+        let reduce = |max| {
+            let attempts = Cell::new(max);
+            let reduced = ddmin(
+                (0..100).collect(),
+                &mut |items| {
+                    attempts.set(attempts.get() - 1);
+                    items.contains(&42)
+                },
+                &attempts,
+            );
+
+            (reduced, max - attempts.get())
+        };
+
+        let (reduced, used) = reduce(100);
+        assert_eq!(reduced, [42]);
+        assert!(used < 100);
+
+        let (reduced, used) = reduce(3);
+        assert!(reduced.len() > 1 && reduced.contains(&42));
+        assert_eq!(used, 3);
+    }
+
+    #[test]
+    fn repro_file() {
+        let dir = temp_dir("debug-repro");
+
+        write_repro::<NoX>(&dir, &NoX::get_input().unwrap());
+
+        let repro = std::fs::read_to_string(dir.join("repro_DAY_07.txt")).unwrap();
+        assert_eq!(repro, "x");
+        assert_eq!(NoX::run_with_input(&repro).err().unwrap(), ErrorKind::Parse);
+    }
+
+    #[test]
+    fn parallel_runs_write_a_repro() {
+        let _lock = env_lock();
+        std::env::set_var(REDUCE_ENV, "1");
+
+        let result = NoX::run_par();
+        std::env::remove_var(REDUCE_ENV);
+        let repro = std::fs::read_to_string(repro_path(NoX::DAY));
+        let _ = std::fs::remove_file(repro_path(NoX::DAY));
+
+        assert_eq!(result.err().unwrap(), ErrorKind::Parse);
+        assert_eq!(repro.unwrap(), "x");
+    }
+}
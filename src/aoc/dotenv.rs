@@ -0,0 +1,123 @@
+//! Minimal reader of `.env` files, used to find the session cookie. See [crate::client::session]
+//!
+//! `.env` and `.aoc.env` files are looked up in the current directory and its ancestors,
+//! up to the workspace root (the first directory holding a `Cargo.lock` or a `.git`).
+//! They hold `KEY=VALUE` lines, optionally quoted, with `#` comments.
+
+use std::path::{Path, PathBuf};
+
+/// Names of the files read, in order of preference within a directory.
+const FILE_NAMES: [&str; 2] = [".env", ".aoc.env"];
+
+/// Parses the `KEY=VALUE` lines of a `.env` file. Invalid lines are ignored.
+pub(crate) fn parse(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            if line.starts_with('#') {
+                return None;
+            }
+
+            let (key, value) = line.split_once('=')?;
+            let key = key.trim();
+            if key.is_empty() {
+                return None;
+            }
+
+            Some((key.to_owned(), unquote(value.trim())))
+        })
+        .collect()
+}
+
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if let Some(rest) = value.strip_prefix(quote) {
+            if let Some(end) = rest.find(quote) {
+                return rest[..end].to_owned();
+            }
+        }
+    }
+
+    match value.find(" #") {
+        Some(comment) => value[..comment].trim_end().to_owned(),
+        None => value.to_owned(),
+    }
+}
+
+/// Candidate `.env` files, from `dir` up to the workspace root, nearest first.
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+
+    for dir in dir.ancestors() {
+        files.extend(FILE_NAMES.iter().map(|name| dir.join(name)));
+
+        if dir.join("Cargo.lock").exists() || dir.join(".git").exists() {
+            break;
+        }
+    }
+
+    files
+}
+
+/// Value of `key` in the nearest `.env` file defining it, along with the file's path.
+pub(crate) fn lookup(key: &str, dir: &Path) -> Option<(String, PathBuf)> {
+    files(dir).into_iter().find_map(|path| {
+        let content = std::fs::read_to_string(&path).ok()?;
+
+        parse(&content)
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| (value, path))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn parse_lines() {
+        let content = "# comment\r\n\r\nAOC_SESSION=abc123\r\nexport YEAR = 2022 # inline\r\nQUOTED=\"a # b\"\r\nSINGLE='x=y'\r\nnot a pair\r\n=value\r\n";
+
+        assert_eq!(
+            parse(content),
+            vec![
+                ("AOC_SESSION".to_owned(), "abc123".to_owned()),
+                ("YEAR".to_owned(), "2022".to_owned()),
+                ("QUOTED".to_owned(), "a # b".to_owned()),
+                ("SINGLE".to_owned(), "x=y".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn lookup_nearest_file() {
+        let root = temp_dir("dotenv");
+        let nested = root.join("day01/src");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join("Cargo.lock"), "").unwrap();
+        std::fs::write(root.join(".env"), "AOC_SESSION=root\nOTHER=1").unwrap();
+        std::fs::write(root.join("day01/.aoc.env"), "AOC_SESSION='nested'").unwrap();
+
+        assert_eq!(
+            lookup("AOC_SESSION", &nested),
+            Some(("nested".to_owned(), root.join("day01/.aoc.env")))
+        );
+        assert_eq!(
+            lookup("OTHER", &nested),
+            Some(("1".to_owned(), root.join(".env")))
+        );
+        assert_eq!(lookup("MISSING", &nested), None);
+    }
+
+    #[test]
+    fn lookup_without_files() {
+        let root = temp_dir("dotenv-missing");
+        std::fs::create_dir(root.join(".git")).unwrap();
+
+        assert_eq!(lookup("AOC_SESSION", &root), None);
+    }
+}
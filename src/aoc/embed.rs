@@ -0,0 +1,190 @@
+//! Inputs embedded in the binary at build time, to ship a single binary running every day
+//!
+//! Only available with the `embed-inputs` feature. [generate] is called from the `build.rs`
+//! of the solutions crate, scanning the input directory:
+//!
+//! ```no_run
+//! // build.rs, in `fn main`:
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//!
+//! aoc::embed::generate(out_dir).expect("inputs should be embedded");
+//! ```
+//!
+//! The generated lookup is then registered once, at the start of `main`, with
+//! [crate::embed_inputs!]. From there, the default [crate::Solution::get_input] returns the
+//! embedded input of the day before touching the filesystem. `AOC_INPUT=-` still reads the
+//! standard input.
+//!
+//! Both layouts are embedded: `inputs/DAY_07.txt` for any year and `inputs/2022/DAY_07.txt`
+//! for [crate::Solution::YEAR] `2022`. The example inputs are not. Files larger than
+//! [DEFAULT_MAX_SIZE] (see [generate_from]) are skipped with a build warning.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::input;
+use crate::solution::Result;
+
+/// Name of the file written by [generate] in the output directory.
+pub const GENERATED_FILE: &str = "embedded_inputs.rs";
+
+/// Default size limit of an embedded input, in bytes.
+pub const DEFAULT_MAX_SIZE: u64 = 1024 * 1024;
+
+/// Lookup of an embedded input by year and day, as generated by [generate].
+pub type Lookup = fn(Option<u16>, u8) -> Option<&'static str>;
+
+static LOOKUP: OnceLock<Lookup> = OnceLock::new();
+
+/// Generates the lookup of the inputs of [crate::input::input_dir] in `out_dir`,
+/// skipping the files larger than [DEFAULT_MAX_SIZE]. See [generate_from]
+pub fn generate(out_dir: impl AsRef<Path>) -> Result<PathBuf> {
+    generate_from(&input::input_dir(), out_dir.as_ref(), DEFAULT_MAX_SIZE)
+}
+
+/// Generates the lookup of the inputs of `input_dir` in `out_dir`, and returns its path.
+///
+/// The generated file holds a single function, named `embedded_input` and matching [Lookup],
+/// including the inputs with `include_str!`. Files larger than `max_size` bytes are skipped
+/// with a `cargo:warning`, and cargo is asked to rerun the build script when `input_dir`
+/// changes.
+pub fn generate_from(input_dir: &Path, out_dir: &Path, max_size: u64) -> Result<PathBuf> {
+    println!("cargo:rerun-if-changed={}", input_dir.display());
+    println!("cargo:rerun-if-env-changed={}", input::INPUT_DIR_ENV);
+
+    let mut arms = String::new();
+    for (year, day, path) in scan(input_dir)? {
+        let size = std::fs::metadata(&path)?.len();
+        if size > max_size {
+            println!(
+                "cargo:warning=Not embedding {}: {} bytes is over the {} bytes limit",
+                path.display(),
+                size,
+                max_size
+            );
+            continue;
+        }
+
+        let path = path.canonicalize()?;
+        let _ = writeln!(
+            arms,
+            "        ({:?}, {}) => Some(include_str!({:?})),",
+            year, day, path
+        );
+    }
+
+    let source = format!(
+        "/// Embedded inputs, generated by `aoc::embed::generate`.\n\
+         #[allow(clippy::match_single_binding)]\n\
+         fn embedded_input(year: Option<u16>, day: u8) -> Option<&'static str> {{\n    \
+             match (year, day) {{\n{}        _ => None,\n    }}\n}}\n",
+        arms
+    );
+
+    let path = out_dir.join(GENERATED_FILE);
+    std::fs::write(&path, source)?;
+
+    Ok(path)
+}
+
+/// Registers the lookup used by the default [crate::Solution::get_input].
+/// Only the first registration is kept. See [crate::embed_inputs!]
+pub fn register(lookup: Lookup) {
+    let _ = LOOKUP.set(lookup);
+}
+
+/// Embedded input of the day, from the `year` layout first then the flat one.
+/// `None` when no lookup is registered or the input is not embedded.
+pub fn embedded(year: Option<u16>, day: u8) -> Option<&'static str> {
+    let lookup = LOOKUP.get()?;
+
+    year.and_then(|_| lookup(year, day))
+        .or_else(|| lookup(None, day))
+}
+
+/// Inputs of `input_dir`, as `(year, day, path)`, sorted.
+fn scan(input_dir: &Path) -> Result<Vec<(Option<u16>, u8, PathBuf)>> {
+    let mut inputs = Vec::new();
+    if !input_dir.is_dir() {
+        println!(
+            "cargo:warning=No input embedded: {} is not a directory",
+            input_dir.display()
+        );
+        return Ok(inputs);
+    }
+
+    for entry in std::fs::read_dir(input_dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            let Some(year) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<u16>().ok())
+            else {
+                continue;
+            };
+
+            for entry in std::fs::read_dir(&path)? {
+                let path = entry?.path();
+                if let Some(day) = day_of(&path) {
+                    inputs.push((Some(year), day, path));
+                }
+            }
+        } else if let Some(day) = day_of(&path) {
+            inputs.push((None, day, path));
+        }
+    }
+    inputs.sort();
+
+    Ok(inputs)
+}
+
+/// Day of an input file named like the default template: `DAY_07.txt`.
+fn day_of(path: &Path) -> Option<u8> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix("DAY_")?
+        .strip_suffix(".txt")?
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn days_of_inputs() {
+        assert_eq!(day_of(Path::new("inputs/DAY_07.txt")), Some(7));
+        assert_eq!(day_of(Path::new("inputs/2022/DAY_25.txt")), Some(25));
+        assert_eq!(day_of(Path::new("inputs/DAY_07_example.txt")), None);
+        assert_eq!(day_of(Path::new("inputs/DAY_07.txt.enc")), None);
+        assert_eq!(day_of(Path::new("inputs/DAY_26.txt")), None);
+        assert_eq!(day_of(Path::new("inputs/checksums.toml")), None);
+    }
+
+    #[test]
+    fn generated_lookup() {
+        let dir = temp_dir("embed-inputs");
+        let inputs = dir.join("inputs");
+        std::fs::create_dir_all(inputs.join("2022")).unwrap();
+        std::fs::write(inputs.join("DAY_07.txt"), "$ cd /").unwrap();
+        std::fs::write(inputs.join("DAY_08.txt"), "30373".repeat(10)).unwrap();
+        std::fs::write(inputs.join("DAY_07_example.txt"), "$ ls").unwrap();
+        std::fs::write(inputs.join("2022/DAY_01.txt"), "1000").unwrap();
+
+        let path = generate_from(&inputs, &dir, 10).unwrap();
+        let source = std::fs::read_to_string(&path).unwrap();
+
+        assert_eq!(path, dir.join(GENERATED_FILE));
+        assert!(source.contains("fn embedded_input(year: Option<u16>, day: u8)"));
+        assert!(source.contains("(None, 7) => Some(include_str!("));
+        assert!(source.contains("(Some(2022), 1) => Some(include_str!("));
+        assert!(!source.contains("(None, 8)"), "{}", source);
+        assert!(!source.contains("example"), "{}", source);
+    }
+}
@@ -0,0 +1,223 @@
+//! Encryption of the input files, so they can be committed without publishing them
+//!
+//! Only available with the `encrypt` feature. Re-exported in [crate::input].
+//!
+//! An input is encrypted with ChaCha20-Poly1305 next to the plain file, eg:
+//! `inputs/DAY_07.txt.enc`. The key is any secret text, read from `AOC_INPUT_KEY` or from the
+//! file at `AOC_INPUT_KEY_FILE`. Keep the plain files out of the repository (`.gitignore`)
+//! and commit the `.enc` ones.
+//!
+//! The default [crate::Solution::get_input] reads an encrypted input when the plain file is
+//! missing. The `aoc-encrypt-inputs` binary encrypts every input of the input directory:
+//!
+//! ```shell
+//! cargo install aoc-runner --features encrypt --bin aoc-encrypt-inputs
+//! AOC_INPUT_KEY=<secret> aoc-encrypt-inputs
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::solution::{Result, SolutionError};
+
+/// Environment variable holding the key of the encrypted inputs.
+pub const INPUT_KEY_ENV: &str = "AOC_INPUT_KEY";
+
+/// Environment variable holding the path of a file containing the key, when
+/// `AOC_INPUT_KEY` is not set.
+pub const INPUT_KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+
+/// Extension appended to the path of an encrypted input.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Header of the encrypted files, with the format version.
+const MAGIC: &[u8] = b"aoc-enc\x01";
+
+/// Key of the encrypted inputs: `AOC_INPUT_KEY`, or the content of `AOC_INPUT_KEY_FILE`.
+/// `None` when neither is set.
+pub fn input_key() -> Result<Option<String>> {
+    if let Some(key) = std::env::var(INPUT_KEY_ENV)
+        .ok()
+        .filter(|key| !key.trim().is_empty())
+    {
+        return Ok(Some(key.trim().to_owned()));
+    }
+
+    match std::env::var_os(INPUT_KEY_FILE_ENV) {
+        Some(path) => std::fs::read_to_string(&path)
+            .map(|key| Some(key.trim().to_owned()))
+            .map_err(|e| {
+                SolutionError::Encryption(format!(
+                    "can't read the key file {}: {}",
+                    Path::new(&path).display(),
+                    e
+                ))
+            }),
+        None => Ok(None),
+    }
+}
+
+/// Path of the encrypted version of an input: `inputs/DAY_07.txt` gives `inputs/DAY_07.txt.enc`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(ENCRYPTED_EXTENSION);
+
+    PathBuf::from(path)
+}
+
+fn cipher(key: &str) -> LessSafeKey {
+    // Any secret text is accepted as key, hashed to the 256 bits of ChaCha20.
+    let key = digest(&SHA256, key.as_bytes());
+
+    LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, key.as_ref()).expect("256 bits key"))
+}
+
+/// Encrypts an input with `key`.
+pub fn encrypt(input: &str, key: &str) -> Result<Vec<u8>> {
+    let mut nonce = [0; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| SolutionError::Encryption("no random source".to_owned()))?;
+
+    let mut sealed = input.as_bytes().to_vec();
+    cipher(key)
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(MAGIC),
+            &mut sealed,
+        )
+        .map_err(|_| SolutionError::Encryption("encryption failed".to_owned()))?;
+
+    Ok([MAGIC, &nonce, &sealed].concat())
+}
+
+/// Decrypts an input encrypted by [encrypt] with the same `key`.
+///
+/// # Example
+/// ```
+/// use aoc::input::{decrypt, encrypt};
+///
+/// let encrypted = encrypt("1721\n979", "secret").unwrap();
+///
+/// assert_eq!(decrypt(&encrypted, "secret").unwrap(), "1721\n979");
+/// assert!(decrypt(&encrypted, "guess").is_err());
+/// ```
+pub fn decrypt(data: &[u8], key: &str) -> Result<String> {
+    let invalid = || SolutionError::Encryption("wrong key or corrupted file".to_owned());
+
+    let data = data.strip_prefix(MAGIC).ok_or_else(|| {
+        SolutionError::Encryption("not an encrypted input, or an unknown version".to_owned())
+    })?;
+    if data.len() < NONCE_LEN {
+        return Err(invalid());
+    }
+
+    let (nonce, sealed) = data.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| invalid())?;
+    let mut sealed = sealed.to_vec();
+    let plain = cipher(key)
+        .open_in_place(nonce, Aad::from(MAGIC), &mut sealed)
+        .map_err(|_| invalid())?;
+
+    String::from_utf8(plain.to_vec()).map_err(|_| invalid())
+}
+
+/// Encrypts the input file at `path` with `key` to its [encrypted_path], and returns it.
+///
+/// The plain file is kept. An encrypted file already holding the same input is left untouched,
+/// so encrypting again doesn't change the committed files.
+pub fn encrypt_file(path: &Path, key: &str) -> Result<PathBuf> {
+    let input = crate::input::read(path)?;
+    let encrypted = encrypted_path(path);
+
+    let unchanged = std::fs::read(&encrypted)
+        .ok()
+        .and_then(|data| decrypt(&data, key).ok())
+        .is_some_and(|previous| previous == input);
+
+    if !unchanged {
+        std::fs::write(&encrypted, encrypt(&input, key)?)?;
+    }
+
+    Ok(encrypted)
+}
+
+/// Decrypts the encrypted input at `path` with the [input_key].
+pub(crate) fn read_encrypted(path: &Path) -> Result<String> {
+    let key = input_key()?.ok_or_else(|| {
+        SolutionError::Encryption(format!(
+            "{} is encrypted: set {} (or {}) to decrypt it",
+            path.display(),
+            INPUT_KEY_ENV,
+            INPUT_KEY_FILE_ENV
+        ))
+    })?;
+    let data = std::fs::read(path)?;
+
+    decrypt(&data, &key)
+        .map_err(|e| SolutionError::Encryption(format!("{}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{env_lock, temp_dir};
+
+    #[test]
+    fn round_trip() {
+        let encrypted = encrypt("$ cd /\n$ ls", "temp key").unwrap();
+
+        assert!(encrypted.starts_with(MAGIC));
+        assert!(!String::from_utf8_lossy(&encrypted).contains("$ cd /"));
+        assert_eq!(decrypt(&encrypted, "temp key").unwrap(), "$ cd /\n$ ls");
+        assert_ne!(encrypt("$ cd /\n$ ls", "temp key").unwrap(), encrypted);
+    }
+
+    #[test]
+    fn wrong_key() {
+        let encrypted = encrypt("input", "temp key").unwrap();
+
+        let error = decrypt(&encrypted, "other key").unwrap_err();
+
+        assert!(error.to_string().contains("wrong key"), "{}", error);
+        assert!(decrypt(b"input", "temp key").is_err());
+        assert!(decrypt(&encrypted[..MAGIC.len() + 4], "temp key").is_err());
+    }
+
+    #[test]
+    fn encrypt_file_once() {
+        let dir = temp_dir("encrypt-file");
+        let path = dir.join("DAY_01.txt");
+        std::fs::write(&path, "1721\n979").unwrap();
+
+        let encrypted = encrypt_file(&path, "temp key").unwrap();
+        let first = std::fs::read(&encrypted).unwrap();
+        encrypt_file(&path, "temp key").unwrap();
+
+        assert_eq!(encrypted, dir.join("DAY_01.txt.enc"));
+        assert_eq!(std::fs::read(&encrypted).unwrap(), first);
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn key_from_file() {
+        let _lock = env_lock();
+        let path = temp_dir("input-key-file").join("key");
+        std::fs::write(&path, "from file\n").unwrap();
+
+        std::env::set_var(INPUT_KEY_FILE_ENV, &path);
+        let from_file = input_key().unwrap();
+        std::env::set_var(INPUT_KEY_ENV, "from env");
+        let from_env = input_key().unwrap();
+        std::env::remove_var(INPUT_KEY_ENV);
+        std::env::remove_var(INPUT_KEY_FILE_ENV);
+
+        assert_eq!(from_file.as_deref(), Some("from file"));
+        assert_eq!(from_env.as_deref(), Some("from env"));
+        assert_eq!(input_key().unwrap(), None);
+    }
+}
@@ -0,0 +1,335 @@
+//! Reports of the errors of a day, as printed by [crate::solution!]
+//!
+//! A report is the error followed by its causes, the line of the input where it is, if known,
+//! and a hint saying what to do about it:
+//!
+//! ```text
+//! error: Invalid Puzzle input: invalid integer "x": invalid digit found in string at line 1, column 5
+//!  --> line 1, column 5
+//! 1 | 3,4,x,4
+//!   |     ^
+//! hint: the input doesn't parse at line 1, column 5: check the input or Solution::parse
+//! ```
+//!
+//! With the `color` feature, the `error` and `hint` labels are colored when stderr is a terminal
+//! and `NO_COLOR` is not set.
+
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::input;
+use crate::logging::{self, error_chain};
+use crate::solution::{ErrorKind, SolutionError};
+
+/// Environment variable holding the session cookie, as read by the `fetch` feature.
+const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The report of `err`: the error with its causes, then its [hint].
+///
+/// # Example
+/// ```
+/// use aoc::solution::SolutionError;
+///
+/// let error = SolutionError::Network("invalid session: the cookie was refused".to_owned());
+///
+/// assert!(aoc::error::report(&error).ends_with("refresh AOC_SESSION: log in on adventofcode.com and copy the `session` cookie"));
+/// ```
+pub fn report(err: &SolutionError) -> String {
+    render(err, None, color_enabled())
+}
+
+/// Same as [report], with the line of `input` where the error is under it.
+/// (See [crate::parse::render_diagnostic])
+pub fn report_with_input(err: &SolutionError, input: &str) -> String {
+    render(err, Some(input), color_enabled())
+}
+
+/// What to do about `err`, picked from its [ErrorKind] and what the error knows:
+///
+/// - a missing input: download it, create the file, or read it from elsewhere,
+/// - an input that doesn't parse at a known location: look at that location,
+/// - a refused session cookie: refresh it,
+/// - anything else: the [SolutionError::hint] of the error.
+pub fn hint(err: &SolutionError) -> Option<String> {
+    match err.kind() {
+        ErrorKind::Input => match (err.io_kind(), err.path()) {
+            (Some(std::io::ErrorKind::NotFound), Some(path)) if is_file(path) => Some(format!(
+                "download it with the `download` feature and {} set, create {}, set {}, or override get_input()",
+                SESSION_ENV,
+                path.display(),
+                input::INPUT_DIR_ENV
+            )),
+            _ => err.hint(),
+        },
+        ErrorKind::Parse => match err.location() {
+            Some(location) => Some(format!(
+                "the input doesn't parse at {}: check the input or Solution::parse",
+                location
+            )),
+            None => err.hint(),
+        },
+        ErrorKind::Network if is_invalid_session(err) => Some(format!(
+            "refresh {}: log in on adventofcode.com and copy the `session` cookie",
+            SESSION_ENV
+        )),
+        ErrorKind::Network
+        | ErrorKind::Run
+        | ErrorKind::Answer
+        | ErrorKind::Skipped
+        | ErrorKind::Storage => err.hint(),
+    }
+}
+
+/// Prints the report of the error of a day on stderr, or logs it. (See [crate::logging])
+/// Used by the macros.
+///
+/// The line of the input is the one kept when it failed to parse, rather than read again:
+/// an input from stdin can't be read twice. (See [keep_failed_input])
+#[doc(hidden)]
+pub fn print_day_report(day: u8, year: Option<u16>, title: &str, err: &SolutionError) {
+    let input = failed_inputs().remove(&(year, day));
+    let report = match input.filter(|_| err.location().is_some()) {
+        Some(input) => report_with_input(err, &input),
+        None => report(err),
+    };
+
+    logging::day_report(day, title, &report);
+}
+
+/// Keeps the input of a day that failed to parse at a known location, for [print_day_report].
+pub(crate) fn keep_failed_input(year: Option<u16>, day: u8, err: &SolutionError, input: &str) {
+    if err.location().is_some() {
+        failed_inputs().insert((year, day), input.to_owned());
+    }
+}
+
+/// The inputs that failed to parse, by year and day. Global rather than thread local:
+/// [crate::run_all_par!] parses on a worker thread and reports on the main one.
+fn failed_inputs() -> MutexGuard<'static, BTreeMap<(Option<u16>, u8), String>> {
+    static FAILED_INPUTS: Mutex<BTreeMap<(Option<u16>, u8), String>> = Mutex::new(BTreeMap::new());
+
+    FAILED_INPUTS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether `path` is an actual file, rather than an [input::UNKNOWN_PATH] or a `$VARIABLE`.
+/// (See [input::read_env])
+fn is_file(path: &Path) -> bool {
+    path != Path::new(input::UNKNOWN_PATH) && !path.to_string_lossy().starts_with('$')
+}
+
+fn is_invalid_session(err: &SolutionError) -> bool {
+    matches!(err, SolutionError::Network(message) if message.starts_with("invalid session"))
+}
+
+fn render(err: &SolutionError, input: Option<&str>, color: bool) -> String {
+    let mut report = format!("{}: {}", paint("error", "1;31", color), error_chain(err));
+
+    let diagnostic = input.map(|input| crate::parse::render_diagnostic(input, err));
+    if let Some(diagnostic) = diagnostic.filter(|diagnostic| !diagnostic.is_empty()) {
+        report.push('\n');
+        report.push_str(&diagnostic);
+    }
+
+    if let Some(hint) = hint(err) {
+        report.push_str(&format!("\n{}: {}", paint("hint", "1;36", color), hint));
+    }
+
+    report
+}
+
+fn color_enabled() -> bool {
+    cfg!(feature = "color")
+        && std::env::var_os("NO_COLOR").is_none()
+        && std::io::stderr().is_terminal()
+}
+
+/// `text` with the ANSI style `code` when `color` is set.
+fn paint(text: &str, code: &str, color: bool) -> String {
+    if color {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::input::{InputKind, MissingInput};
+    use crate::solution::Panicked;
+    use crate::Solution;
+
+    #[test]
+    fn missing_input_hint() {
+        let missing = SolutionError::MissingInput(MissingInput {
+            kind: InputKind::Real,
+            attempted: vec!["inputs/DAY_07.txt".into(), "inputs/2022/DAY_07.txt".into()],
+            cwd: "/aoc".into(),
+            created: None,
+        });
+        assert_eq!(
+            hint(&missing).as_deref(),
+            Some("download it with the `download` feature and AOC_SESSION set, create inputs/DAY_07.txt, set AOC_INPUT_DIR, or override get_input()")
+        );
+
+        let not_found = SolutionError::PuzzleInput {
+            path: "inputs/DAY_08.txt".into(),
+            source: Arc::new(io::Error::from(io::ErrorKind::NotFound)),
+        };
+        assert_eq!(
+            hint(&not_found).as_deref(),
+            Some("download it with the `download` feature and AOC_SESSION set, create inputs/DAY_08.txt, set AOC_INPUT_DIR, or override get_input()")
+        );
+    }
+
+    #[test]
+    fn missing_pseudo_path_hint() {
+        let unset = crate::input::read_env("AOC_TEST_UNSET_HINT").unwrap_err();
+        let unknown = SolutionError::from(io::Error::from(io::ErrorKind::NotFound));
+
+        for error in [unset, unknown] {
+            assert_eq!(
+                hint(&error).as_deref(),
+                Some("Check that the input can be read"),
+                "{:?}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn unreadable_input_hint() {
+        let denied = SolutionError::PuzzleInput {
+            path: "inputs/DAY_08.txt".into(),
+            source: Arc::new(io::Error::from(io::ErrorKind::PermissionDenied)),
+        };
+
+        assert_eq!(
+            hint(&denied).as_deref(),
+            Some("Check that the input can be read")
+        );
+    }
+
+    #[test]
+    fn located_parse_error_hint() {
+        let error = crate::parse::csv_ints("3,4,x,4").unwrap_err();
+
+        assert_eq!(
+            hint(&error).as_deref(),
+            Some("the input doesn't parse at line 1, column 5: check the input or Solution::parse")
+        );
+        assert_eq!(
+            render(&error, Some("3,4,x,4"), false),
+            "error: Invalid Puzzle input: invalid integer \"x\": invalid digit found in string at line 1, column 5\n \
+             --> line 1, column 5\n\
+             1 | 3,4,x,4\n  \
+               |     ^\n\
+             hint: the input doesn't parse at line 1, column 5: check the input or Solution::parse"
+        );
+    }
+
+    #[test]
+    fn failed_input_kept_for_the_report() {
+        struct Ints;
+        impl Solution for Ints {
+            const TITLE: &'static str = "";
+            const DAY: u8 = 201;
+            type Input = Vec<i64>;
+            type P1 = usize;
+            type P2 = usize;
+
+            fn parse(input: &str) -> crate::solution::Result<Self::Input> {
+                crate::parse::csv_ints(input)
+            }
+
+            fn part1(input: &Self::Input) -> Option<Self::P1> {
+                Some(input.len())
+            }
+
+            fn part2(input: &Self::Input) -> Option<Self::P2> {
+                Some(input.len())
+            }
+
+            fn get_input() -> crate::solution::Result<String> {
+                unreachable!("the input is kept, not read again")
+            }
+        }
+
+        let error = Ints::run_with_input("3,4,x,4").err().unwrap();
+        assert_eq!(
+            failed_inputs().get(&(None, 201)).map(String::as_str),
+            Some("3,4,x,4")
+        );
+
+        print_day_report(201, None, "", &error);
+        assert!(!failed_inputs().contains_key(&(None, 201)));
+    }
+
+    #[test]
+    fn parse_error_hint() {
+        let error = SolutionError::parse("not a number");
+
+        assert_eq!(
+            hint(&error).as_deref(),
+            Some("parse() failed: the input was read, check Solution::parse")
+        );
+        assert_eq!(
+            render(&error, Some("12\n3"), false),
+            "error: Invalid Puzzle input: not a number\n\
+             hint: parse() failed: the input was read, check Solution::parse"
+        );
+    }
+
+    #[test]
+    fn invalid_session_hint() {
+        let error = SolutionError::Network(
+            "invalid session: the cookie from the AOC_SESSION environment variable was refused, \
+             set a fresh one"
+                .to_owned(),
+        );
+
+        assert_eq!(
+            hint(&error).as_deref(),
+            Some("refresh AOC_SESSION: log in on adventofcode.com and copy the `session` cookie")
+        );
+        assert_eq!(hint(&SolutionError::Network("offline".to_owned())), None);
+        assert_eq!(
+            render(&SolutionError::Network("offline".to_owned()), None, false),
+            "error: Request to Advent of Code failed: offline"
+        );
+    }
+
+    #[test]
+    fn other_hints() {
+        let part = SolutionError::Part {
+            part: 2,
+            source: Arc::new(Panicked("boom".to_owned())),
+        };
+
+        assert_eq!(
+            hint(&part).as_deref(),
+            Some("Solution::part2 failed, see the panic message above")
+        );
+        assert_eq!(
+            render(&part, None, false),
+            "error: Error while running part 2: panicked: boom\n\
+             hint: Solution::part2 failed, see the panic message above"
+        );
+    }
+
+    #[test]
+    fn colored_labels() {
+        let error = SolutionError::parse("not a number");
+
+        assert_eq!(
+            render(&error, None, true),
+            "\x1b[1;31merror\x1b[0m: Invalid Puzzle input: not a number\n\
+             \x1b[1;36mhint\x1b[0m: parse() failed: the input was read, check Solution::parse"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Compile-time parsing of the day and year of a solution from its file path
+//!
+//! Used by the [crate::day_from_file!] and [crate::year_from_file!] macros, so that
+//! `const DAY` and `const YEAR` can't get out of sync with the file name.
+//!
+//! The numbers are read from the end of the path, file name first then its directories:
+//! - the day is the last number of 1 or 2 digits, between 1 and 25
+//! - the year is the last number of 4 digits, from 2015
+//!
+//! So `src/bin/2022_day05.rs`, `examples/aoc_2022_05.rs` and `2022/day05/src/main.rs`
+//! all give day 5 of 2022.
+
+/// Day number of a solution file. Panics (failing the build in a const) when the path has none.
+pub const fn day(path: &str) -> u8 {
+    let bytes = path.as_bytes();
+    let mut end = bytes.len();
+
+    while let Some((start, value)) = previous_number(bytes, end) {
+        if digits(bytes, start) <= 2 {
+            if value == 0 || value > 25 {
+                panic!("the day number in the file path must be between 1 and 25");
+            }
+            return value as u8;
+        }
+        end = start;
+    }
+
+    panic!("no day number found in the file path")
+}
+
+/// Year of a solution file, `None` when the path has none.
+pub const fn year(path: &str) -> Option<u16> {
+    let bytes = path.as_bytes();
+    let mut end = bytes.len();
+
+    while let Some((start, value)) = previous_number(bytes, end) {
+        if digits(bytes, start) == 4 && value >= 2015 {
+            return Some(value as u16);
+        }
+        end = start;
+    }
+
+    None
+}
+
+/// Number of digits of the number starting at `start`.
+const fn digits(bytes: &[u8], start: usize) -> usize {
+    let mut end = start;
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+
+    end - start
+}
+
+/// Start and value of the last number ending before `end`.
+const fn previous_number(bytes: &[u8], end: usize) -> Option<(usize, u32)> {
+    let mut end = end;
+    while end > 0 && !bytes[end - 1].is_ascii_digit() {
+        end -= 1;
+    }
+    if end == 0 {
+        return None;
+    }
+
+    let mut start = end;
+    let mut value: u32 = 0;
+    let mut scale: u32 = 1;
+    while start > 0 && bytes[start - 1].is_ascii_digit() {
+        start -= 1;
+        value = value.saturating_add(scale.saturating_mul((bytes[start] - b'0') as u32));
+        scale = scale.saturating_mul(10);
+    }
+
+    Some((start, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days() {
+        assert_eq!(day("src/bin/2022_day05.rs"), 5);
+        assert_eq!(day("examples/aoc_2020_day01.rs"), 1);
+        assert_eq!(day("src/bin/day25.rs"), 25);
+        assert_eq!(day("2022/day07/src/main.rs"), 7);
+        assert_eq!(day(r"src\bin\day3.rs"), 3);
+    }
+
+    #[test]
+    fn years() {
+        assert_eq!(year("src/bin/2022_day05.rs"), Some(2022));
+        assert_eq!(year("aoc2021/day07/src/main.rs"), Some(2021));
+        assert_eq!(year("src/bin/day05.rs"), None);
+        assert_eq!(year("src/bin/1999_day05.rs"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "between 1 and 25")]
+    fn day_out_of_range() {
+        day("src/bin/day26.rs");
+    }
+
+    #[test]
+    #[should_panic(expected = "no day number")]
+    fn no_day() {
+        day("src/main.rs");
+    }
+}
@@ -0,0 +1,213 @@
+//! Fuzzing of [crate::Solution::parse]
+//!
+//! Use the [crate::fuzz!] macro in your tests rather than calling this module directly.
+//!
+//! Random strings, and mutations of the real input when it is available, are fed to `parse`,
+//! which must return an `Err` on malformed input rather than panic on an `unwrap`.
+//! The generator is seeded, so a failure can be replayed.
+
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::solution::Panicked;
+use crate::Solution;
+
+/// Seed used by [crate::fuzz!] when none is given.
+pub const DEFAULT_SEED: u64 = 0x2015_1201;
+
+/// Bytes favored by the generator, as they are common in puzzle inputs.
+const ALPHABET: &[u8] = b"0123456789-+,.:;#=() \n\nabcdefxyzLRUD";
+
+/// An input on which `parse` panicked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzFailure {
+    pub iteration: usize,
+    pub seed: u64,
+    pub input: String,
+    pub message: String,
+}
+
+impl Display for FuzzFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "parse panicked at iteration {} (seed {:#x}): {}\ninput: {:?}",
+            self.iteration, self.seed, self.message, self.input
+        )
+    }
+}
+
+/// Minimal SplitMix64 generator.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+
+    fn byte(&mut self) -> u8 {
+        if self.below(4) == 0 {
+            self.next() as u8
+        } else {
+            ALPHABET[self.below(ALPHABET.len())]
+        }
+    }
+
+    fn random(&mut self) -> Vec<u8> {
+        let len = self.below(64);
+
+        (0..len).map(|_| self.byte()).collect()
+    }
+
+    fn mutate(&mut self, input: &[u8]) -> Vec<u8> {
+        let mut bytes = input.to_vec();
+
+        for _ in 0..=self.below(4) {
+            let at = self.below(bytes.len() + 1);
+
+            match self.below(5) {
+                0 if at < bytes.len() => bytes[at] = self.byte(),
+                1 => bytes.insert(at, self.byte()),
+                2 if at < bytes.len() => {
+                    bytes.remove(at);
+                }
+                3 => bytes.truncate(at),
+                _ => {
+                    let end = (at + self.below(16)).min(bytes.len());
+                    let chunk = bytes[at..end].to_vec();
+                    bytes.splice(at..at, chunk);
+                }
+            }
+        }
+
+        bytes
+    }
+}
+
+/// Feeds `iterations` generated inputs to `S::parse_ctx`. See [crate::fuzz!]
+///
+/// Half of the inputs are mutations of `S::get_input()` when it succeeds.
+/// Returns the first input on which `parse` panicked.
+pub fn parse<S: Solution + ?Sized>(
+    iterations: usize,
+    seed: u64,
+) -> std::result::Result<(), FuzzFailure> {
+    let real = S::get_input().ok();
+    let mut rng = Rng(seed);
+
+    for iteration in 0..iterations {
+        let bytes = match &real {
+            Some(real) if iteration % 2 == 1 => rng.mutate(real.as_bytes()),
+            _ => rng.random(),
+        };
+        let input = String::from_utf8_lossy(&bytes).into_owned();
+
+        if let Err(panic) =
+            panic::catch_unwind(AssertUnwindSafe(|| S::parse_ctx(&input, S::DAY, S::YEAR)))
+        {
+            let message = Panicked::from_payload(panic.as_ref()).0;
+
+            return Err(FuzzFailure {
+                iteration,
+                seed,
+                input,
+                message,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Result, SolutionError};
+
+    struct Careful;
+    impl Solution for Careful {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = Vec<u32>;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .split(',')
+                .map(|n| {
+                    n.trim()
+                        .parse()
+                        .map_err(|_| SolutionError::parse("not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("1,2,3,40,500".to_owned())
+        }
+    }
+
+    struct Careless;
+    impl Solution for Careless {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = Vec<u32>;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.split(',').map(|n| n.parse().unwrap()).collect())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+
+        fn get_input() -> Result<String> {
+            Careful::get_input()
+        }
+    }
+
+    #[test]
+    fn generator_is_deterministic() {
+        let (mut a, mut b) = (Rng(7), Rng(7));
+
+        assert_eq!(a.random(), b.random());
+        assert_eq!(a.mutate(b"1,2,3"), b.mutate(b"1,2,3"));
+    }
+
+    #[test]
+    fn graceful_parse() {
+        assert_eq!(parse::<Careful>(500, DEFAULT_SEED), Ok(()));
+    }
+
+    #[test]
+    fn panicking_parse() {
+        let failure = parse::<Careless>(500, DEFAULT_SEED).unwrap_err();
+
+        assert_eq!(parse::<Careless>(500, DEFAULT_SEED), Err(failure.clone()));
+        assert!(failure.message.contains("unwrap"), "{}", failure);
+    }
+}
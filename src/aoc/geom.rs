@@ -0,0 +1,412 @@
+//! Coordinates and directions of the grid and walk puzzles
+//!
+//! Like [crate::grid], `x` grows to the right and `y` grows downwards: [Direction::Up] is
+//! `(0, -1)`. Turning right goes clockwise on screen.
+//!
+//! ```
+//! use aoc::geom::{Direction, Point2};
+//!
+//! let mut position = Point2::new(0, 0);
+//! let mut facing: Direction = "N".parse().unwrap();
+//!
+//! for _ in 0..3 {
+//!     position = position + facing.delta() * 2;
+//!     facing = facing.turn_right();
+//! }
+//!
+//! assert_eq!(position, Point2::new(0, 0) + Point2::new(2, 0));
+//! assert_eq!(position.manhattan(), 2);
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::solution::SolutionError;
+
+/// Point or vector of the plane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    /// Manhattan length of the vector: `|x| + |y|`. The distance between `a` and `b` is
+    /// `(a - b).manhattan()`.
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Vector with each coordinate reduced to -1, 0 or 1, eg: the step of a rope's knot
+    /// towards the previous one.
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// The position in a [crate::grid::Grid], when both coordinates are positive.
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2::new(x, y)
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point2::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point2 {
+    fn sub_assign(&mut self, other: Point2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, factor: i64) -> Point2 {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the 4 orthogonal directions.
+///
+/// Parsed from `U`/`D`/`L`/`R`, `^`/`v`/`<`/`>` or `N`/`S`/`W`/`E`, case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// The directions, clockwise from [Direction::Up].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The step of one cell in this direction.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(0, -1),
+            Direction::Right => Point2::new(1, 0),
+            Direction::Down => Point2::new(0, 1),
+            Direction::Left => Point2::new(-1, 0),
+        }
+    }
+
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+impl FromStr for Direction {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "U" | "^" | "N" => Ok(Direction::Up),
+            "R" | ">" | "E" => Ok(Direction::Right),
+            "D" | "V" | "S" => Ok(Direction::Down),
+            "L" | "<" | "W" => Ok(Direction::Left),
+            _ => Err(SolutionError::parse(format!("unknown direction {:?}", s))),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+/// One of the 8 orthogonal and diagonal directions, by compass point: [Direction8::N] is up.
+///
+/// Parsed from the compass points (`N`, `NE`...) or from the forms of [Direction] for the
+/// orthogonal ones, case-insensitively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// The directions, clockwise from [Direction8::N].
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// The step of one cell in this direction.
+    pub fn delta(self) -> Point2 {
+        match self {
+            Direction8::N => Point2::new(0, -1),
+            Direction8::NE => Point2::new(1, -1),
+            Direction8::E => Point2::new(1, 0),
+            Direction8::SE => Point2::new(1, 1),
+            Direction8::S => Point2::new(0, 1),
+            Direction8::SW => Point2::new(-1, 1),
+            Direction8::W => Point2::new(-1, 0),
+            Direction8::NW => Point2::new(-1, -1),
+        }
+    }
+
+    /// The direction an eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction an eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "NE" => Ok(Direction8::NE),
+            "SE" => Ok(Direction8::SE),
+            "SW" => Ok(Direction8::SW),
+            "NW" => Ok(Direction8::NW),
+            orthogonal => orthogonal.parse::<Direction>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p((x, y): (i64, i64)) -> Point2 {
+        Point2::new(x, y)
+    }
+
+    #[test]
+    fn operators() {
+        let cases = [
+            ((1, 2), (3, -4), (4, -2), (-2, 6)),
+            ((0, 0), (0, 0), (0, 0), (0, 0)),
+            ((-5, 7), (5, -7), (0, 0), (-10, 14)),
+        ];
+
+        for (a, b, sum, difference) in cases {
+            let (a, b) = (p(a), p(b));
+
+            assert_eq!(a + b, p(sum), "{} + {}", a, b);
+            assert_eq!(a - b, p(difference), "{} - {}", a, b);
+            assert_eq!(b - a, -(a - b), "{} - {}", b, a);
+            assert_eq!(a + b - b, a);
+
+            let mut c = a;
+            c += b;
+            c -= a;
+            assert_eq!(c, b);
+        }
+    }
+
+    #[test]
+    fn scaling_and_lengths() {
+        let cases = [
+            ((3, -4), 2, (6, -8), 7, (1, -1)),
+            ((0, 5), -3, (0, -15), 5, (0, 1)),
+            ((-2, -2), 0, (0, 0), 4, (-1, -1)),
+            ((0, 0), 7, (0, 0), 0, (0, 0)),
+        ];
+
+        for (point, factor, scaled, manhattan, signum) in cases {
+            let point = p(point);
+
+            assert_eq!(point * factor, p(scaled), "{} * {}", point, factor);
+            assert_eq!(point.manhattan(), manhattan, "|{}|", point);
+            assert_eq!(point.signum(), p(signum), "signum {}", point);
+        }
+
+        assert_eq!((Point2::new(1, 1) - Point2::new(4, -3)).manhattan(), 7);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Point2::from((3usize, 4usize)), Point2::new(3, 4));
+        assert_eq!(Point2::new(3, 4).to_usize(), Some((3, 4)));
+        assert_eq!(Point2::new(-1, 4).to_usize(), None);
+        assert_eq!(Point2::new(3, -4).to_usize(), None);
+        assert_eq!(Point2::new(-3, 4).to_string(), "(-3, 4)");
+    }
+
+    #[test]
+    fn directions() {
+        use Direction::*;
+
+        let cases = [
+            (Up, (0, -1), Right, Left, Down),
+            (Right, (1, 0), Down, Up, Left),
+            (Down, (0, 1), Left, Right, Up),
+            (Left, (-1, 0), Up, Down, Right),
+        ];
+
+        for (direction, delta, right, left, opposite) in cases {
+            assert_eq!(direction.delta(), p(delta), "{:?}", direction);
+            assert_eq!(direction.turn_right(), right, "{:?}", direction);
+            assert_eq!(direction.turn_left(), left, "{:?}", direction);
+            assert_eq!(direction.opposite(), opposite, "{:?}", direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(Direction8::from(direction).delta(), direction.delta());
+        }
+    }
+
+    #[test]
+    fn directions8() {
+        use Direction8::*;
+
+        let cases = [
+            (N, (0, -1), NE, NW, S),
+            (NE, (1, -1), E, N, SW),
+            (E, (1, 0), SE, NE, W),
+            (SE, (1, 1), S, E, NW),
+            (S, (0, 1), SW, SE, N),
+            (SW, (-1, 1), W, S, NE),
+            (W, (-1, 0), NW, SW, E),
+            (NW, (-1, -1), N, W, SE),
+        ];
+
+        for (direction, delta, right, left, opposite) in cases {
+            assert_eq!(direction.delta(), p(delta), "{:?}", direction);
+            assert_eq!(direction.turn_right(), right, "{:?}", direction);
+            assert_eq!(direction.turn_left(), left, "{:?}", direction);
+            assert_eq!(direction.opposite(), opposite, "{:?}", direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+        }
+
+        let full_turn = (0..8).fold(NE, |direction, _| direction.turn_right());
+        assert_eq!(full_turn, NE);
+    }
+
+    #[test]
+    fn parse_directions() {
+        use Direction::*;
+
+        let cases = [
+            ("U", Up),
+            ("^", Up),
+            ("N", Up),
+            ("u", Up),
+            ("D", Down),
+            ("v", Down),
+            ("V", Down),
+            ("S", Down),
+            ("L", Left),
+            ("<", Left),
+            ("W", Left),
+            ("R", Right),
+            (">", Right),
+            (" e ", Right),
+        ];
+
+        for (s, direction) in cases {
+            assert_eq!(s.parse::<Direction>().unwrap(), direction, "{:?}", s);
+            assert_eq!(
+                s.parse::<Direction8>().unwrap(),
+                Direction8::from(direction),
+                "{:?}",
+                s
+            );
+        }
+
+        for s in ["", "X", "UP", "NE", "<>"] {
+            assert!(s.parse::<Direction>().is_err(), "{:?}", s);
+        }
+
+        assert_eq!("ne".parse::<Direction8>().unwrap(), Direction8::NE);
+        assert_eq!("SW".parse::<Direction8>().unwrap(), Direction8::SW);
+        assert!("NS".parse::<Direction8>().is_err());
+    }
+}
@@ -0,0 +1,404 @@
+//! 2D grid of cells, the input of the map and simulation puzzles
+//!
+//! The cells are stored row by row and addressed by `(x, y)`: `x` is the column from the left,
+//! `y` the row from the top, both starting at 0.
+//!
+//! ```
+//! use aoc::grid::Grid;
+//!
+//! let grid = Grid::from_str("#.\n..", |c| Some(c == '#')).unwrap();
+//!
+//! assert_eq!((grid.width(), grid.height()), (2, 2));
+//! assert!(grid[(0, 0)]);
+//! assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+//! ```
+
+use std::ops::{Index, IndexMut};
+
+use crate::geom::Point2;
+use crate::solution::{Result, SolutionError};
+
+/// Offsets of the orthogonal neighbours: up, left, right, down.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the orthogonal and diagonal neighbours, row by row.
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangular grid of cells. See the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses one cell per character of the input with `parse`, row by row.
+    ///
+    /// A character refused by `parse` is reported as [SolutionError::InvalidCell], and a row of
+    /// another width than the first one as [SolutionError::RaggedRow], with their 1-based
+    /// position. The trailing blank lines are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::grid::Grid;
+    /// use aoc::solution::SolutionError;
+    ///
+    /// let heights = Grid::from_str("219\n398", |c| c.to_digit(10)).unwrap();
+    /// assert_eq!(heights[(2, 1)], 8);
+    ///
+    /// let ragged = Grid::from_str("219\n39", |c| c.to_digit(10));
+    /// assert!(matches!(ragged, Err(SolutionError::RaggedRow { line: 2, .. })));
+    /// ```
+    pub fn from_str(input: &str, parse: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let rows = parse_rows(input, parse)?;
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Grid of `width` columns with the given cells, row by row.
+    ///
+    /// # Panics
+    /// When the number of cells is not a multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            cells
+                .len()
+                .checked_rem(width)
+                .map_or(cells.is_empty(), |rest| rest == 0),
+            "{} cells don't fill rows of width {}",
+            cells.len(),
+            width
+        );
+
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` is inside the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// The cell at `point`, `None` outside the grid. The grid is also indexed by [Point2].
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::geom::{Direction, Point2};
+    /// use aoc::grid::Grid;
+    ///
+    /// let grid = Grid::from_str("12\n34", |c| c.to_digit(10)).unwrap();
+    /// let corner = Point2::new(1, 1);
+    ///
+    /// assert_eq!(grid[corner + Direction::Up.delta()], 2);
+    /// assert_eq!(grid.get_point(corner + Direction::Right.delta()), None);
+    /// assert_eq!(grid.get_point(Point2::new(-1, 0)), None);
+    /// ```
+    pub fn get_point(&self, point: Point2) -> Option<&T> {
+        let (x, y) = point.to_usize()?;
+        self.get(x, y)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        match self.contains(x, y) {
+            true => Some(&mut self.cells[y * self.width + x]),
+            false => None,
+        }
+    }
+
+    /// The cells with their position, row by row.
+    pub fn iter_with_pos(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    /// Positions of the up to 4 orthogonal neighbours of `(x, y)` inside the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.around(x, y, &ORTHOGONAL)
+    }
+
+    /// Positions of the up to 8 orthogonal and diagonal neighbours of `(x, y)` inside the grid.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.around(x, y, &ALL_AROUND)
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::grid::Grid;
+    ///
+    /// let grid = Grid::from_str("12\n34", |c| c.to_digit(10)).unwrap();
+    ///
+    /// assert_eq!(grid.map(|n| n * 10)[(1, 1)], 40);
+    /// ```
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn around<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// When `(x, y)` is outside the grid.
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside the {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, width, height))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// When `point` is outside the grid.
+    fn index(&self, point: Point2) -> &T {
+        self.get_point(point).unwrap_or_else(|| {
+            panic!(
+                "{} is outside the {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, point: Point2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        point
+            .to_usize()
+            .and_then(|(x, y)| self.get_mut(x, y))
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", point, width, height))
+    }
+}
+
+/// Parses one cell per character of the input, row by row. See [Grid::from_str]
+pub(crate) fn parse_rows<T>(
+    input: &str,
+    mut parse: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for (index, line) in input.trim_end().lines().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(column, c)| {
+                parse(c).ok_or(SolutionError::InvalidCell {
+                    line: index + 1,
+                    column: column + 1,
+                    found: c,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(first) = rows.first() {
+            if first.len() != row.len() {
+                return Err(SolutionError::RaggedRow {
+                    line: index + 1,
+                    width: row.len(),
+                    expected: first.len(),
+                });
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered() -> Grid<u32> {
+        Grid::from_str("123\n456\n789\n", |c| c.to_digit(10)).unwrap()
+    }
+
+    fn sorted(positions: impl Iterator<Item = (usize, usize)>) -> Vec<(usize, usize)> {
+        let mut positions: Vec<_> = positions.collect();
+        positions.sort();
+        positions
+    }
+
+    #[test]
+    fn parse_grid() {
+        let grid = numbered();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 2)], 7);
+
+        let windows = Grid::from_str("12\r\n34\r\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(windows.map(|n| *n), Grid::from_cells(2, vec![1, 2, 3, 4]));
+
+        let empty = Grid::from_str("", |c| c.to_digit(10)).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.neighbors8(0, 0).count(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Grid::from_str("12\n3?", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "Invalid Puzzle input at line 2, column 2: '?'"
+        );
+        assert_eq!(
+            Grid::from_str("12\n34\n567", |c| c.to_digit(10))
+                .unwrap_err()
+                .to_string(),
+            "Invalid Puzzle input at line 3: 3 cells wide, expected 2"
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = numbered();
+
+        assert_eq!(grid.get(2, 2), Some(&9));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get(usize::MAX, usize::MAX), None);
+
+        grid[(1, 1)] = 0;
+        assert_eq!(grid.get(1, 1), Some(&0));
+        assert!(grid.get_mut(3, 3).is_none());
+    }
+
+    #[test]
+    fn point_indexing() {
+        let mut grid = numbered();
+
+        assert_eq!(grid[Point2::new(2, 1)], 6);
+        assert_eq!(grid.get_point(Point2::new(0, -1)), None);
+        assert_eq!(grid.get_point(Point2::new(3, 0)), None);
+
+        grid[Point2::new(0, 2)] = 0;
+        assert_eq!(grid[(0, 2)], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "(-1, 0) is outside the 3x3 grid")]
+    fn point_out_of_bounds() {
+        let _ = numbered()[Point2::new(-1, 0)];
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside the 3x3 grid")]
+    fn index_out_of_bounds() {
+        let _ = numbered()[(3, 0)];
+    }
+
+    #[test]
+    fn neighbours_at_corners() {
+        let grid = numbered();
+
+        assert_eq!(sorted(grid.neighbors4(0, 0)), vec![(0, 1), (1, 0)]);
+        assert_eq!(sorted(grid.neighbors4(2, 2)), vec![(1, 2), (2, 1)]);
+        assert_eq!(sorted(grid.neighbors8(0, 0)), vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(sorted(grid.neighbors8(2, 0)), vec![(1, 0), (1, 1), (2, 1)]);
+        assert_eq!(sorted(grid.neighbors8(0, 2)), vec![(0, 1), (1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn neighbours_inside() {
+        let grid = numbered();
+
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(sorted(grid.neighbors4(1, 0)), vec![(0, 0), (1, 1), (2, 0)]);
+        assert_eq!(grid.neighbors4(5, 5).count(), 0, "outside the grid");
+
+        let line = Grid::from_cells(3, vec![1, 2, 3]);
+        assert_eq!(sorted(line.neighbors8(1, 0)), vec![(0, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn cells_with_positions() {
+        let grid = Grid::from_cells(2, vec!['a', 'b', 'c', 'd']);
+
+        assert_eq!(
+            grid.iter_with_pos().collect::<Vec<_>>(),
+            vec![
+                ((0, 0), &'a'),
+                ((1, 0), &'b'),
+                ((0, 1), &'c'),
+                ((1, 1), &'d')
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "5 cells don't fill rows of width 2")]
+    fn from_uneven_cells() {
+        Grid::from_cells(2, vec![1, 2, 3, 4, 5]);
+    }
+}
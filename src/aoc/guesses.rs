@@ -0,0 +1,399 @@
+//! History of the submitted answers, to avoid submitting a known wrong answer again
+//!
+//! Only available with the `fetch` feature.
+//!
+//! Every answer submitted with [crate::client::Client::submit] is recorded with its outcome
+//! in `answers/guesses_{year}_{day}_{part}.json`. Before submitting, the answer is checked
+//! against the history (see [GuessHistory::check]):
+//! - an answer already submitted is refused, quoting when it was tried. A correct one is
+//!   reported as [crate::client::SubmitOutcome::AlreadySolved] instead,
+//! - an answer contradicting a known bound (higher than an answer that was too high, or lower
+//!   than one that was too low) is refused too, unless forced with `AOC_FORCE=1`.
+//!
+//! The cooldown asked by the website after a submission is saved next to the histories, in
+//! `answers/cooldown`, so that it outlives the process.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::client::SubmitOutcome;
+use crate::output::json_string;
+use crate::solution::{Result, SolutionError};
+
+/// Directory of the guess histories, shared with the known answers of [crate::answers].
+pub use crate::answers::ANSWERS_DIR;
+
+/// File of [ANSWERS_DIR] holding the end of the submission cooldown.
+pub const COOLDOWN_FILE: &str = "cooldown";
+
+/// Environment variable submitting an answer contradicting a known bound when set to `1`.
+pub const FORCE_ENV: &str = "AOC_FORCE";
+
+/// A submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: String,
+    /// [SubmitOutcome::Correct], [SubmitOutcome::Incorrect], [SubmitOutcome::TooHigh]
+    /// or [SubmitOutcome::TooLow].
+    pub outcome: SubmitOutcome,
+    pub at: SystemTime,
+}
+
+impl Guess {
+    /// When the answer was submitted, eg: `2022-12-07T05:03:12Z`.
+    pub fn tried_at(&self) -> String {
+        humantime::format_rfc3339_seconds(self.at).to_string()
+    }
+}
+
+/// Result of [GuessHistory::check].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessCheck {
+    /// The answer is worth submitting.
+    New,
+    /// The answer was already submitted.
+    Repeated(Guess),
+    /// The answer contradicts a previous answer that was too high or too low.
+    OutOfBounds(Guess),
+}
+
+/// Submitted answers of a puzzle part. See the [module documentation](self)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuessHistory {
+    path: PathBuf,
+    guesses: Vec<Guess>,
+}
+
+impl GuessHistory {
+    /// Path of the history of a puzzle part: `answers/guesses_{year}_{day}_{part}.json`.
+    pub fn path(dir: &Path, year: u16, day: u8, part: u8) -> PathBuf {
+        dir.join(format!("guesses_{}_{}_{}.json", year, day, part))
+    }
+
+    /// Loads the history of a puzzle part from `dir`. Empty when the file doesn't exist.
+    pub fn load(dir: &Path, year: u16, day: u8, part: u8) -> Result<Self> {
+        let path = Self::path(dir, year, day, part);
+        let guesses = match std::fs::read_to_string(&path) {
+            Ok(content) => parse_guesses(&content).ok_or_else(|| {
+                SolutionError::RefusedAnswer(format!("{} is malformed", path.display()))
+            })?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self { path, guesses })
+    }
+
+    /// Saves the history, creating its directory if needed.
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, format_guesses(&self.guesses))?;
+
+        Ok(())
+    }
+
+    /// The submitted answers, oldest first.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Records a submitted answer. Only the outcomes telling whether the answer is right are
+    /// recorded: a refused submission ([SubmitOutcome::TooRecent]...) is ignored.
+    pub fn record(&mut self, answer: &str, outcome: &SubmitOutcome, at: SystemTime) {
+        if matches!(
+            outcome,
+            SubmitOutcome::Correct
+                | SubmitOutcome::Incorrect
+                | SubmitOutcome::TooHigh
+                | SubmitOutcome::TooLow
+        ) {
+            self.guesses.push(Guess {
+                answer: answer.trim().to_owned(),
+                outcome: outcome.clone(),
+                at,
+            });
+        }
+    }
+
+    /// Checks `answer` against the history before submitting it.
+    ///
+    /// Bounds only apply to integer answers: a textual answer is only checked for repetition.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::SystemTime;
+    /// use aoc::client::SubmitOutcome;
+    /// use aoc::guesses::{GuessCheck, GuessHistory};
+    ///
+    /// let dir = std::env::temp_dir().join("aoc-guesses-doc");
+    /// let mut history = GuessHistory::load(&dir, 2022, 7, 1).unwrap();
+    /// history.record("1500", &SubmitOutcome::TooHigh, SystemTime::now());
+    ///
+    /// assert_eq!(history.check("1200"), GuessCheck::New);
+    /// assert!(matches!(history.check("1500"), GuessCheck::Repeated(_)));
+    /// assert!(matches!(history.check("1600"), GuessCheck::OutOfBounds(_)));
+    /// ```
+    pub fn check(&self, answer: &str) -> GuessCheck {
+        let answer = answer.trim();
+
+        if let Some(guess) = self.guesses.iter().find(|guess| guess.answer == answer) {
+            return GuessCheck::Repeated(guess.clone());
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return GuessCheck::New;
+        };
+
+        self.guesses
+            .iter()
+            .find(
+                |guess| match (&guess.outcome, guess.answer.parse::<i128>()) {
+                    (SubmitOutcome::TooHigh, Ok(bound)) => value >= bound,
+                    (SubmitOutcome::TooLow, Ok(bound)) => value <= bound,
+                    _ => false,
+                },
+            )
+            .map_or(GuessCheck::New, |guess| {
+                GuessCheck::OutOfBounds(guess.clone())
+            })
+    }
+}
+
+/// Deadline of the submission cooldown asked by the website, saved in `dir`.
+/// `None` when there was none.
+pub(crate) fn load_cooldown(dir: &Path) -> Option<SystemTime> {
+    let content = std::fs::read_to_string(dir.join(COOLDOWN_FILE)).ok()?;
+    let until = content.trim().strip_prefix("until: ")?.parse().ok()?;
+
+    Some(UNIX_EPOCH + Duration::from_secs(until))
+}
+
+/// Saves the deadline of the submission cooldown in `dir`, rounded up to the second.
+pub(crate) fn save_cooldown(dir: &Path, until: SystemTime) -> Result<()> {
+    let until = until.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join(COOLDOWN_FILE), format!("until: {}\n", seconds))?;
+
+    Ok(())
+}
+
+/// Whether `AOC_FORCE=1` asks to submit an answer contradicting a known bound.
+pub fn forced() -> bool {
+    std::env::var(FORCE_ENV).is_ok_and(|value| value.trim() == "1")
+}
+
+fn outcome_name(outcome: &SubmitOutcome) -> &'static str {
+    match outcome {
+        SubmitOutcome::Correct => "correct",
+        SubmitOutcome::TooHigh => "too_high",
+        SubmitOutcome::TooLow => "too_low",
+        _ => "incorrect",
+    }
+}
+
+fn outcome_from_name(name: &str) -> Option<SubmitOutcome> {
+    match name {
+        "correct" => Some(SubmitOutcome::Correct),
+        "incorrect" => Some(SubmitOutcome::Incorrect),
+        "too_high" => Some(SubmitOutcome::TooHigh),
+        "too_low" => Some(SubmitOutcome::TooLow),
+        _ => None,
+    }
+}
+
+/// One guess per line, so the file diffs nicely.
+fn format_guesses(guesses: &[Guess]) -> String {
+    let lines: Vec<_> = guesses
+        .iter()
+        .map(|guess| {
+            format!(
+                "  {{\"answer\": {}, \"outcome\": \"{}\", \"at\": {}}}",
+                json_string(&guess.answer),
+                outcome_name(&guess.outcome),
+                guess
+                    .at
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", lines.join(",\n"))
+}
+
+/// Reads the guesses written by [format_guesses].
+fn parse_guesses(content: &str) -> Option<Vec<Guess>> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('{'))
+        .map(|line| {
+            let (answer, rest) = json_field(line, "answer").and_then(parse_json_string)?;
+            let (outcome, rest) = json_field(rest, "outcome").and_then(parse_json_string)?;
+            let at: String = json_field(rest, "at")?
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+
+            Some(Guess {
+                answer,
+                outcome: outcome_from_name(&outcome)?,
+                at: UNIX_EPOCH + Duration::from_secs(at.parse().ok()?),
+            })
+        })
+        .collect()
+}
+
+/// Value of `key` in a single line JSON object, with the rest of the line.
+fn json_field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let start = line.find(&format!("\"{}\":", key))? + key.len() + 3;
+
+    Some(line[start..].trim_start())
+}
+
+/// Reads a JSON string at the start of `s`, returning it unescaped with the rest of `s`.
+fn parse_json_string(s: &str) -> Option<(String, &str)> {
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    let mut out = String::new();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &s[i + 2..])),
+            '\\' => match chars.next()?.1 {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let hex: String = (0..4)
+                        .filter_map(|_| chars.next())
+                        .map(|(_, c)| c)
+                        .collect();
+                    out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    fn history(guesses: &[(&str, SubmitOutcome)]) -> GuessHistory {
+        let mut history = GuessHistory {
+            path: PathBuf::from("guesses.json"),
+            guesses: Vec::new(),
+        };
+        for (answer, outcome) in guesses {
+            history.record(
+                answer,
+                outcome,
+                UNIX_EPOCH + Duration::from_secs(1670389392),
+            );
+        }
+
+        history
+    }
+
+    #[test]
+    fn repeated_answers() {
+        let history = history(&[
+            ("42", SubmitOutcome::Incorrect),
+            ("ABCDEF", SubmitOutcome::Incorrect),
+        ]);
+
+        assert!(
+            matches!(history.check(" 42\n"), GuessCheck::Repeated(guess) if guess.tried_at() == "2022-12-07T05:03:12Z")
+        );
+        assert!(matches!(history.check("ABCDEF"), GuessCheck::Repeated(_)));
+        assert_eq!(history.check("abcdef"), GuessCheck::New);
+        assert_eq!(history.check("43"), GuessCheck::New);
+    }
+
+    #[test]
+    fn numeric_bounds() {
+        let history = history(&[
+            ("100", SubmitOutcome::TooLow),
+            ("200", SubmitOutcome::TooHigh),
+            ("150", SubmitOutcome::Incorrect),
+        ]);
+
+        assert_eq!(history.check("101"), GuessCheck::New);
+        assert_eq!(history.check("199"), GuessCheck::New);
+        assert!(matches!(history.check("150"), GuessCheck::Repeated(_)));
+        assert!(
+            matches!(history.check("201"), GuessCheck::OutOfBounds(guess) if guess.answer == "200")
+        );
+        assert!(
+            matches!(history.check("99"), GuessCheck::OutOfBounds(guess) if guess.answer == "100")
+        );
+        assert!(matches!(history.check("-5"), GuessCheck::OutOfBounds(_)));
+        assert!(matches!(
+            history.check("100000000000000000000"),
+            GuessCheck::OutOfBounds(_)
+        ));
+    }
+
+    #[test]
+    fn non_numeric_bounds() {
+        let history = history(&[
+            ("10", SubmitOutcome::TooHigh),
+            ("HELLO", SubmitOutcome::TooLow),
+        ]);
+
+        assert_eq!(history.check("ZZZ"), GuessCheck::New);
+        assert_eq!(history.check("5"), GuessCheck::New);
+        assert_eq!(history.check("1,2"), GuessCheck::New);
+        assert!(matches!(history.check("11"), GuessCheck::OutOfBounds(_)));
+    }
+
+    #[test]
+    fn refused_submissions_are_not_recorded() {
+        let history = history(&[
+            ("1", SubmitOutcome::TooRecent { wait: None }),
+            ("2", SubmitOutcome::AlreadySolved),
+        ]);
+
+        assert!(history.guesses().is_empty());
+    }
+
+    #[test]
+    fn save_and_load() {
+        let dir = temp_dir("guesses").join(ANSWERS_DIR);
+        let mut saved = GuessHistory::load(&dir, 2022, 7, 2).unwrap();
+        assert!(saved.guesses().is_empty());
+
+        let at = UNIX_EPOCH + Duration::from_secs(1670389392);
+        saved.record("24933642", &SubmitOutcome::TooHigh, at);
+        saved.record("a \"quoted\"\\ answer, ü", &SubmitOutcome::Incorrect, at);
+        saved.record("12", &SubmitOutcome::Correct, at);
+        saved.save().unwrap();
+
+        let loaded = GuessHistory::load(&dir, 2022, 7, 2).unwrap();
+
+        assert_eq!(loaded, saved);
+        assert!(dir.join("guesses_2022_7_2.json").is_file());
+    }
+
+    #[test]
+    fn malformed_history() {
+        let dir = temp_dir("guesses-malformed");
+        std::fs::write(
+            GuessHistory::path(&dir, 2022, 7, 1),
+            "[\n  {\"answer\": \"1\", \"outcome\": \"maybe\", \"at\": 0}\n]\n",
+        )
+        .unwrap();
+
+        assert!(GuessHistory::load(&dir, 2022, 7, 1).is_err());
+    }
+}
@@ -0,0 +1,1279 @@
+//! Location and reading of the puzzle input files
+//!
+//! By default, inputs are read from `inputs/DAY_<XX>.txt`. The `inputs` directory can be moved
+//! with the `AOC_INPUT_DIR` environment variable, for instance to keep the inputs in a private
+//! repository since Advent of Code asks not to publish them:
+//!
+//! ```shell
+//! AOC_INPUT_DIR=~/aoc-inputs/2022 cargo run --bin day07
+//! ```
+//!
+//! A leading `~` is expanded to the home directory and relative paths are resolved against
+//! the current directory.
+//!
+//! The file name itself comes from [crate::Solution::INPUT_TEMPLATE]. (See [render_template])
+//!
+//! A missing file is reported with the attempted paths and the working directory.
+//! With `AOC_CREATE_MISSING=1`, an empty placeholder is created so it is ready to be filled in.
+//!
+//! The input can also be piped on the standard input, either explicitly with `AOC_INPUT=-`
+//! or implicitly when the input file is missing:
+//!
+//! ```shell
+//! pbpaste | cargo run --bin day07
+//! ```
+//!
+//! In sandboxes where writing files is awkward, the input of a day can be given in the
+//! `AOC_INPUT_DAY_<XX>` environment variable instead, which is read before any file.
+//! (See [input_var] and [crate::Solution::get_input_env])
+//!
+//! ```shell
+//! AOC_INPUT_DAY_07="${{ secrets.DAY_07_INPUT }}" cargo run --bin day07
+//! ```
+//!
+//! Another file can be given on the command line of the days using [crate::run!]:
+//!
+//! ```shell
+//! cargo run --bin day07 -- --input ~/Downloads/input.txt
+//! ```
+//!
+//! The SHA-256 of each input is recorded by year and day in `inputs/checksums.toml` after the
+//! first successful run of the day, so that a later run on another input (pasted in the wrong
+//! file...) prints a warning, or fails with `AOC_STRICT_INPUT=1`. (See [verify_checksum])
+//!
+//! With the `encrypt` feature, an input can be committed encrypted, eg: `inputs/DAY_07.txt.enc`.
+//! It is read when the plain file is missing. (See [encrypt_file])
+//!
+//! The example of a puzzle is read from the same path with an `_example` suffix,
+//! eg: `inputs/DAY_07_example.txt`. (See [InputKind] and [crate::Solution::get_input_kind])
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::solution::{Result, SolutionError};
+use crate::{logging, sha256};
+
+#[cfg(feature = "encrypt")]
+pub use crate::encrypt::{
+    decrypt, encrypt, encrypt_file, encrypted_path, input_key, ENCRYPTED_EXTENSION, INPUT_KEY_ENV,
+    INPUT_KEY_FILE_ENV,
+};
+
+/// Environment variable overriding the input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Default input directory, relative to the current directory.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Environment variable creating an empty placeholder for a missing input file when set to `1`.
+pub const CREATE_MISSING_ENV: &str = "AOC_CREATE_MISSING";
+
+/// Environment variable failing a run on an input not matching its checksum when set to `1`.
+pub const STRICT_INPUT_ENV: &str = "AOC_STRICT_INPUT";
+
+/// File of [input_dir] holding the checksums of the inputs. See [record_checksum]
+pub const CHECKSUMS_FILE: &str = "checksums.toml";
+
+/// Environment variable selecting the input source. Only `-` (the standard input) is supported.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Command line argument giving the input file to [crate::run!]. See [input_arg]
+pub const INPUT_ARG: &str = "--input";
+
+/// Path of the [SolutionError::PuzzleInput] converted from a bare [std::io::Error].
+pub const UNKNOWN_PATH: &str = "<unknown path>";
+
+/// Prefix of the environment variables holding the input of a day. See [input_var]
+pub const INPUT_VAR_PREFIX: &str = "AOC_INPUT_DAY_";
+
+/// Default [crate::Solution::INPUT_TEMPLATE].
+pub const DEFAULT_TEMPLATE: &str = "inputs/DAY_{day:02}.txt";
+
+/// Template tried before [DEFAULT_TEMPLATE] when [crate::Solution::YEAR] is set.
+pub const YEAR_TEMPLATE: &str = "inputs/{year}/DAY_{day:02}.txt";
+
+/// Resolved input directory: `AOC_INPUT_DIR` if set, `inputs` otherwise.
+pub fn input_dir() -> PathBuf {
+    let dir = std::env::var(INPUT_DIR_ENV)
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_INPUT_DIR.to_owned());
+    let cwd = std::env::current_dir().unwrap_or_default();
+
+    resolve(dir.trim(), home_dir().as_deref(), &cwd)
+}
+
+/// Resolves a path relative to the current directory, expanding a leading `~`.
+///
+/// When `path` starts with the `inputs` directory, it is replaced by [input_dir],
+/// so that `AOC_INPUT_DIR` applies to custom templates too.
+pub fn locate(path: &str) -> PathBuf {
+    match Path::new(path).strip_prefix(DEFAULT_INPUT_DIR) {
+        Ok(rest) => input_dir().join(rest),
+        Err(_) => {
+            let cwd = std::env::current_dir().unwrap_or_default();
+
+            resolve(path, home_dir().as_deref(), &cwd)
+        }
+    }
+}
+
+/// Renders an input path template. See [crate::Solution::INPUT_TEMPLATE]
+///
+/// Supported placeholders:
+/// - `{day}` - the day, eg: `7`
+/// - `{day:02}` - the zero padded day, eg: `07`
+/// - `{year}` - the year, eg: `2022`. An error is returned if the year is unknown.
+///
+/// # Example
+/// ```
+/// use aoc::input::render_template;
+///
+/// assert_eq!(render_template("inputs/DAY_{day:02}.txt", 7, None).unwrap(), "inputs/DAY_07.txt");
+/// assert_eq!(render_template("{year}/day{day}", 7, Some(2022)).unwrap(), "2022/day7");
+/// assert!(render_template("{year}/day{day}", 7, None).is_err());
+/// assert!(render_template("{month}/day{day}", 7, None).is_err());
+/// ```
+pub fn render_template(template: &str, day: u8, year: Option<u16>) -> Result<String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+
+        let end = rest[start..].find('}').ok_or_else(|| {
+            SolutionError::InputTemplate(format!("unclosed placeholder in {:?}", template))
+        })? + start;
+
+        match &rest[start + 1..end] {
+            "day" => rendered.push_str(&day.to_string()),
+            "day:02" => rendered.push_str(&format!("{:02}", day)),
+            "year" => match year {
+                Some(year) => rendered.push_str(&year.to_string()),
+                None => {
+                    return Err(SolutionError::InputTemplate(format!(
+                        "{{year}} is used in {:?} but Solution::YEAR is not set",
+                        template
+                    )))
+                }
+            },
+            unknown => {
+                return Err(SolutionError::InputTemplate(format!(
+                    "unknown placeholder {{{}}} in {:?}, expected {{day}}, {{day:02}} or {{year}}",
+                    unknown, template
+                )))
+            }
+        }
+
+        rest = &rest[end + 1..];
+    }
+    rendered.push_str(rest);
+
+    Ok(rendered)
+}
+
+/// Candidate paths of an input file, in order of preference.
+///
+/// With the default template and a known year, the per-year layout `inputs/{year}/DAY_XX.txt`
+/// is preferred over the flat `inputs/DAY_XX.txt` layout. Otherwise, the template is the only
+/// candidate.
+pub fn candidates(template: &str, day: u8, year: Option<u16>) -> Result<Vec<PathBuf>> {
+    let templates = match year {
+        Some(_) if template == DEFAULT_TEMPLATE => vec![YEAR_TEMPLATE, DEFAULT_TEMPLATE],
+        _ => vec![template],
+    };
+
+    templates
+        .into_iter()
+        .map(|template| Ok(locate(&render_template(template, day, year)?)))
+        .collect()
+}
+
+/// Picks the first existing path among `candidates`.
+///
+/// A note is printed on stderr when a fallback is used. When no candidate exists,
+/// the error lists every attempted path. A single candidate is returned as is.
+pub fn find(candidates: &[PathBuf]) -> Result<PathBuf> {
+    match candidates {
+        [] => Err(SolutionError::InputTemplate("no input path".to_owned())),
+        [path] => Ok(path.clone()),
+        [preferred, fallbacks @ ..] => {
+            if exists(preferred) {
+                return Ok(preferred.clone());
+            }
+
+            match fallbacks.iter().find(|path| exists(path)) {
+                Some(path) => {
+                    logging::warn(format_args!(
+                        "Note: using {}. This layout is deprecated, move the file to {}",
+                        path.display(),
+                        preferred.display()
+                    ));
+                    Ok(path.clone())
+                }
+                None => Err(missing(candidates.to_vec())),
+            }
+        }
+    }
+}
+
+pub(crate) fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Expands a leading `~` with `home` and makes `path` absolute relative to `cwd`.
+fn resolve(path: &str, home: Option<&Path>, cwd: &Path) -> PathBuf {
+    let expanded = match (path.strip_prefix('~'), home) {
+        (Some(""), Some(home)) => home.to_path_buf(),
+        (Some(rest), Some(home)) if rest.starts_with(['/', '\\']) => home.join(&rest[1..]),
+        _ => PathBuf::from(path),
+    };
+
+    cwd.join(expanded)
+}
+
+/// Whether the input file at `path` exists, plain or encrypted.
+fn exists(path: &Path) -> bool {
+    #[cfg(feature = "encrypt")]
+    if encrypted_path(path).is_file() {
+        return true;
+    }
+
+    path.is_file()
+}
+
+/// Reads the input file at `path`. The error mentions the path.
+///
+/// With the `encrypt` feature, its encrypted version is read when the plain file is missing.
+pub fn read(path: &Path) -> Result<String> {
+    #[cfg(feature = "encrypt")]
+    if !path.exists() && encrypted_path(path).is_file() {
+        return crate::encrypt::read_encrypted(&encrypted_path(path));
+    }
+
+    std::fs::read_to_string(path).map_err(|e| with_path(e, path))
+}
+
+/// Opens the input file at `path`. The error mentions the path.
+pub fn open(path: &Path) -> Result<std::fs::File> {
+    std::fs::File::open(path).map_err(|e| with_path(e, path))
+}
+
+/// Name of the environment variable holding the input of the `day`, eg: `AOC_INPUT_DAY_07`.
+///
+/// # Example
+/// ```
+/// use aoc::input::input_var;
+///
+/// assert_eq!(input_var(7), "AOC_INPUT_DAY_07");
+/// ```
+pub fn input_var(day: u8) -> String {
+    format!("{}{:02}", INPUT_VAR_PREFIX, day)
+}
+
+/// Reads the input from the environment variable `var`.
+///
+/// The error names the variable as its path, eg: `$AOC_INPUT_DAY_07`.
+pub fn read_env(var: &str) -> Result<String> {
+    std::env::var(var).map_err(|e| {
+        let kind = match e {
+            std::env::VarError::NotPresent => std::io::ErrorKind::NotFound,
+            std::env::VarError::NotUnicode(_) => std::io::ErrorKind::InvalidData,
+        };
+
+        SolutionError::PuzzleInput {
+            path: PathBuf::from(format!("${}", var)),
+            source: Arc::new(std::io::Error::new(kind, e)),
+        }
+    })
+}
+
+/// Whether `AOC_INPUT=-` asks for the input to be read from the standard input.
+pub fn stdin_requested() -> bool {
+    std::env::var(INPUT_ENV).is_ok_and(|value| value.trim() == "-")
+}
+
+/// Reads the input file at `path`, or `stdin` instead.
+///
+/// `stdin` is read when `AOC_INPUT=-` is set, or when the input file is missing and `stdin`
+/// is not a terminal (`is_terminal`). In the latter case, an empty `stdin` still reports the
+/// missing file, so running without input never hangs waiting for the keyboard.
+pub fn read_or_stdin<R: Read>(
+    path: Result<PathBuf>,
+    mut stdin: R,
+    is_terminal: bool,
+) -> Result<String> {
+    let mut read_stdin = || {
+        let mut input = String::new();
+        stdin
+            .read_to_string(&mut input)
+            .map_err(|e| with_path(e, Path::new("<stdin>")))?;
+
+        Ok::<_, SolutionError>(input)
+    };
+
+    if stdin_requested() {
+        logging::warn(format_args!(
+            "Note: reading the input from stdin ({}=-)",
+            INPUT_ENV
+        ));
+        return read_stdin();
+    }
+
+    let missing = match path.and_then(|path| read(&path)) {
+        Ok(input) => return Ok(input),
+        Err(SolutionError::MissingInput(missing)) if !is_terminal => missing,
+        Err(e) => return Err(e),
+    };
+
+    match read_stdin()? {
+        input if input.is_empty() => Err(SolutionError::MissingInput(missing)),
+        input => {
+            let attempted: Vec<_> = missing
+                .attempted
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            logging::warn(format_args!(
+                "Note: {} not found, reading the input from stdin",
+                attempted.join(", ")
+            ));
+            Ok(input)
+        }
+    }
+}
+
+/// The input file given with `--input <path>` or `--input=<path>` among the command line `args`,
+/// eg: [std::env::args]. The last one wins.
+///
+/// # Example
+/// ```
+/// use aoc::input::input_arg;
+///
+/// let args = ["day07", "--input", "foo.txt"].map(String::from);
+/// assert_eq!(input_arg(args).as_deref(), Some("foo.txt"));
+/// assert_eq!(input_arg(["day07", "--input=bar.txt"].map(String::from)).as_deref(), Some("bar.txt"));
+/// assert_eq!(input_arg(["day07".to_owned()]), None);
+/// ```
+pub fn input_arg<I: IntoIterator<Item = String>>(args: I) -> Option<String> {
+    let mut args = args.into_iter();
+    let mut path = None;
+
+    while let Some(arg) = args.next() {
+        if arg == INPUT_ARG {
+            path = args.next().or(path);
+        } else if let Some(value) = arg
+            .strip_prefix(INPUT_ARG)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            path = Some(value.to_owned());
+        }
+    }
+
+    path
+}
+
+/// Normalizes the line endings of an input.
+///
+/// A leading UTF-8 byte order mark, as saved by some Windows editors, is always removed.
+/// (See [strip_bom])
+///
+/// - `trim_trailing_newline` removes the newlines at the end of the input, which would otherwise
+///   produce an empty last element when splitting on `\n`. Other whitespace is kept.
+/// - `normalize_crlf` converts the Windows `\r\n` line endings to `\n`.
+///
+/// # Example
+/// ```
+/// use aoc::input::normalize;
+///
+/// assert_eq!(normalize("1\r\n2\r\n".to_owned(), true, true), "1\n2");
+/// assert_eq!(normalize("    [D]\n".to_owned(), false, true), "    [D]\n");
+/// ```
+pub fn normalize(input: String, trim_trailing_newline: bool, normalize_crlf: bool) -> String {
+    let input = strip_bom(input);
+    let mut input = if normalize_crlf && input.contains("\r\n") {
+        input.replace("\r\n", "\n")
+    } else {
+        input
+    };
+
+    if trim_trailing_newline {
+        let len = input.trim_end_matches(['\n', '\r']).len();
+        input.truncate(len);
+    }
+
+    input
+}
+
+/// UTF-8 encoding of the byte order mark `\u{FEFF}`.
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Removes a leading byte order mark from `input`.
+pub fn strip_bom(mut input: String) -> String {
+    if input.starts_with('\u{FEFF}') {
+        logging::debug(format_args!("Stripped a byte order mark from the input"));
+        input.drain(..'\u{FEFF}'.len_utf8());
+    }
+
+    input
+}
+
+/// Skips a leading UTF-8 byte order mark from `reader`.
+pub fn skip_bom<R: BufRead>(reader: &mut R) -> std::io::Result<()> {
+    if reader.fill_buf()?.starts_with(BOM) {
+        logging::debug(format_args!("Stripped a byte order mark from the input"));
+        reader.consume(BOM.len());
+    }
+
+    Ok(())
+}
+
+/// Records the SHA-256 of the input of `year`/`day` in `inputs/checksums.toml`, replacing any
+/// previous one.
+///
+/// The file holds one `year-day = "sha256"` line per day, eg: `2022-7 = "..."`, or
+/// `day = "sha256"` when the year is unknown.
+pub fn record_checksum(year: Option<u16>, day: u8, input: &str) -> Result<()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+    let path = input_dir().join(CHECKSUMS_FILE);
+    let mut checksums = read_checksums(&path);
+    checksums.insert((year, day), sha256::hex_digest(input.as_bytes()));
+
+    let content: String = checksums
+        .iter()
+        .map(|((year, day), checksum)| match year {
+            Some(year) => format!("{}-{} = \"{}\"\n", year, day, checksum),
+            None => format!("{} = \"{}\"\n", day, checksum),
+        })
+        .collect();
+    std::fs::write(&path, content)?;
+
+    Ok(())
+}
+
+/// Compares the input of `year`/`day` with the checksum recorded by [record_checksum].
+///
+/// Returns whether a checksum was recorded, or [SolutionError::InputChecksum] with both hashes
+/// when the input changed.
+///
+/// # Example
+/// ```
+/// use aoc::input::{record_checksum, verify_checksum};
+///# std::env::set_var("AOC_INPUT_DIR", std::env::temp_dir().join("aoc-checksum-doctest"));
+///# std::fs::create_dir_all(aoc::input::input_dir()).unwrap();
+///# let _ = std::fs::remove_file(aoc::input::input_dir().join("checksums.toml"));
+///
+/// assert!(!verify_checksum(Some(2022), 7, "$ cd /").unwrap());
+///
+/// record_checksum(Some(2022), 7, "$ cd /").unwrap();
+///
+/// assert!(verify_checksum(Some(2022), 7, "$ cd /").unwrap());
+/// assert!(verify_checksum(Some(2022), 7, "1721\n979").is_err());
+/// assert!(!verify_checksum(Some(2020), 7, "1721\n979").unwrap());
+/// ```
+pub fn verify_checksum(year: Option<u16>, day: u8, input: &str) -> Result<bool> {
+    let checksums = read_checksums(&input_dir().join(CHECKSUMS_FILE));
+    let Some(recorded) = checksums.get(&(year, day)).cloned() else {
+        return Ok(false);
+    };
+
+    let actual = sha256::hex_digest(input.as_bytes());
+    if recorded != actual {
+        return Err(SolutionError::InputChecksum {
+            day,
+            recorded,
+            actual,
+        });
+    }
+
+    Ok(true)
+}
+
+/// Whether `AOC_STRICT_INPUT=1`.
+pub(crate) fn strict_input() -> bool {
+    std::env::var(STRICT_INPUT_ENV).is_ok_and(|value| value.trim() == "1")
+}
+
+fn read_checksums(path: &Path) -> BTreeMap<(Option<u16>, u8), String> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (key, checksum) = line.split_once('=')?;
+            let key = match key.trim().split_once('-') {
+                Some((year, day)) => (Some(year.parse().ok()?), day.parse().ok()?),
+                None => (None, key.trim().parse().ok()?),
+            };
+
+            Some((key, checksum.trim().trim_matches('"').to_owned()))
+        })
+        .collect()
+}
+
+/// Which input of a puzzle to read. See [crate::Solution::get_input_kind]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputKind {
+    /// The example given in the puzzle description, eg: `inputs/DAY_07_example.txt`.
+    Example,
+    /// The personal puzzle input, eg: `inputs/DAY_07.txt`.
+    #[default]
+    Real,
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "example input"),
+            InputKind::Real => write!(f, "puzzle input"),
+        }
+    }
+}
+
+/// Path of the example matching an input path: `inputs/DAY_07.txt` gives
+/// `inputs/DAY_07_example.txt`.
+///
+/// # Example
+/// ```
+/// use std::path::Path;
+/// use aoc::input::example_path;
+///
+/// assert_eq!(example_path(Path::new("inputs/DAY_07.txt")), Path::new("inputs/DAY_07_example.txt"));
+/// assert_eq!(example_path(Path::new("2022/day7")), Path::new("2022/day7_example"));
+/// ```
+pub fn example_path(path: &Path) -> PathBuf {
+    let mut name = path.file_stem().unwrap_or_default().to_owned();
+    name.push("_example");
+    if let Some(extension) = path.extension() {
+        name.push(".");
+        name.push(extension);
+    }
+
+    path.with_file_name(name)
+}
+
+/// Reads the first existing example among the examples of the input `candidates`.
+///
+/// A missing example is reported as [InputKind::Example], and never downloaded nor read from
+/// the standard input.
+pub fn read_example(candidates: &[PathBuf]) -> Result<String> {
+    let examples: Vec<_> = candidates.iter().map(|path| example_path(path)).collect();
+
+    find(&examples)
+        .and_then(|path| read(&path))
+        .map_err(|e| match e {
+            SolutionError::MissingInput(missing) => SolutionError::MissingInput(MissingInput {
+                kind: InputKind::Example,
+                ..missing
+            }),
+            e => e,
+        })
+}
+
+/// Details of a missing input file. See [SolutionError::MissingInput]
+#[derive(Debug, Clone)]
+pub struct MissingInput {
+    /// The kind of input that was sought.
+    pub kind: InputKind,
+    /// Every path that was tried, in order of preference.
+    pub attempted: Vec<PathBuf>,
+    /// The working directory, against which the relative paths are resolved.
+    pub cwd: PathBuf,
+    /// The empty placeholder created because of `AOC_CREATE_MISSING=1`, if any.
+    pub created: Option<PathBuf>,
+}
+
+impl Display for MissingInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let attempted: Vec<_> = self
+            .attempted
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+
+        write!(
+            f,
+            "{} ({})\n  working directory: {}",
+            attempted.join(", "),
+            self.kind,
+            self.cwd.display(),
+        )?;
+
+        if let Some(created) = &self.created {
+            write!(f, "\n  note: created an empty {}", created.display())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reports the missing input files, creating a placeholder for the first one
+/// when `AOC_CREATE_MISSING=1`.
+fn missing(attempted: Vec<PathBuf>) -> SolutionError {
+    let create = std::env::var(CREATE_MISSING_ENV).is_ok_and(|value| value.trim() == "1");
+    let created = attempted
+        .first()
+        .filter(|_| create)
+        .filter(|path| create_placeholder(path).is_ok())
+        .cloned();
+
+    SolutionError::MissingInput(MissingInput {
+        kind: InputKind::Real,
+        attempted,
+        cwd: std::env::current_dir().unwrap_or_default(),
+        created,
+    })
+}
+
+fn create_placeholder(path: &Path) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map(drop)
+}
+
+fn with_path(e: std::io::Error, path: &Path) -> SolutionError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        return missing(vec![path.to_path_buf()]);
+    }
+
+    SolutionError::PuzzleInput {
+        path: path.to_path_buf(),
+        source: Arc::new(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{env_lock, temp_dir};
+    use crate::Solution;
+
+    struct Day07;
+    impl Solution for Day07 {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 7;
+        type Input = String;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_owned())
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input.len())
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    #[test]
+    fn resolve_paths() {
+        let home = Path::new("/home/elf");
+        let cwd = Path::new("/work/aoc");
+
+        assert_eq!(
+            resolve("inputs", Some(home), cwd),
+            PathBuf::from("/work/aoc/inputs")
+        );
+        assert_eq!(
+            resolve("../aoc-inputs/2022", Some(home), cwd),
+            PathBuf::from("/work/aoc/../aoc-inputs/2022")
+        );
+        assert_eq!(
+            resolve("~/aoc-inputs", Some(home), cwd),
+            PathBuf::from("/home/elf/aoc-inputs")
+        );
+        assert_eq!(resolve("~", Some(home), cwd), PathBuf::from("/home/elf"));
+        assert_eq!(
+            resolve("~elf/inputs", Some(home), cwd),
+            PathBuf::from("/work/aoc/~elf/inputs")
+        );
+        assert_eq!(
+            resolve("/abs/inputs", Some(home), cwd),
+            PathBuf::from("/abs/inputs")
+        );
+    }
+
+    #[test]
+    fn input_dir_from_env() {
+        let _lock = env_lock();
+        let dir = temp_dir("input-dir");
+        std::fs::write(dir.join("DAY_07.txt"), "from env").unwrap();
+
+        std::env::set_var(INPUT_DIR_ENV, &dir);
+        let input = Day07::get_input();
+        std::env::remove_var(INPUT_DIR_ENV);
+
+        assert_eq!(input.unwrap(), "from env");
+    }
+
+    #[test]
+    fn missing_input_mentions_the_resolved_path() {
+        let _lock = env_lock();
+        let dir = temp_dir("input-dir-missing");
+
+        std::env::set_var(INPUT_DIR_ENV, &dir);
+        let error = Day07::get_input_from(std::io::empty(), true).unwrap_err();
+        std::env::remove_var(INPUT_DIR_ENV);
+
+        assert!(matches!(error, SolutionError::MissingInput(_)));
+        assert!(
+            error
+                .to_string()
+                .contains(&dir.join("DAY_07.txt").display().to_string()),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn missing_input_hints() {
+        let _lock = env_lock();
+        let dir = temp_dir("missing-hints");
+
+        let error = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+        let message = error.unwrap_err().to_string();
+
+        assert!(message.contains(&dir.join("DAY_07.txt").display().to_string()));
+        assert!(message.contains(&std::env::current_dir().unwrap().display().to_string()));
+        assert!(
+            !message.contains("hint:"),
+            "the hint is in the report: {}",
+            message
+        );
+        assert!(!message.contains("created"));
+        assert!(!dir.join("DAY_07.txt").exists());
+    }
+
+    #[test]
+    fn missing_input_placeholder() {
+        let _lock = env_lock();
+        let dir = temp_dir("missing-placeholder").join("nested");
+
+        std::env::set_var(CREATE_MISSING_ENV, "1");
+        let error = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+        std::env::remove_var(CREATE_MISSING_ENV);
+
+        let message = error.unwrap_err().to_string();
+        assert!(message.contains("created an empty"), "{}", message);
+        assert_eq!(std::fs::read_to_string(dir.join("DAY_07.txt")).unwrap(), "");
+    }
+
+    #[test]
+    fn stdin_when_requested() {
+        let _lock = env_lock();
+        let dir = temp_dir("stdin-requested");
+        std::fs::write(dir.join("DAY_07.txt"), "from file").unwrap();
+
+        std::env::set_var(INPUT_ENV, "-");
+        let input = with_input_dir(&dir, || {
+            Day07::get_input_from("from stdin".as_bytes(), true)
+        });
+        std::env::remove_var(INPUT_ENV);
+
+        assert_eq!(input.unwrap(), "from stdin");
+    }
+
+    #[test]
+    fn input_from_env() {
+        let _lock = env_lock();
+        let dir = temp_dir("input-env");
+        std::fs::write(dir.join("DAY_07.txt"), "from file").unwrap();
+
+        std::env::set_var(input_var(7), "from env\r\n");
+        let input = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+        std::env::remove_var(input_var(7));
+
+        assert_eq!(input.unwrap(), "from env");
+        assert_eq!(
+            with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true)).unwrap(),
+            "from file"
+        );
+
+        let error = Day07::get_input_env("AOC_TEST_UNSET_INPUT").unwrap_err();
+        assert_eq!(error.io_kind(), Some(std::io::ErrorKind::NotFound));
+        assert_eq!(
+            error.to_string(),
+            "Missing Puzzle input at \"$AOC_TEST_UNSET_INPUT\": environment variable not found"
+        );
+    }
+
+    #[test]
+    fn unreadable_inputs() {
+        let dir = temp_dir("unreadable");
+
+        let error = read(&dir.join("DAY_07.txt")).unwrap_err();
+        assert_eq!(error.io_kind(), Some(std::io::ErrorKind::NotFound));
+        assert_eq!(error.path(), Some(dir.join("DAY_07.txt").as_path()));
+
+        let error = read(&dir).unwrap_err();
+        assert!(matches!(error, SolutionError::PuzzleInput { .. }));
+        assert_eq!(error.path(), Some(dir.as_path()));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Missing Puzzle input at {:?}: {}",
+                dir,
+                std::fs::read_to_string(&dir).unwrap_err()
+            )
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let path = dir.join("DAY_08.txt");
+            std::fs::write(&path, "secret").unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o000)).unwrap();
+
+            // Root reads the file anyway
+            if std::fs::read(&path).is_err() {
+                let error = read(&path).unwrap_err();
+                assert_eq!(error.io_kind(), Some(std::io::ErrorKind::PermissionDenied));
+                assert_eq!(error.path(), Some(path.as_path()));
+            }
+        }
+    }
+
+    #[test]
+    fn stdin_when_the_file_is_missing() {
+        let _lock = env_lock();
+        let dir = temp_dir("stdin-missing");
+
+        let piped = with_input_dir(&dir, || Day07::get_input_from("piped".as_bytes(), false));
+        let empty = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), false));
+        let terminal = with_input_dir(&dir, || Day07::get_input_from("typed".as_bytes(), true));
+
+        assert_eq!(piped.unwrap(), "piped");
+        assert!(matches!(empty, Err(SolutionError::MissingInput(_))));
+        assert!(matches!(terminal, Err(SolutionError::MissingInput(_))));
+    }
+
+    struct Crates;
+    impl Solution for Crates {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 5;
+        const TRIM_TRAILING_NEWLINE: bool = false;
+        type Input = String;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_owned())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    #[test]
+    fn normalized_input() {
+        let _lock = env_lock();
+        let dir = temp_dir("normalized");
+        std::fs::write(dir.join("DAY_07.txt"), "1721\r\n979\r\n366\r\n\r\n").unwrap();
+
+        let input = with_input_dir(&dir, Day07::get_input);
+
+        assert_eq!(input.unwrap(), "1721\n979\n366");
+    }
+
+    #[test]
+    fn trailing_whitespace_opt_out() {
+        let _lock = env_lock();
+        let dir = temp_dir("opt-out");
+        let crates = "    [D]    \r\n[N] [C]    \r\n 1   2   3 \r\n";
+        std::fs::write(dir.join("DAY_05.txt"), crates).unwrap();
+
+        let input = with_input_dir(&dir, Crates::get_input);
+
+        assert_eq!(input.unwrap(), "    [D]    \n[N] [C]    \n 1   2   3 \n");
+    }
+
+    struct Numbers;
+    impl Solution for Numbers {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| SolutionError::parse("not a number"))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            input.iter().max().copied()
+        }
+    }
+
+    #[test]
+    fn byte_order_mark_is_stripped() {
+        let _lock = env_lock();
+        let dir = temp_dir("bom");
+        std::fs::write(dir.join("DAY_01.txt"), "\u{FEFF}1721\r\n979\r\n").unwrap();
+
+        let input = with_input_dir(&dir, Numbers::get_input).unwrap();
+        let streamed = with_input_dir(&dir, Numbers::run_streaming).unwrap();
+
+        assert_eq!(Numbers::parse(&input).unwrap(), vec![1721, 979]);
+        assert_eq!(streamed.view().part1.as_deref(), Some("2700"));
+    }
+
+    #[test]
+    fn default_input_dir() {
+        let _lock = env_lock();
+        std::env::remove_var(INPUT_DIR_ENV);
+
+        assert_eq!(
+            Day07::input_path().unwrap(),
+            std::env::current_dir().unwrap().join("inputs/DAY_07.txt")
+        );
+    }
+
+    #[test]
+    fn templates() {
+        let render = |template| render_template(template, 7, Some(2022)).unwrap();
+
+        assert_eq!(render("inputs/DAY_{day:02}.txt"), "inputs/DAY_07.txt");
+        assert_eq!(render("input/day{day:02}.txt"), "input/day07.txt");
+        assert_eq!(render("data/{year}/{day}/input"), "data/2022/7/input");
+        assert_eq!(render("no placeholder"), "no placeholder");
+        assert_eq!(
+            render_template("inputs/DAY_{day:02}.txt", 25, None).unwrap(),
+            "inputs/DAY_25.txt"
+        );
+    }
+
+    #[test]
+    fn template_errors() {
+        let error = |template| render_template(template, 7, None).unwrap_err().to_string();
+
+        assert!(error("data/{year}/{day}").contains("Solution::YEAR is not set"));
+        assert!(error("data/{month}/{day}").contains("unknown placeholder {month}"));
+        assert!(error("data/{day").contains("unclosed placeholder"));
+    }
+
+    struct Day05;
+    impl Solution for Day05 {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 5;
+        const YEAR: Option<u16> = Some(2022);
+        const INPUT_TEMPLATE: &'static str = "inputs/{year}/day{day}.txt";
+        type Input = String;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_owned())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    struct NoYear;
+    impl Solution for NoYear {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 5;
+        const INPUT_TEMPLATE: &'static str = "inputs/{year}/day{day}.txt";
+        type Input = String;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_owned())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    struct Day01;
+    impl Solution for Day01 {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 1;
+        const YEAR: Option<u16> = Some(2015);
+        type Input = String;
+        type P1 = ();
+        type P2 = ();
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.to_owned())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            None
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    fn with_input_dir<T>(dir: &Path, f: impl FnOnce() -> T) -> T {
+        std::env::set_var(INPUT_DIR_ENV, dir);
+        let result = f();
+        std::env::remove_var(INPUT_DIR_ENV);
+
+        result
+    }
+
+    #[test]
+    fn checksum_recorded_on_first_run() {
+        let _lock = env_lock();
+        let dir = temp_dir("checksum-first-run");
+        std::fs::write(dir.join("DAY_07.txt"), "abc").unwrap();
+
+        assert!(with_input_dir(&dir, Day07::run).is_ok());
+
+        assert_eq!(
+            std::fs::read_to_string(dir.join(CHECKSUMS_FILE)).unwrap(),
+            "7 = \"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\"\n"
+        );
+        assert!(with_input_dir(&dir, || verify_checksum(None, 7, "abc")).unwrap());
+    }
+
+    #[test]
+    fn checksums_by_year() {
+        let _lock = env_lock();
+        let dir = temp_dir("checksum-years");
+
+        with_input_dir(&dir, || {
+            record_checksum(Some(2022), 7, "2022").unwrap();
+            record_checksum(Some(2015), 7, "2015").unwrap();
+            record_checksum(None, 7, "abc").unwrap();
+        });
+
+        assert_eq!(
+            std::fs::read_to_string(dir.join(CHECKSUMS_FILE))
+                .unwrap()
+                .lines()
+                .map(|line| line.split_once(" = ").unwrap().0)
+                .collect::<Vec<_>>(),
+            ["7", "2015-7", "2022-7"]
+        );
+        with_input_dir(&dir, || {
+            assert!(verify_checksum(Some(2022), 7, "2022").unwrap());
+            assert!(verify_checksum(Some(2015), 7, "2015").unwrap());
+            assert!(verify_checksum(None, 7, "abc").unwrap());
+            assert!(verify_checksum(Some(2022), 7, "2015").is_err());
+            assert!(!verify_checksum(Some(2016), 7, "2016").unwrap());
+        });
+    }
+
+    #[test]
+    fn checksum_matching_rerun() {
+        let _lock = env_lock();
+        let dir = temp_dir("checksum-rerun");
+        std::fs::write(dir.join("DAY_07.txt"), "abc").unwrap();
+
+        std::env::set_var(STRICT_INPUT_ENV, "1");
+        let runs = with_input_dir(&dir, || (Day07::run(), Day07::run()));
+        std::env::remove_var(STRICT_INPUT_ENV);
+
+        assert!(runs.0.is_ok());
+        assert!(runs.1.is_ok());
+    }
+
+    #[test]
+    fn checksum_mismatch() {
+        let _lock = env_lock();
+        let dir = temp_dir("checksum-mismatch");
+        std::fs::write(dir.join("DAY_07.txt"), "abc").unwrap();
+        with_input_dir(&dir, Day07::run).unwrap();
+        std::fs::write(dir.join("DAY_07.txt"), "input of day 8").unwrap();
+
+        let lenient = with_input_dir(&dir, Day07::run);
+        std::env::set_var(STRICT_INPUT_ENV, "1");
+        let strict = with_input_dir(&dir, Day07::run);
+        std::env::remove_var(STRICT_INPUT_ENV);
+
+        assert!(lenient.is_ok());
+        let message = strict.err().unwrap().to_string();
+        assert!(message.contains("Input of day 07"), "{}", message);
+        assert!(message.contains("recorded sha256 ba7816bf"), "{}", message);
+        assert!(
+            message.contains(&sha256::hex_digest(b"input of day 8")),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "encrypt")]
+    fn encrypted_inputs() {
+        let _lock = env_lock();
+        let dir = temp_dir("encrypted-inputs");
+        let plain = dir.join("DAY_07.txt");
+        std::fs::write(&plain, "plain").unwrap();
+        std::fs::write(
+            encrypted_path(&plain),
+            encrypt("encrypted", "temp key").unwrap(),
+        )
+        .unwrap();
+
+        std::env::set_var(INPUT_KEY_ENV, "temp key");
+        let both = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+        std::fs::remove_file(&plain).unwrap();
+        let encrypted_only = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+        std::env::remove_var(INPUT_KEY_ENV);
+        let without_key = with_input_dir(&dir, || Day07::get_input_from(std::io::empty(), true));
+
+        assert_eq!(both.unwrap(), "plain");
+        assert_eq!(encrypted_only.unwrap(), "encrypted");
+        let message = without_key.unwrap_err().to_string();
+        assert!(message.contains("set AOC_INPUT_KEY"), "{}", message);
+    }
+
+    #[test]
+    #[cfg(feature = "encrypt")]
+    fn encrypted_year_layout_is_preferred() {
+        let _lock = env_lock();
+        let dir = temp_dir("encrypted-year-layout");
+        std::fs::create_dir_all(dir.join("2015")).unwrap();
+        std::fs::write(
+            dir.join("2015/DAY_01.txt.enc"),
+            encrypt("year", "temp key").unwrap(),
+        )
+        .unwrap();
+        std::fs::write(dir.join("DAY_01.txt"), "flat").unwrap();
+
+        std::env::set_var(INPUT_KEY_ENV, "temp key");
+        let input = with_input_dir(&dir, || Day01::get_input_from(std::io::empty(), true));
+        std::env::remove_var(INPUT_KEY_ENV);
+
+        assert_eq!(input.unwrap(), "year");
+    }
+
+    #[test]
+    fn example_and_real_inputs() {
+        let _lock = env_lock();
+        let dir = temp_dir("input-kinds");
+        std::fs::create_dir_all(dir.join("2015")).unwrap();
+        std::fs::write(dir.join("2015/DAY_01.txt"), "real\n").unwrap();
+        std::fs::write(dir.join("2015/DAY_01_example.txt"), "example\r\n").unwrap();
+
+        let (real, example) = with_input_dir(&dir, || {
+            (
+                Day01::get_input_kind(InputKind::Real),
+                Day01::get_input_kind(InputKind::Example),
+            )
+        });
+
+        assert_eq!(real.unwrap(), "real");
+        assert_eq!(example.unwrap(), "example");
+    }
+
+    #[test]
+    fn missing_example() {
+        let _lock = env_lock();
+        let dir = temp_dir("missing-example");
+        std::fs::write(dir.join("DAY_07.txt"), "real").unwrap();
+
+        let error = with_input_dir(&dir, || Day07::get_input_kind(InputKind::Example));
+        let message = error.unwrap_err().to_string();
+
+        assert!(
+            message.contains(&format!(
+                "{} (example input)",
+                dir.join("DAY_07_example.txt").display()
+            )),
+            "{}",
+            message
+        );
+    }
+
+    #[test]
+    fn year_layout_is_preferred() {
+        let _lock = env_lock();
+        let dir = temp_dir("year-layout");
+        std::fs::create_dir_all(dir.join("2015")).unwrap();
+        std::fs::write(dir.join("2015/DAY_01.txt"), "year").unwrap();
+        std::fs::write(dir.join("DAY_01.txt"), "flat").unwrap();
+
+        let input = with_input_dir(&dir, Day01::get_input);
+
+        assert_eq!(input.unwrap(), "year");
+    }
+
+    #[test]
+    fn flat_layout_is_a_fallback() {
+        let _lock = env_lock();
+        let dir = temp_dir("flat-layout");
+        std::fs::write(dir.join("DAY_01.txt"), "flat").unwrap();
+
+        let input = with_input_dir(&dir, Day01::get_input);
+
+        assert_eq!(input.unwrap(), "flat");
+    }
+
+    #[test]
+    fn missing_year_and_flat_layouts() {
+        let _lock = env_lock();
+        let dir = temp_dir("no-layout");
+
+        let error =
+            with_input_dir(&dir, || Day01::get_input_from(std::io::empty(), true)).unwrap_err();
+        let message = error.to_string();
+
+        assert!(matches!(error, SolutionError::MissingInput(_)));
+        assert!(message.contains(&dir.join("2015/DAY_01.txt").display().to_string()));
+        assert!(message.contains(&dir.join("DAY_01.txt").display().to_string()));
+    }
+
+    #[test]
+    fn get_input_with_template() {
+        let _lock = env_lock();
+        let dir = temp_dir("template");
+        std::fs::create_dir_all(dir.join("2022")).unwrap();
+        std::fs::write(dir.join("2022/day5.txt"), "year layout").unwrap();
+
+        std::env::set_var(INPUT_DIR_ENV, &dir);
+        let input = Day05::get_input();
+        let no_year = NoYear::get_input();
+        std::env::remove_var(INPUT_DIR_ENV);
+
+        assert_eq!(input.unwrap(), "year layout");
+        assert!(matches!(no_year, Err(SolutionError::InputTemplate(_))));
+    }
+}
@@ -0,0 +1,271 @@
+//! Standings of a private leaderboard
+//!
+//! Only available with the `fetch` feature.
+//!
+//! [crate::client::fetch_leaderboard] reads the JSON API of a private leaderboard, and its
+//! [Leaderboard] displays the standings as a compact grid:
+//!
+//! ```text
+//!            123
+//!   1)    24 **+ Alice
+//!   2)    10 .*. (anonymous user #202)
+//! ```
+//!
+//! `*` is a day with both stars, `+` a day with only the first one.
+//! A binary printing a leaderboard fits in a few lines:
+//!
+//! ```no_run
+//! // src/bin/leaderboard.rs, in `fn main`:
+//! match aoc::client::fetch_leaderboard(2022, 123456) {
+//!     Ok(leaderboard) => print!("{}", leaderboard),
+//!     Err(e) => eprintln!("{}", e),
+//! }
+//! ```
+//!
+//! The `leaderboard` binary does the same: `leaderboard <year> <board id>`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde_json::Value;
+
+use crate::solution::{Result, SolutionError};
+
+/// Standings of a private leaderboard, sorted by local score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub year: u16,
+    pub owner_id: u64,
+    pub members: Vec<Member>,
+}
+
+/// A member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Number of stars (1 or 2) of each day with at least one.
+    pub days: BTreeMap<u8, u8>,
+}
+
+impl Member {
+    /// Name of the member, or `(anonymous user #id)` like on the website.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Stars of the member on `day`: 0, 1 or 2.
+    pub fn stars_on(&self, day: u8) -> u8 {
+        self.days.get(&day).copied().unwrap_or(0)
+    }
+}
+
+impl Leaderboard {
+    /// Parses the response of the JSON API of a private leaderboard.
+    ///
+    /// Anonymous members, members without any star and missing fields are accepted.
+    pub fn parse(json: &str) -> Result<Self> {
+        let root: Value = serde_json::from_str(json)
+            .map_err(|_| invalid("the response is not JSON, is the session valid?"))?;
+
+        let year = root
+            .get("event")
+            .and_then(as_u64)
+            .and_then(|year| u16::try_from(year).ok())
+            .ok_or_else(|| invalid("no event"))?;
+        let owner_id = root.get("owner_id").and_then(as_u64).unwrap_or(0);
+
+        let mut members = match root.get("members") {
+            Some(Value::Object(members)) => members
+                .iter()
+                .map(|(id, member)| parse_member(id, member))
+                .collect::<Result<Vec<_>>>()?,
+            _ => return Err(invalid("no members")),
+        };
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(Leaderboard {
+            year,
+            owner_id,
+            members,
+        })
+    }
+
+    /// Last day with a star, among all the members.
+    fn last_day(&self) -> u8 {
+        self.members
+            .iter()
+            .filter_map(|member| member.days.keys().last())
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MARGIN: &str = "           ";
+        let days = 1..=self.last_day();
+
+        let tens: String = days
+            .clone()
+            .map(|day| match day / 10 {
+                0 => ' ',
+                tens => char::from(b'0' + tens),
+            })
+            .collect();
+        if !tens.trim().is_empty() {
+            writeln!(f, "{}{}", MARGIN, tens)?;
+        }
+        writeln!(
+            f,
+            "{}{}",
+            MARGIN,
+            days.clone()
+                .map(|day| char::from(b'0' + day % 10))
+                .collect::<String>()
+        )?;
+
+        for (rank, member) in (1..).zip(&self.members) {
+            let grid: String = days
+                .clone()
+                .map(|day| match member.stars_on(day) {
+                    0 => '.',
+                    1 => '+',
+                    _ => '*',
+                })
+                .collect();
+
+            writeln!(
+                f,
+                "{:>3}) {:>5} {} {}",
+                rank,
+                member.local_score,
+                grid,
+                member.display_name()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_member(id: &str, member: &Value) -> Result<Member> {
+    let id = member
+        .get("id")
+        .and_then(as_u64)
+        .or_else(|| id.parse().ok())
+        .ok_or_else(|| invalid(&format!("member {:?} has no id", id)))?;
+
+    let mut days = BTreeMap::new();
+    if let Some(Value::Object(completion)) = member.get("completion_day_level") {
+        for (day, levels) in completion {
+            let (Ok(day), Value::Object(levels)) = (day.parse::<u8>(), levels) else {
+                continue;
+            };
+            let stars = levels.len().min(2) as u8;
+            if stars > 0 {
+                days.insert(day, stars);
+            }
+        }
+    }
+
+    Ok(Member {
+        id,
+        name: member
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::to_owned),
+        local_score: member.get("local_score").and_then(as_u64).unwrap_or(0),
+        stars: member
+            .get("stars")
+            .and_then(as_u64)
+            .unwrap_or_else(|| days.values().map(|&stars| stars as u64).sum()),
+        days,
+    })
+}
+
+fn invalid(reason: &str) -> SolutionError {
+    SolutionError::Network(format!("invalid leaderboard: {}", reason))
+}
+
+/// Numbers, and numbers in a string (`"event": "2022"`).
+fn as_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        value => value.as_u64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../../tests/fixtures/leaderboard.json");
+
+    #[test]
+    fn parse_fixture() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+        assert_eq!(leaderboard.year, 2022);
+        assert_eq!(leaderboard.owner_id, 101);
+        assert_eq!(
+            leaderboard
+                .members
+                .iter()
+                .map(Member::display_name)
+                .collect::<Vec<_>>(),
+            vec!["Alice", "(anonymous user #202)", "Bob", "Zoé"]
+        );
+
+        let alice = &leaderboard.members[0];
+        assert_eq!((alice.local_score, alice.stars), (52, 7));
+        assert_eq!(alice.stars_on(3), 1);
+        assert_eq!(alice.stars_on(11), 2);
+        assert_eq!(alice.stars_on(4), 0);
+        assert!(leaderboard.members[3].days.is_empty());
+    }
+
+    #[test]
+    fn display() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+        assert_eq!(
+            leaderboard.to_string(),
+            [
+                "                    11",
+                "           12345678901",
+                "  1)    52 **+.......* Alice",
+                "  2)    18 .*......... (anonymous user #202)",
+                "  3)     3 +.......... Bob",
+                "  4)     0 ........... Zoé",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn invalid_responses() {
+        assert!(Leaderboard::parse("<html>login</html>").is_err());
+        assert!(Leaderboard::parse(r#"{"event": "2022"}"#).is_err());
+        assert!(Leaderboard::parse(r#"{"event": -1, "members": {}}"#).is_err());
+        assert_eq!(
+            Leaderboard::parse(r#"{"event": "2022", "members": {}}"#)
+                .unwrap()
+                .to_string(),
+            "           \n"
+        );
+    }
+}
@@ -0,0 +1,306 @@
+//! Routing of the runner's messages through the [log](https://docs.rs/log) facade
+//!
+//! With the `log` feature (enabled by default), the messages of [crate::Solution::test_part1],
+//! [crate::Solution::test_part2] and the error reports of [crate::solution!] (see
+//! [crate::error]) are emitted with `log::info!` and `log::error!`, and [crate::Solution::run] /
+//! [crate::Solution::run_par] log each phase at the debug level.
+//!
+//! As long as no logger is installed, the messages are printed as they were before,
+//! so nothing changes for users who don't care about logging.
+//! Use your favorite logger (env_logger, tracing-subscriber...) or [init_default_logger].
+//!
+//! With the `tracing` feature, each run is wrapped in an `aoc.run` span, and its phases in
+//! `aoc.parse`, `aoc.part1` and `aoc.part2` spans with `day` and `year` fields. They complement
+//! the reported durations, for instance to profile a day with a flamegraph once a subscriber is
+//! installed (here with `tracing-flame`):
+//!
+//! ```ignore
+//! use tracing_subscriber::prelude::*;
+//!
+//! let (flame, _guard) = tracing_flame::FlameLayer::with_file("./tracing.folded").unwrap();
+//! tracing_subscriber::registry().with(flame).init();
+//!
+//! Day07::run().expect("Day should run");
+//! // inferno-flamegraph < tracing.folded > flamegraph.svg
+//! ```
+
+use std::fmt::Arguments;
+use std::time::Duration;
+
+use humantime::format_duration;
+
+#[cfg(feature = "log")]
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Whether messages should go through `log` rather than being printed.
+#[cfg(feature = "log")]
+fn has_logger() -> bool {
+    log::max_level() != LevelFilter::Off
+}
+
+pub(crate) fn info(args: Arguments) {
+    #[cfg(feature = "log")]
+    if has_logger() {
+        log::info!("{}", args);
+        return;
+    }
+
+    println!("{}", args);
+}
+
+pub(crate) fn warn(args: Arguments) {
+    #[cfg(feature = "log")]
+    if has_logger() {
+        log::warn!("{}", args);
+        return;
+    }
+
+    eprintln!("{}", args);
+}
+
+/// Reports an error. Used by the macros.
+#[doc(hidden)]
+pub fn error(args: Arguments) {
+    #[cfg(feature = "log")]
+    if has_logger() {
+        log::error!("{}", args);
+        return;
+    }
+
+    println!("{}", args);
+}
+
+/// The error followed by its [std::error::Error::source] chain, one cause per line.
+///
+/// The causes already part of the message are skipped.
+pub(crate) fn error_chain(e: &dyn std::error::Error) -> String {
+    let mut message = e.to_string();
+    let mut source = e.source();
+
+    while let Some(cause) = source {
+        let cause_message = cause.to_string();
+        if !message.contains(&cause_message) {
+            message.push_str(&format!("\n  caused by: {}", cause_message));
+        }
+        source = cause.source();
+    }
+
+    message
+}
+
+/// Reports the error of a day rendered by [crate::error::report]. Unlike [error], it is printed
+/// on stderr when there is no logger.
+pub(crate) fn day_report(day: u8, title: &str, report: &str) {
+    self::report(format_args!("Day {} - {:?}\n{}", day, title, report));
+}
+
+/// Reports an error rendered by [crate::error::report], on stderr when there is no logger.
+pub(crate) fn report(args: Arguments) {
+    #[cfg(feature = "log")]
+    if has_logger() {
+        log::error!("{}", args);
+        return;
+    }
+
+    eprintln!("{}", args);
+}
+
+/// Reports the duration of a labeled [crate::time!].
+#[doc(hidden)]
+pub fn timing(label: &str, duration: Duration) {
+    info(format_args!("{}: {}", label, format_duration(duration)));
+}
+
+pub(crate) fn debug(_args: Arguments) {
+    #[cfg(feature = "log")]
+    log::debug!("{}", _args);
+}
+
+/// Minimal logger writing `[LEVEL] message` lines on stderr.
+#[cfg(feature = "log")]
+struct StderrLogger;
+
+#[cfg(feature = "log")]
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs a minimal logger writing on stderr, for those who don't want to pick a logger.
+///
+/// The level is read from `RUST_LOG` (`error`, `warn`, `info`, `debug` or `trace`)
+/// and defaults to `info`. Does nothing if a logger is already installed.
+///
+/// # Example
+/// ```
+/// aoc::init_default_logger();
+///
+/// log::info!("Hello from the logger");
+/// ```
+#[cfg(feature = "log")]
+pub fn init_default_logger() {
+    static LOGGER: StderrLogger = StderrLogger;
+
+    let level = std::env::var("RUST_LOG")
+        .ok()
+        .and_then(|level| level.trim().parse::<Level>().ok())
+        .unwrap_or(Level::Info);
+
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(level.to_level_filter());
+    }
+}
+
+#[cfg(all(test, feature = "log"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use crate::solution::{Result, SolutionError};
+    use crate::Solution;
+
+    struct Capture(Mutex<Vec<(Level, String)>>);
+
+    impl Log for Capture {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            self.0
+                .lock()
+                .unwrap()
+                .push((record.level(), record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+    fn records() -> Vec<(Level, String)> {
+        let _ = log::set_logger(&CAPTURE);
+        log::set_max_level(LevelFilter::Trace);
+
+        CAPTURE.0.lock().unwrap().clone()
+    }
+
+    fn logged(level: Level, message: &str) -> bool {
+        records()
+            .iter()
+            .any(|(l, m)| *l == level && m.contains(message))
+    }
+
+    struct Logged;
+    impl Solution for Logged {
+        const TITLE: &'static str = "Logged";
+        const DAY: u8 = 42;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input + 1000)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(input + 2000)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("7".to_owned())
+        }
+    }
+
+    #[test]
+    fn test_parts_log_at_info_level() {
+        records();
+
+        Logged::test_part1("1").unwrap();
+        Logged::test_part2("1").unwrap();
+
+        assert!(logged(Level::Info, "Part1: Some(1001)"));
+        assert!(logged(Level::Info, "Part2: Some(2001)"));
+    }
+
+    #[test]
+    fn runners_log_phases_at_debug_level() {
+        records();
+
+        Logged::run().unwrap();
+        Logged::run_par().unwrap();
+
+        for phase in ["parse", "part 1", "part 2"] {
+            assert!(logged(Level::Debug, &format!("Day 42: {} started", phase)));
+            assert!(logged(Level::Debug, &format!("Day 42: {} done in", phase)));
+        }
+    }
+
+    #[test]
+    fn macro_errors_log_at_error_level() {
+        records();
+
+        super::error(format_args!("Day 42 - \"Logged\" Error: oops"));
+
+        assert!(logged(Level::Error, "Day 42 - \"Logged\" Error: oops"));
+    }
+
+    #[test]
+    fn day_reports_log_at_error_level() {
+        records();
+
+        crate::error::print_day_report(42, None, "Logged", &SolutionError::parse("oops"));
+
+        assert!(logged(
+            Level::Error,
+            "Day 42 - \"Logged\"\nerror: Invalid Puzzle input: oops\nhint: "
+        ));
+    }
+
+    #[test]
+    fn failures_log_at_error_level() {
+        records();
+
+        let code = crate::output::fail(&SolutionError::parse("oops"));
+
+        assert_eq!(code, std::process::ExitCode::from(1));
+        assert!(logged(
+            Level::Error,
+            "error: Invalid Puzzle input: oops\nhint: "
+        ));
+    }
+
+    #[test]
+    fn stdin_fallback_notes_fit_on_a_line() {
+        let _lock = crate::test_util::env_lock();
+        records();
+        let path = crate::test_util::temp_dir("stdin-note").join("DAY_07.txt");
+
+        let input = crate::input::read_or_stdin(Ok(path.clone()), "piped".as_bytes(), false);
+
+        assert_eq!(input.unwrap(), "piped");
+        assert!(records().contains(&(
+            Level::Warn,
+            format!(
+                "Note: {} not found, reading the input from stdin",
+                path.display()
+            )
+        )));
+    }
+}
@@ -23,23 +23,122 @@
 /// assert_eq!(duration.as_secs(), 2)
 ///
 /// ```
+///
+/// With a label, the duration is also printed (or logged at the info level with the `log`
+/// feature and a logger installed).
+///
+///```
+/// let (sum, _) = aoc::time!("inner loop", (0..1_000).sum::<u32>());
+/// // inner loop: 12us 400ns
+///
+/// assert_eq!(sum, 499_500);
+/// ```
+///
+/// The duration is read from the [crate::clock::RealClock]. Another [crate::clock::Clock] can be
+/// given first, eg: a [crate::clock::MockClock] for deterministic durations in the tests.
+///
+///```
+/// use std::time::Duration;
+/// use aoc::clock::MockClock;
+///
+/// let (_, duration) = aoc::time!(clock: MockClock, MockClock::advance(Duration::from_secs(2)));
+///
+/// assert_eq!(duration, Duration::from_secs(2));
+/// ```
 #[macro_export]
 macro_rules! time {
-    ($e:expr) => {{
-        use ::std::time::Instant;
+    (clock: $clock:ty, $label:expr, $e:expr) => {{
+        let (result, elapsed) = $crate::time!(clock: $clock, $e);
+        $crate::logging::timing($label, elapsed);
 
-        let start = Instant::now();
+        (result, elapsed)
+    }};
+    (clock: $clock:ty, $e:expr) => {{
+        let start = <$clock as $crate::clock::Clock>::now();
         let result = $e;
-        let elapsed = start.elapsed();
+        let elapsed = <$clock as $crate::clock::Clock>::elapsed(start);
 
         (result, elapsed)
     }};
+    ($label:expr, $e:expr) => {
+        $crate::time!(clock: $crate::clock::RealClock, $label, $e)
+    };
+    ($e:expr) => {
+        $crate::time!(clock: $crate::clock::RealClock, $e)
+    };
 }
 
-/// Utility macro that calls [crate::Solution::run] and displays it's output
+/// Day number of the file the macro is used in, read at compile time.
+///
+/// Avoids keeping `const DAY` in sync with the file name by hand.
+/// The build fails when the path has no day number. See [crate::filename] for the rules.
 ///
 /// # Example
+/// ```ignore
+/// // src/bin/2022_day05.rs
+/// impl Solution for Day05 {
+///     const TITLE: &'static str = "Supply Stacks";
+///     const DAY: u8 = aoc::day_from_file!(); // 5
+///     const YEAR: Option<u16> = aoc::year_from_file!(); // Some(2022)
+///     //-- snip --
+/// }
+/// ```
+#[macro_export]
+macro_rules! day_from_file {
+    () => {
+        $crate::filename::day(file!())
+    };
+}
+
+/// Year of the file the macro is used in, read at compile time. `None` when the path has none.
+///
+/// See [crate::day_from_file!]
+#[macro_export]
+macro_rules! year_from_file {
+    () => {
+        $crate::filename::year(file!())
+    };
+}
+
+/// Utility macro that calls [crate::Solution::run] and displays it's output
+///
+/// The output format is read at runtime from the `AOC_FORMAT` environment variable,
+/// see [crate::output] for the supported values (`pretty` by default, `json`, `csv`, `markdown`
+/// and `quiet`). An unknown value is reported on stderr and the result is printed as `pretty`.
+///
+/// ```shell
+/// AOC_FORMAT=json cargo run --example dayxx
+/// ```
+///
+/// An error is reported on stderr with its causes, the faulty line of the input when it is
+/// located in the input, and a hint. (See [crate::error::report])
+///
+/// With `exit_code`, evaluates to the [std::process::ExitCode] of the day instead of `()`:
+/// success, or `1` after reporting an error, like [crate::output::fail]. Return it from `main`
+/// for the process to fail when the day does, eg: in CI.
+///
+/// With `strict`, a part without an answer is an error. (See [crate::Solution::run_strict])
+/// Both combine as `aoc::solution!(Day25, strict, exit_code)`.
+///
+/// ```
+///# use aoc::Solution;
+///# use aoc::solution::{PartStatus, Result};
+///# struct Day25;
+///# impl Solution for Day25 {
+///#     const TITLE: &'static str = "";const DAY: u8 = 25;
+///#     const PART2: PartStatus = PartStatus::Skipped;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(123) }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+///# }
+/// fn run_solution() {
+///     aoc::solution!(Day25, strict);
+/// }
 /// ```
+///
+/// # Example
+/// ```no_run
 /// use aoc::Solution;
 ///# use aoc::solution::SolutionError;
 ///
@@ -65,24 +164,103 @@ macro_rules! time {
 /// fn run_solution() {
 ///     aoc::solution!(DayXX);
 /// }
+///
+/// fn main() -> std::process::ExitCode {
+///     aoc::solution!(DayXX, exit_code)
+/// }
 /// ```
 #[macro_export]
 macro_rules! solution {
     ($d: ident) => {{
-        match $d::run_par() {
+        let _ = $crate::solution!($d, exit_code);
+    }};
+    ($d: ident, strict) => {{
+        let _ = $crate::solution!($d, strict, exit_code);
+    }};
+    ($d: ident, exit_code) => {
+        $crate::solution!(@report $d, $d::run_par())
+    };
+    ($d: ident, strict, exit_code) => {
+        $crate::solution!(@report $d, $d::run_par_strict())
+    };
+    (@report $d: ident, $run: expr) => {{
+        match $run.and_then($crate::solution::SolutionResult::checked) {
             Ok(result) => {
-                println!("{}", result)
-            }
-            Err(e) => {
-                println!("Day {} - {:?} Error: {}", $d::DAY, $d::TITLE, e)
+                if let Err(e) = $crate::output::emit(&result.view()) {
+                    eprintln!("{}", e);
+                    println!("{}", result)
+                }
+                ::std::process::ExitCode::SUCCESS
             }
+            Err(e) => $crate::output::fail_day($d::DAY, $d::YEAR, $d::TITLE, &e),
         }
     }};
 }
+
+/// Like [crate::solution!], on the input file at the given path instead of the day's input.
+/// (See [crate::Solution::run_file])
+///
+/// With `exit_code`, evaluates to the [std::process::ExitCode] of the day instead of `()`,
+/// and `strict` makes a part without an answer an error, as with [crate::solution!].
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+///# use aoc::solution::SolutionError;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+///#         Ok(())
+///#         }
+///#
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///#         Some(123)
+///#     }
+///#
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///#         Some(456)
+///#     }
+/// }
+///
+/// fn run_solution(path: &str) {
+///     aoc::solution_file!(DayXX, path);
+/// }
+/// ```
+#[macro_export]
+macro_rules! solution_file {
+    ($d: ident, $path: expr) => {{
+        let _ = $crate::solution_file!($d, $path, exit_code);
+    }};
+    ($d: ident, $path: expr, strict) => {{
+        let _ = $crate::solution_file!($d, $path, strict, exit_code);
+    }};
+    ($d: ident, $path: expr, exit_code) => {{
+        let path: &str = $path;
+        $crate::solution!(@report $d, $d::run_file(path))
+    }};
+    ($d: ident, $path: expr, strict, exit_code) => {{
+        let path: &str = $path;
+        $crate::solution!(@report $d, $d::run_file(path).and_then(|result| {
+            result.solved($d::PART1, $d::PART2)
+        }))
+    }};
+}
+
 /// Wraps aoc::solution! inside a main function
 ///
 /// Helper function when the main is only in charge of running 1 solution.
 ///
+/// The input file can be given on the command line with `--input <path>`, which runs
+/// aoc::solution_file! instead. (See [crate::input::input_arg])
+///
+/// The process exits with `1` when the day fails. (See [crate::output::fail])
+/// With `aoc::run!(DayXX, strict)`, a part without an answer fails it too.
+///
 /// @example
 /// ```
 /// use aoc::Solution;
@@ -113,12 +291,482 @@ macro_rules! solution {
 #[macro_export]
 macro_rules! run {
     ($d:ident) => {
-        fn main() {
-            ::aoc::solution!($d)
+        fn main() -> ::std::process::ExitCode {
+            match ::aoc::input::input_arg(::std::env::args()) {
+                Some(path) => ::aoc::solution_file!($d, &path, exit_code),
+                None => ::aoc::solution!($d, exit_code),
+            }
         }
-    }
+    };
+    ($d:ident, strict) => {
+        fn main() -> ::std::process::ExitCode {
+            match ::aoc::input::input_arg(::std::env::args()) {
+                Some(path) => ::aoc::solution_file!($d, &path, strict, exit_code),
+                None => ::aoc::solution!($d, strict, exit_code),
+            }
+        }
+    };
+}
+
+/// Runs several days one after the other with [crate::solution!]'s output,
+/// then prints the total time.
+///
+/// Evaluates to the days' results, in the given order. (See [crate::runner::run_all])
+///
+/// # Example
+/// ```no_run
+/// use aoc::Solution;
+///# use aoc::solution::SolutionError;
+///
+/// struct Day01;
+/// impl Solution for Day01 {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 1;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+///#         Ok(())
+///#         }
+///#
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///#         Some(123)
+///#     }
+///#
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///#         Some(456)
+///#     }
+/// }
+///# struct Day02;
+///# impl Solution for Day02 {
+///#     const TITLE: &'static str = "";const DAY: u8 = 2;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> { Ok(()) }
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+///# }
+///
+/// fn run_season() {
+///     aoc::run_all!(Day01, Day02);
+/// }
+/// ```
+#[macro_export]
+macro_rules! run_all {
+    ($($d:ident),+ $(,)?) => {
+        $crate::runner::run_all(&[$($crate::runner::Day::of::<$d>()),+])
+    };
 }
 
+/// Runs several days like [crate::run_all!], then sums them up in a
+/// [crate::runner::SeasonOutcome], whose failures are printed on stderr.
+///
+/// Return its [crate::runner::SeasonOutcome::into_exit_code] from `main` to fail a CI job when a
+/// day is wrong, broken or unsolved. Pass `--allow-incomplete` (or set
+/// `AOC_ALLOW_INCOMPLETE=1`) to accept the days without an answer.
+///
+/// # Example
+/// ```ignore
+/// fn main() -> std::process::ExitCode {
+///     aoc::run_season!(Day01, Day02).into_exit_code()
+/// }
+/// ```
+#[macro_export]
+macro_rules! run_season {
+    ($($d:ident),+ $(,)?) => {
+        $crate::runner::run_season(&[$($crate::runner::Day::of::<$d>()),+])
+    };
+}
+
+/// Parallel version of [crate::run_all!]. Requires the `rayon` feature.
+///
+/// The days run concurrently on rayon's thread pool. Their results are printed in day order
+/// once they are all solved. (See [crate::runner::run_all_par])
+///
+/// # Example
+/// ```ignore
+/// fn run_season() {
+///     aoc::run_all_par!(Day01, Day02, Day03);
+/// }
+/// ```
+#[macro_export]
+macro_rules! run_all_par {
+    ($($d:ident),+ $(,)?) => {
+        $crate::runner::run_all_par(&[$($crate::runner::Day::of::<$d>()),+])
+    };
+}
+
+/// Overrides [crate::Solution::get_input] with a file embedded in the binary at compile time.
+///
+/// Must be used inside the `impl Solution` block. The file is read with [include_str!],
+/// so **its path is relative to the source file invoking the macro**, not to the crate root.
+/// A missing file is a compile error. The content is normalized like the default
+/// [crate::Solution::get_input]. (See [crate::input::normalize])
+///
+/// This gives a self-contained binary without pasting the input as a string literal.
+///
+/// # Example
+/// ```ignore
+/// use aoc::Solution;
+///
+/// struct Day01;
+/// impl Solution for Day01 {
+///     //-- snip --
+///
+///     // reads `<this file's directory>/inputs/DAY_01.txt`
+///     aoc::embed_input!("inputs/DAY_01.txt");
+/// }
+/// ```
+#[macro_export]
+macro_rules! embed_input {
+    ($path:literal) => {
+        fn get_input() -> $crate::solution::Result<String> {
+            Ok($crate::input::normalize(
+                include_str!($path).to_owned(),
+                Self::TRIM_TRAILING_NEWLINE,
+                Self::NORMALIZE_CRLF,
+            ))
+        }
+    };
+}
+
+/// Overrides [crate::Solution::get_input] with the day's input file, embedded in the binary at
+/// compile time.
+///
+/// Must be used inside the `impl Solution` block. Unlike [crate::embed_input!], the path follows
+/// the default layout and is relative to the crate root (`CARGO_MANIFEST_DIR`):
+///
+/// - `include_input!(7)` embeds `<crate root>/inputs/DAY_07.txt`
+/// - `include_input!(year 2022, day 7)` embeds `<crate root>/inputs/2022/DAY_07.txt`
+/// - `include_input!(dir "tests/inputs", day 7)` embeds `<crate root>/tests/inputs/DAY_07.txt`,
+///   and can be combined with `year`: `include_input!(dir "puzzles", year 2022, day 7)`
+///
+/// A missing file is a compile error from [include_str!], mentioning the full expected path.
+/// The content is normalized like the default [crate::Solution::get_input].
+///
+/// # Example
+/// ```ignore
+/// use aoc::Solution;
+///
+/// struct Day07;
+/// impl Solution for Day07 {
+///     //-- snip --
+///
+///     aoc::include_input!(year 2022, day 7);
+/// }
+/// ```
+#[macro_export]
+macro_rules! include_input {
+    ($day:tt) => {
+        $crate::include_input!(dir "inputs", day $day);
+    };
+    (day $day:tt) => {
+        $crate::include_input!(dir "inputs", day $day);
+    };
+    (year $year:literal, day $day:tt) => {
+        $crate::include_input!(dir "inputs", year $year, day $day);
+    };
+    (dir $dir:literal, year $year:literal, day $day:tt) => {
+        $crate::include_input!(@file concat!(
+            env!("CARGO_MANIFEST_DIR"), "/", $dir, "/", $year, "/DAY_", $crate::__padded_day!($day), ".txt"
+        ));
+    };
+    (dir $dir:literal, day $day:tt) => {
+        $crate::include_input!(@file concat!(
+            env!("CARGO_MANIFEST_DIR"), "/", $dir, "/DAY_", $crate::__padded_day!($day), ".txt"
+        ));
+    };
+    (@file $path:expr) => {
+        fn get_input() -> $crate::solution::Result<String> {
+            Ok($crate::input::normalize(
+                include_str!($path).to_owned(),
+                Self::TRIM_TRAILING_NEWLINE,
+                Self::NORMALIZE_CRLF,
+            ))
+        }
+    };
+}
+
+/// Zero padded day of a literal, for [crate::include_input!].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __padded_day {
+    (1) => {
+        "01"
+    };
+    (01) => {
+        "01"
+    };
+    (2) => {
+        "02"
+    };
+    (02) => {
+        "02"
+    };
+    (3) => {
+        "03"
+    };
+    (03) => {
+        "03"
+    };
+    (4) => {
+        "04"
+    };
+    (04) => {
+        "04"
+    };
+    (5) => {
+        "05"
+    };
+    (05) => {
+        "05"
+    };
+    (6) => {
+        "06"
+    };
+    (06) => {
+        "06"
+    };
+    (7) => {
+        "07"
+    };
+    (07) => {
+        "07"
+    };
+    (8) => {
+        "08"
+    };
+    (08) => {
+        "08"
+    };
+    (9) => {
+        "09"
+    };
+    (09) => {
+        "09"
+    };
+    ($day:tt) => {
+        $day
+    };
+}
+
+/// Registers the inputs embedded by [crate::embed::generate] in the `build.rs`.
+///
+/// Call it once at the start of `main`: the default [crate::Solution::get_input] then reads the
+/// embedded inputs. Only available with the `embed-inputs` feature. (See [crate::embed])
+///
+/// # Example
+/// ```ignore
+/// fn main() {
+///     aoc::embed_inputs!();
+///
+///     Day01::run().expect("Day should run");
+/// }
+/// ```
+#[cfg(feature = "embed-inputs")]
+#[macro_export]
+macro_rules! embed_inputs {
+    () => {
+        $crate::embed::register({
+            include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+            embedded_input
+        })
+    };
+}
+
+/// Checks that [crate::Solution::parse] never panics on malformed input.
+///
+/// Feeds `iterations` random strings and mutations of the real input to `parse`, which must
+/// return an `Err` rather than panic. The generator is seeded (with [crate::fuzz::DEFAULT_SEED]
+/// unless given), so a failure is reproducible. (See [crate::fuzz])
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+/// use aoc::solution::{Result, SolutionError};
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     // -- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = Vec<u32>;type P1 = ();type P2 = ();
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+///
+///     fn parse(input: &str) -> Result<Self::Input> {
+///         input.lines().map(|line| line.parse().map_err(|_| SolutionError::parse("not a number"))).collect()
+///     }
+/// }
+///
+/// aoc::fuzz!(DayXX, 1000);
+/// aoc::fuzz!(DayXX, 1000, 42);
+/// ```
+#[macro_export]
+macro_rules! fuzz {
+    ($d:ident, $iterations:expr) => {
+        $crate::fuzz!($d, $iterations, $crate::fuzz::DEFAULT_SEED)
+    };
+    ($d:ident, $iterations:expr, $seed:expr) => {
+        if let Err(failure) = $crate::fuzz::parse::<$d>($iterations, $seed) {
+            panic!("{}", failure);
+        }
+    };
+}
+
+/// Implements an identity [crate::Solution::parse], for days working on the raw input.
+///
+/// Must be used inside the `impl Solution` block. It sets `type Input = String`,
+/// so the day only has to write [crate::Solution::part1] and [crate::Solution::part2].
+/// It also sets [crate::Solution::IDENTITY_PARSE]: the runners don't report any parse time for
+/// such days.
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     const TITLE: &'static str = "";
+///     const DAY: u8 = 0;
+///     type P1 = usize;
+///     type P2 = usize;
+///
+///     aoc::no_parse!();
+///
+///     fn part1(input: &String) -> Option<Self::P1> {
+///         Some(input.lines().count())
+///     }
+///
+///     fn part2(input: &String) -> Option<Self::P2> {
+///         Some(input.len())
+///     }
+///#
+///#    fn get_input() -> aoc::solution::Result<String> {
+///#        Ok("a\nb".to_owned())
+///#    }
+/// }
+///
+/// let result = DayXX::run().unwrap().view();
+///
+/// assert!(DayXX::IDENTITY_PARSE);
+/// assert_eq!(result.part1.as_deref(), Some("2"));
+/// assert_eq!(result.parse_duration, std::time::Duration::ZERO);
+/// ```
+#[macro_export]
+macro_rules! no_parse {
+    () => {
+        type Input = String;
+
+        const IDENTITY_PARSE: bool = true;
+
+        fn parse(input: &str) -> $crate::solution::Result<String> {
+            Ok(input.to_owned())
+        }
+    };
+}
+
+/// Implements [crate::Solution::parse] with the [std::str::FromStr] implementation of `Input`.
+///
+/// Must be used inside the `impl Solution` block. It sets `type Input` to the given type.
+/// A parse error is reported as [crate::solution::SolutionError::ParseError].
+/// (See [crate::solution::parse_fromstr])
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use aoc::Solution;
+///
+/// struct Rucksack(String, String);
+/// impl FromStr for Rucksack {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         let (left, right) = s.split_at(s.len() / 2);
+///         Ok(Rucksack(left.to_owned(), right.to_owned()))
+///     }
+/// }
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     const TITLE: &'static str = "";
+///     const DAY: u8 = 0;
+///     type P1 = usize;
+///     type P2 = usize;
+///
+///     aoc::implement_fromstr!(Rucksack);
+///
+///     fn part1(input: &Rucksack) -> Option<Self::P1> {
+///         Some(input.0.len())
+///     }
+///
+///     fn part2(input: &Rucksack) -> Option<Self::P2> {
+///         Some(input.1.len())
+///     }
+/// }
+///
+/// assert_eq!(DayXX::parse("vJrwpWtwJgWr").unwrap().1, "twJgWr");
+/// ```
+#[macro_export]
+macro_rules! implement_fromstr {
+    ($input:ty) => {
+        type Input = $input;
+
+        fn parse(input: &str) -> $crate::solution::Result<$input> {
+            $crate::solution::parse_fromstr(input)
+        }
+    };
+}
+
+/// Reads and parses the day's input then prints [crate::Solution::visualize]'s rendering.
+///
+/// Nothing is printed when the day doesn't override [crate::Solution::visualize].
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+///# use aoc::solution::SolutionError;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+///#         Ok(())
+///#         }
+///#
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///#         Some(123)
+///#     }
+///#
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///#         Some(456)
+///#     }
+///#
+///#     fn get_input() -> Result<String, SolutionError> {
+///#         Ok("".to_owned())
+///#     }
+///
+///     fn visualize(input: &Self::Input) -> Option<String> {
+///         Some("#..#\n.##.".to_owned())
+///     }
+/// }
+///
+/// aoc::visualize!(DayXX);
+/// ```
+#[macro_export]
+macro_rules! visualize {
+    ($d: ident) => {{
+        match $d::get_input().and_then(|input| $d::parse_ctx(&input, $d::DAY, $d::YEAR)) {
+            Ok(input) => {
+                if let Some(frame) = $d::visualize(&input) {
+                    println!("{}", frame)
+                }
+            }
+            Err(e) => $crate::error::print_day_report($d::DAY, $d::YEAR, $d::TITLE, &e),
+        }
+    }};
+}
 
 /// Wrapper for `impl Solution for $name {}`
 ///
@@ -127,35 +775,84 @@ macro_rules! run {
 ///  - name             - name of the struct. Eg: Day00
 ///  - title            - title of day's puzzle
 ///  - day              - puzzle's day
-///  - parse function   - parse input into Self::Input
+///  - input_path       - optional, see [crate::Solution::INPUT_TEMPLATE]
+///  - input_file       - optional, embeds the input at compile time. See [crate::include_input!]
+///    eg: `input_file: day 7;` or `input_file: year 2022, day 7;`
+///  - parse function   - parse input into Self::Input. Without a function, parses it with
+///    [crate::parse_default], eg: `parse -> aoc::Lines<i64>;`
 ///  - part_1 function  - solve part 1 of puzzle
-///  - part_2 function  - solve part 2 of puzzle
+///  - part_2 function  - optional, solve part 2 of puzzle. Without it, `P2` is
+///    [crate::solution::Unsolved] and [crate::Solution::part2] returns `None`
+///  - expect_1         - optional, known answer of part 1. See [crate::Solution::EXPECTED_P1]
+///  - expect_2         - optional, known answer of part 2. See [crate::Solution::EXPECTED_P2]
+///
 /// @example
 /// ```
 ///use itertools::Itertools;
-///use aoc::solution::SolutionError;
+///use aoc::OptionExt;
 ///
 ///aoc::implement! {
 ///    name: Day00;
 ///    title: "addition or product";
 ///    day: 0;
 ///#    input : "12345".to_owned();
-///    parse   -> Vec<u32> : |input: &str| input.chars().map(|c| c.to_digit(10).ok_or(SolutionError::ParseError)).collect();
+///    input_path: "input/day{day:02}.txt";
+///    parse   -> Vec<u32> : |input: &str| input.chars().map(|c| c.to_digit(10).ok_or_parse("not a digit")).collect();
 ///    part_1  -> u32      : |input: &Self::Input| input.iter().sum1();
 ///    part_2  -> u32      : |input: &Self::Input| input.iter().product1();
+///    expect_1: "15";
+///    expect_2: "120";
+///}
+///
+///# assert!(Day00::input_path().unwrap().ends_with("input/day00.txt"));
+///# assert_eq!(Day00::run().unwrap().verified(), Some((true, true)));
+/// ```
+///
+/// A day with only part 1 solved:
+/// ```
+///aoc::implement! {
+///    name: Day01;
+///    title: "Sonar Sweep";
+///    day: 1;
+///#    input : "199\n200\n208\n200".to_owned();
+///    parse   -> aoc::Lines<u32>;
+///    part_1  -> usize    : |input: &Self::Input| Some(input.windows(2).filter(|w| w[1] > w[0]).count());
 ///}
+///
+///# assert_eq!(Day01::run().unwrap().view().part1.as_deref(), Some("2"));
 /// ```
 ///
 #[macro_export]
 macro_rules! implement {
+    (@parse $input:ident) => {
+        $crate::parse_default($input)
+    };
+    (@parse $input:ident, $parse:expr) => {{
+        let fun = $parse;
+        fun($input)
+    }};
+    (@part2 $input:ident) => {{
+        let _ = $input;
+        None
+    }};
+    (@part2 $input:ident, $part2:expr) => {{
+        let fun = $part2;
+        fun($input)
+    }};
+    (@type) => { $crate::solution::Unsolved };
+    (@type $t:ident) => { $t };
     (
         name    :   $name:ident;
         title   :   $title:expr;
         day     :   $day:expr;
         $(input :   $input:expr;)?
-        parse   -> $ti:ty :   $parse:expr;
+        $(input_path : $input_path:expr;)?
+        $(input_file : $(dir $file_dir:literal,)? $(year $file_year:literal,)? day $file_day:tt;)?
+        parse   -> $ti:ty $(:   $parse:expr)?;
         part_1  ->$tp1:ident :   $part1:expr;
-        part_2  ->$tp2:ident :   $part2:expr;
+        $(part_2  ->$tp2:ident :   $part2:expr;)?
+        $(expect_1 : $expect1:expr;)?
+        $(expect_2 : $expect2:expr;)?
 
     ) => {
         use aoc::Solution;
@@ -164,13 +861,21 @@ macro_rules! implement {
         impl Solution for $name {
                 const TITLE: &'static str = $title;
                 const DAY: u8 = $day;
+            $(
+                const INPUT_TEMPLATE: &'static str = $input_path;
+            )?
+            $(
+                const EXPECTED_P1: Option<&'static str> = Some($expect1);
+            )?
+            $(
+                const EXPECTED_P2: Option<&'static str> = Some($expect2);
+            )?
                 type Input = $ti;
                 type P1 = $tp1;
-                type P2 = $tp2;
+                type P2 = $crate::implement!(@type $($tp2)?);
 
                 fn parse(input: &str) -> aoc::solution::Result<Self::Input> {
-                    let fun = $parse;
-                    fun(input)
+                    $crate::implement!(@parse input $(, $parse)?)
                 }
 
                 fn part1(input: &Self::Input) -> Option<Self::P1> {
@@ -179,14 +884,16 @@ macro_rules! implement {
                 }
 
                 fn part2(input: &Self::Input) -> Option<Self::P2> {
-                   let fun = $part2;
-                    fun(input)
+                    $crate::implement!(@part2 input $(, $part2)?)
                 }
             $(
                 fn get_input() -> aoc::solution::Result<String> {
                     Ok($input)
                 }
             )?
+            $(
+                $crate::include_input!($(dir $file_dir,)? $(year $file_year,)? day $file_day);
+            )?
         }
     }
 }
@@ -226,12 +933,93 @@ macro_rules! implement {
 /// }
 /// ```
 ///
+/// An example can be given the `day` and `year` passed to [crate::Solution::parse_ctx], eg: for
+/// a parser shared by several days. They default to the day's [crate::Solution::DAY] and
+/// [crate::Solution::YEAR].
+///
+/// ```
+/// use aoc::Solution;
+///# use aoc::solution::SolutionError;
+///
+/// struct Intcode;
+/// impl Solution for Intcode {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 2;
+///#     type Input = (u8, Option<u16>);type P1 = u8; type P2 = u16;
+///#
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+///#         Ok((Self::DAY, Self::YEAR))
+///#     }
+///     fn parse_ctx(input: &str, day: u8, year: Option<u16>) -> Result<Self::Input, SolutionError> {
+///         Ok((day, year))
+///     }
+///#
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///#         Some(input.0)
+///#     }
+///#
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///#         input.1
+///#     }
+/// }
+///
+/// aoc::example! {
+///     [Intcode]
+///     default: "1,0,0,0,99" => Some(2) => None
+///     day_09 { day: 9, year: 2019 }: "109,1,204,-1" => Some(9) => Some(2019)
+/// }
+/// ```
+///
+/// Without an input, the example file of the day is used, tested in a `tests_example_file_DayXX`
+/// module. (See [crate::Solution::get_input_kind] and [crate::input::InputKind::Example])
+///
+/// ```ignore
+/// // reads inputs/DAY_XX_example.txt
+/// aoc::example! {
+///     [DayXX] => Some(123) => Some(456)
+/// }
+/// ```
+///
 #[macro_export]
+#[allow(clippy::crate_in_macro_def)]
 macro_rules! example {
+    (
+        [$d:ident]
+            => $part1:expr
+            $(=> $part2:expr)?
+    ) => {
+        ::concat_idents::concat_idents!(mod_name = tests_example_file_, $d {
+            #[cfg(test)]
+            #[allow(non_snake_case)]
+            mod mod_name {
+                use crate::*;
+                use crate::{$d};
+
+                fn example() -> String {
+                    $d::get_input_kind($crate::input::InputKind::Example)
+                        .expect("couldn't read the example:")
+                }
+
+                #[test]
+                fn part1() {
+                    let (r, _) = $d::test_part1_silent(&example()).expect("couldn't run test:");
+                    assert_eq!(r, $part1);
+                }
+
+            $(
+                #[test]
+                fn part2() {
+                    let (r, _) = $d::test_part2_silent(&example()).expect("couldn't run test:");
+                    assert_eq!(r, $part2);
+                }
+            )?
+            }
+        });
+    };
     (
         [$d:ident]
         $(
-            $name:ident: $input:expr
+            $name:ident $({ $($key:ident: $value:expr),* $(,)? })?: $input:expr
                 => $part1:expr
                 $(=> $part2:expr)?
         )+
@@ -243,32 +1031,50 @@ macro_rules! example {
                  use crate::*;
                  use crate::{$d};
 
+                 #[allow(unused_mut)]
+                 fn parse(input: &str) -> $crate::solution::Result<<$d as $crate::Solution>::Input> {
+                     let mut day = $d::DAY;
+                     let mut year = $d::YEAR;
+                     $($($crate::example!(@context day year $key $value);)*)?
+
+                     $d::parse_ctx(input, day, year)
+                 }
+
                  #[test]
                  fn part1() {
-                     let (r, _) = $d::test_part1($input).expect("couldn't run test:");
+                     let (r, _) = $d::test_part1_with($input, parse).expect("couldn't run test:");
                      assert_eq!(r, $part1);
                  }
 
              $(
                  #[test]
                  fn part2() {
-                     let (r, _) = $d::test_part2($input).expect("couldn't run test:");
+                     let (r, _) = $d::test_part2_with($input, parse).expect("couldn't run test:");
                      assert_eq!(r, $part2);
                  }
              )?
             }
         });
        )+
-    }
+    };
+    (@context $day:ident $year:ident day $value:expr) => {
+        $day = $value
+    };
+    (@context $day:ident $year:ident year $value:expr) => {
+        $year = Some($value)
+    };
 }
 
-
 /// Repeating tests that can be run for each Solution.
 ///
 /// Compared to `aoc::test!` macro, this one is expected to exists only once per tests module.
 /// The reason is that the test name are not generated based on input. Calling the macro twice
 /// will throw a compilation error.
 ///
+/// The generated tests check that the input exists, is not empty (unless
+/// [crate::Solution::ALLOW_EMPTY_INPUT]) and that [crate::Solution::parse] accepts it.
+/// Pass `skip_parse` for days where parsing is too expensive to be run by the tests.
+///
 /// # Example
 /// ```
 /// #[cfg(test)]
@@ -277,15 +1083,31 @@ macro_rules! example {
 ///   use crate::{DayXX as day_xx};
 ///
 ///   aoc::test_common!(day_xx);
+///   // or, without the parse check:
+///   // aoc::test_common!(day_xx, skip_parse);
 /// }
 /// ```
 ///
 #[macro_export]
 macro_rules! test_common {
     ($d: ident) => {
+        $crate::test_common!($d, skip_parse);
+
+        #[test]
+        fn input_parses() {
+            let input = $d::get_input().expect("An input is required");
+            $d::parse_ctx(&input, $d::DAY, $d::YEAR).expect("The input should parse");
+        }
+    };
+    ($d: ident, skip_parse) => {
         #[test]
         fn input_exists() {
-            $d::get_input().expect("An input is required");
+            let input = $d::get_input().expect("An input is required");
+            assert!(
+                $d::ALLOW_EMPTY_INPUT || !input.trim().is_empty(),
+                "The input of day {} is empty",
+                $d::DAY
+            );
         }
     };
 }
@@ -293,7 +1115,7 @@ macro_rules! test_common {
 ///
 /// Will:
 /// - generate tests for test_part1 and test_part2
-/// - call [crate::Solution::test_part1] and [crate::Solution::test_part2] under the hood
+/// - call [crate::Solution::test_part1_silent] and [crate::Solution::test_part2_silent] under the hood
 /// - assert for result equality
 ///
 /// Example
@@ -339,6 +1161,16 @@ macro_rules! test_common {
 ///     // is used multiple times in the same module
 ///     "optional_suffix"
 ///   );
+///
+///   //parse the example with another parser than DayXX::parse
+///   aoc::test!(
+///     day_xx,
+///     with_parse = |input| DayXX::parse(&input.replace('-', "")),
+///     "Some-Input",
+///     Some(123),
+///     Some(456),
+///     "with_parse"
+///   );
 /// }
 ///
 /// ```
@@ -355,11 +1187,28 @@ macro_rules! test {
          $crate::test!($d, $input, $part1, $part2 $(, $name )?);
        )+
     };
+    ($d:ident, with_parse = $parse:expr, $input:expr, $e1:expr, $e2:expr $(, $name:expr )? ) => {
+        ::concat_idents::concat_idents!(test_name = $d, _part1, $( _, $name)? {
+            #[test]
+            fn test_name() {
+                let (r, _) = $d::test_part1_with($input, $parse).expect("couldn't run test:");
+                assert_eq!(r, $e1);
+            }
+        });
+
+        ::concat_idents::concat_idents!(test_name = $d, _part2, $( _, $name)? {
+            #[test]
+            fn test_name() {
+                let (r, _) = $d::test_part2_with($input, $parse).expect("couldn't run test:");
+                assert_eq!(r, $e2);
+            }
+        });
+    };
     ($d:ident, $input:expr, $e1:expr, $e2:expr $(, $name:expr )? ) => {
         ::concat_idents::concat_idents!(test_name = $d, _part1, $( _, $name)? {
             #[test]
             fn test_name() {
-                let (r, _) = $d::test_part1($input).expect("couldn't run test:");
+                let (r, _) = $d::test_part1_silent($input).expect("couldn't run test:");
                 assert_eq!(r, $e1);
             }
         });
@@ -367,13 +1216,144 @@ macro_rules! test {
         ::concat_idents::concat_idents!(test_name = $d, _part2, $( _, $name)? {
             #[test]
             fn test_name() {
-                let (r, _) = $d::test_part2($input).expect("couldn't run test:");
+                let (r, _) = $d::test_part2_silent($input).expect("couldn't run test:");
                 assert_eq!(r, $e2);
             }
         });
     };
 }
 
+/// Same as [crate::test!], but the expected answers are strings compared with the `Display`
+/// form of the answers, eg: for huge numbers or ASCII-art answers.
+///
+/// An expected answer is a string, or `None` when the part has no answer.
+///
+/// Example
+/// -------
+/// ```
+/// use aoc::Solution;
+///# use aoc::solution::SolutionError;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = ();type P1 = u128; type P2 = String;
+///#
+///#     fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+///#         Ok(())
+///#         }
+///#
+///     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(u128::MAX) }
+///     fn part2(input: &Self::Input) -> Option<Self::P2> { Some("#.\n.#".to_owned()) }
+/// }
+///
+/// #[cfg(test)]
+/// mod tests {
+///   use crate::*;
+///   use crate::{DayXX as day_xx};
+///
+///   aoc::test_str! {
+///      day_xx:
+///      [case_1]
+///         - "Some Input" => "340282366920938463463374607431768211455" => "#.\n.#";
+///     }
+///
+///   //alternate syntax
+///   aoc::test_str!(day_xx, "Another Input", "340282366920938463463374607431768211455", "#.\n.#", "suffix");
+/// }
+///
+/// ```
+#[macro_export]
+macro_rules! test_str {
+    (
+        $d:ident:
+        $(
+            $( [$name:ident] )?
+            - $input: expr => $part1:expr => $part2: expr $(;)?
+        )+
+     ) => {
+       $(
+         $crate::test_str!($d, $input, $part1, $part2 $(, $name )?);
+       )+
+    };
+    ($d:ident, $input:expr, $e1:expr, $e2:expr $(, $name:expr )? ) => {
+        ::concat_idents::concat_idents!(test_name = $d, _part1_str, $( _, $name)? {
+            #[test]
+            fn test_name() {
+                let (r, _) = $d::test_part1_silent($input).expect("couldn't run test:");
+                let expected: Option<&str> = $e1.into();
+                assert_eq!(r.map(|r| format!("{}", r)).as_deref(), expected);
+            }
+        });
+
+        ::concat_idents::concat_idents!(test_name = $d, _part2_str, $( _, $name)? {
+            #[test]
+            fn test_name() {
+                let (r, _) = $d::test_part2_silent($input).expect("couldn't run test:");
+                let expected: Option<&str> = $e2.into();
+                assert_eq!(r.map(|r| format!("{}", r)).as_deref(), expected);
+            }
+        });
+    };
+}
+
+/// Generates a test failing when a part is slower than a time budget on the real input.
+///
+/// The part is run with [crate::Solution::test_part1_silent] (or `test_part2_silent`) on
+/// [crate::Solution::get_input], and the measured duration, parsing included, is compared to
+/// the budget. The budget uses humantime's syntax: `50ms`, `1s 500ms`, `"2s"`...
+///
+/// Timings depend on the machine and on what runs alongside the tests: keep some margin, and
+/// run the budget tests in release mode (`cargo test --release`).
+///
+/// # Example
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///   use crate::*;
+///
+///   aoc::test_budget!(DayXX, part1, 50ms);
+///   aoc::test_budget!(DayXX, part2, 1s 500ms);
+/// }
+/// ```
+#[macro_export]
+macro_rules! test_budget {
+    ($d:ident, part1, $($budget:tt)+) => {
+        ::concat_idents::concat_idents!(test_name = $d, _part1_budget {
+            #[test]
+            #[allow(non_snake_case)]
+            fn test_name() {
+                let input = $d::get_input().expect("An input is required");
+                let (_, time) = $d::test_part1_silent(&input).expect("couldn't run test:");
+                $crate::test_budget!(@check $d, 1, time, $($budget)+);
+            }
+        });
+    };
+    ($d:ident, part2, $($budget:tt)+) => {
+        ::concat_idents::concat_idents!(test_name = $d, _part2_budget {
+            #[test]
+            #[allow(non_snake_case)]
+            fn test_name() {
+                let input = $d::get_input().expect("An input is required");
+                let (_, time) = $d::test_part2_silent(&input).expect("couldn't run test:");
+                $crate::test_budget!(@check $d, 2, time, $($budget)+);
+            }
+        });
+    };
+    (@check $d:ident, $part:literal, $time:expr, $($budget:tt)+) => {
+        let budget = $crate::solution::parse_budget(stringify!($($budget)+));
+        assert!(
+            $time <= budget,
+            "Part {} of day {} took {:?}, over its {:?} budget",
+            $part,
+            $d::DAY,
+            $time,
+            budget,
+        );
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::solution::SolutionError;
@@ -420,6 +1400,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn labeled_time_macro() {
+        use crate::clock::MockClock;
+
+        let (result, time) = time!(clock: MockClock, "labeled", {
+            MockClock::advance(Duration::from_millis(10));
+            42
+        });
+
+        assert_eq!(result, 42);
+        assert_eq!(time, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn time_macro_with_clock() {
+        use crate::clock::MockClock;
+
+        let (result, time) = time!(clock: MockClock, {
+            MockClock::advance(Duration::from_millis(10));
+            42
+        });
+        assert_eq!(result, 42);
+        assert_eq!(time, Duration::from_millis(10));
+
+        let (_, time) =
+            time!(clock: MockClock, "labeled", MockClock::advance(Duration::from_nanos(7)));
+        assert_eq!(time, Duration::from_nanos(7));
+    }
+
     use Demo as test_macro;
 
     test! {
@@ -436,4 +1445,179 @@ mod tests {
     }
 
     test!(test_macro, "Some Input", None, Some(123), "with_suffix");
+
+    struct Strings;
+    impl Solution for Strings {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = u32;
+        type P1 = u128;
+        type P2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(u128::MAX / u128::from(*input))
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            (*input > 1).then(|| "#..#\n.##.".repeat(*input as usize - 1))
+        }
+    }
+
+    use Strings as test_str_macro;
+
+    test_str! {
+        test_str_macro:
+        [huge]
+        - "1" => "340282366920938463463374607431768211455" => None;
+        [ascii_art]
+        - "2" => "170141183460469231731687303715884105727" => "#..#\n.##.";
+    }
+
+    test_str!(
+        test_str_macro,
+        "5",
+        "68056473384187692692674921486353642291",
+        concat!("#..#\n.##.", "#..#\n.##.", "#..#\n.##.", "#..#\n.##."),
+        "expr"
+    );
+
+    struct Scaled;
+    impl Solution for Scaled {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = (usize, Vec<u8>);
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, SolutionError> {
+            Ok((100, input.bytes().collect()))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input.0 * input.1.len())
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(input.0 + input.1.len())
+        }
+    }
+
+    use Scaled as with_parse;
+
+    test!(with_parse, "abc", Some(300), Some(103), "default_parse");
+    test!(
+        with_parse,
+        with_parse = |input: &str| Ok((10, input.bytes().collect())),
+        "abc",
+        Some(30),
+        Some(13),
+        "custom_parse"
+    );
+
+    mod test_common_generated {
+        use crate::solution::{Result, SolutionError};
+        use crate::Solution;
+
+        struct Numbers;
+        impl Solution for Numbers {
+            const TITLE: &'static str = "";
+            const DAY: u8 = 1;
+            type Input = Vec<u32>;
+            type P1 = u32;
+            type P2 = u32;
+
+            fn get_input() -> Result<String> {
+                Ok("1\n2".to_owned())
+            }
+
+            fn parse(input: &str) -> Result<Self::Input> {
+                input
+                    .lines()
+                    .map(|line| {
+                        line.parse()
+                            .map_err(|_| SolutionError::parse("not a number"))
+                    })
+                    .collect()
+            }
+
+            fn part1(input: &Self::Input) -> Option<Self::P1> {
+                input.iter().copied().max()
+            }
+
+            fn part2(input: &Self::Input) -> Option<Self::P2> {
+                Some(input.iter().sum())
+            }
+        }
+
+        crate::test_common!(Numbers);
+    }
+
+    mod test_common_skip_parse {
+        use crate::solution::{Result, SolutionError};
+        use crate::Solution;
+
+        struct Unparsable;
+        impl Solution for Unparsable {
+            const TITLE: &'static str = "";
+            const DAY: u8 = 2;
+            type Input = ();
+            type P1 = ();
+            type P2 = ();
+
+            fn get_input() -> Result<String> {
+                Ok("not parsed by the tests".to_owned())
+            }
+
+            fn parse(_input: &str) -> Result<Self::Input> {
+                Err(SolutionError::parse("not parsed by the tests"))
+            }
+
+            fn part1(_input: &Self::Input) -> Option<Self::P1> {
+                None
+            }
+
+            fn part2(_input: &Self::Input) -> Option<Self::P2> {
+                None
+            }
+        }
+
+        crate::test_common!(Unparsable, skip_parse);
+    }
+
+    mod test_budget_generated {
+        use crate::solution::Result;
+        use crate::Solution;
+
+        struct Fast;
+        impl Solution for Fast {
+            const TITLE: &'static str = "";
+            const DAY: u8 = 3;
+            type Input = usize;
+            type P1 = usize;
+            type P2 = usize;
+
+            fn get_input() -> Result<String> {
+                Ok("abc".to_owned())
+            }
+
+            fn parse(input: &str) -> Result<Self::Input> {
+                Ok(input.len())
+            }
+
+            fn part1(input: &Self::Input) -> Option<Self::P1> {
+                Some(*input)
+            }
+
+            fn part2(input: &Self::Input) -> Option<Self::P2> {
+                Some(input * 2)
+            }
+        }
+
+        crate::test_budget!(Fast, part1, 10s);
+        crate::test_budget!(Fast, part2, "1min 30s");
+    }
 }
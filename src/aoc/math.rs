@@ -0,0 +1,75 @@
+//! Overflow-checked arithmetic for the answers
+//!
+//! A product of a few large values overflows `usize` easily, and a release build silently
+//! wraps around instead of panicking. These helpers return `None` instead of a wrong answer.
+
+/// Product of the values, `None` when it overflows `u64` or a value doesn't fit in it.
+///
+/// The product of no values is 1.
+///
+/// # Example
+/// ```
+/// use aoc::math::checked_product;
+///
+/// assert_eq!(checked_product([1721usize, 299]), Some(514579));
+/// assert_eq!(checked_product([usize::MAX, 2]), None);
+/// assert_eq!(checked_product([-1i64, 2]), None);
+/// ```
+pub fn checked_product<I>(values: I) -> Option<u64>
+where
+    I: IntoIterator,
+    I::Item: TryInto<u64>,
+{
+    values.into_iter().try_fold(1u64, |product, value| {
+        product.checked_mul(value.try_into().ok()?)
+    })
+}
+
+/// Sum of the values, `None` when it overflows `u64` or a value doesn't fit in it.
+///
+/// # Example
+/// ```
+/// use aoc::math::checked_sum;
+///
+/// assert_eq!(checked_sum([1000u32, 2000, 3000]), Some(6000));
+/// assert_eq!(checked_sum([u64::MAX, 1]), None);
+/// ```
+pub fn checked_sum<I>(values: I) -> Option<u64>
+where
+    I: IntoIterator,
+    I::Item: TryInto<u64>,
+{
+    values
+        .into_iter()
+        .try_fold(0u64, |sum, value| sum.checked_add(value.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn products() {
+        assert_eq!(checked_product(Vec::<u64>::new()), Some(1));
+        assert_eq!(checked_product([0usize, usize::MAX]), Some(0));
+        assert_eq!(checked_product([usize::MAX]), Some(usize::MAX as u64));
+        assert_eq!(
+            checked_product([usize::MAX / 2, 2]),
+            Some(usize::MAX as u64 - 1)
+        );
+        assert_eq!(checked_product([usize::MAX / 2 + 1, 2]), None);
+        assert_eq!(checked_product([u32::MAX as u64 + 1; 2]), None);
+        assert_eq!(checked_product([3i32, 4, 5]), Some(60));
+        assert_eq!(checked_product([3i32, -4]), None, "negative values");
+    }
+
+    #[test]
+    fn sums() {
+        assert_eq!(checked_sum(Vec::<u64>::new()), Some(0));
+        assert_eq!(checked_sum([usize::MAX - 1, 1]), Some(usize::MAX as u64));
+        assert_eq!(checked_sum([usize::MAX, 1]), None);
+        assert_eq!(checked_sum([u64::MAX / 2; 3]), None);
+        assert_eq!(checked_sum([1i64, -1]), None, "negative values");
+        assert_eq!(checked_sum((1u8..=100).map(u64::from)), Some(5050));
+    }
+}
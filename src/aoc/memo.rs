@@ -0,0 +1,125 @@
+//! Memoization helper for recursive solutions
+//!
+//! Many puzzles boil down to a recursion which explodes without a cache
+//! (counting arrangements, paths...). [Memoizer] is a small single-threaded cache,
+//! meant to be created inside [crate::Solution::part1] or [crate::Solution::part2].
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Cache of the values computed for each key. See [Memoizer::get_or_compute]
+#[derive(Debug, Clone)]
+pub struct Memoizer<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memoizer<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memoizer<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value of `key`, or computes it with `f` and caches it.
+    ///
+    /// `f` gets the memoizer back, to recurse on other keys.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::memo::Memoizer;
+    ///
+    /// fn arrangements(memo: &mut Memoizer<u64, u64>, n: u64) -> u64 {
+    ///     memo.get_or_compute(n, |memo| match n {
+    ///         0 | 1 => 1,
+    ///         n => arrangements(memo, n - 1) + arrangements(memo, n - 2),
+    ///     })
+    /// }
+    ///
+    /// assert_eq!(arrangements(&mut Memoizer::new(), 90), 4660046610375530309);
+    /// ```
+    pub fn get_or_compute<F>(&mut self, key: K, f: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    /// Cached value of `key`, if computed.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    /// Number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets every cached value.
+    pub fn clear(&mut self) {
+        self.cache.clear()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, calls: &mut usize) -> u64 {
+        *calls += 1;
+
+        match n {
+            0 | 1 => n,
+            n => fibonacci(n - 1, calls) + fibonacci(n - 2, calls),
+        }
+    }
+
+    fn memoized_fibonacci(memo: &mut Memoizer<u64, u64>, n: u64, calls: &mut usize) -> u64 {
+        memo.get_or_compute(n, |memo| {
+            *calls += 1;
+
+            match n {
+                0 | 1 => n,
+                n => {
+                    memoized_fibonacci(memo, n - 1, calls) + memoized_fibonacci(memo, n - 2, calls)
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn fibonacci_speedup() {
+        let (mut plain_calls, mut memo_calls) = (0, 0);
+        let mut memo = Memoizer::new();
+
+        let (plain, plain_time) = crate::time!(fibonacci(25, &mut plain_calls));
+        let (memoized, memo_time) =
+            crate::time!(memoized_fibonacci(&mut memo, 25, &mut memo_calls));
+
+        assert_eq!(plain, 75025);
+        assert_eq!(memoized, plain);
+        assert_eq!(plain_calls, 242785);
+        assert_eq!(memo_calls, 26);
+        assert_eq!(memo.len(), 26);
+        assert!(memo_time < plain_time);
+
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
@@ -1,4 +1,76 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "std")]
+pub mod answer;
+#[cfg(feature = "std")]
+pub mod answers;
+#[cfg(feature = "keyring")]
+pub mod auth;
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "std")]
+pub mod capture;
+#[cfg(feature = "fetch")]
+pub mod client;
+#[cfg(feature = "std")]
+pub mod clock;
+#[cfg(feature = "std")]
+pub mod debug;
+#[cfg(feature = "fetch")]
+mod dotenv;
+#[cfg(feature = "embed-inputs")]
+pub mod embed;
+#[cfg(feature = "encrypt")]
+mod encrypt;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "std")]
+pub mod filename;
+#[cfg(feature = "std")]
+pub mod fuzz;
+#[cfg(feature = "std")]
+pub mod geom;
+#[cfg(feature = "std")]
+pub mod grid;
+#[cfg(feature = "fetch")]
+pub mod guesses;
+#[cfg(feature = "std")]
+pub mod input;
+#[cfg(feature = "fetch")]
+pub mod leaderboard;
+#[cfg(feature = "std")]
+pub mod logging;
+#[cfg(feature = "std")]
 mod r#macro;
+#[cfg(feature = "std")]
+pub mod math;
+#[cfg(feature = "std")]
+pub mod memo;
+#[cfg(feature = "std")]
+pub mod output;
+#[cfg(feature = "std")]
+pub mod parse;
+#[cfg(feature = "std")]
+mod phase;
+#[cfg(feature = "std")]
+pub mod report;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+mod sha256;
+#[cfg(feature = "std")]
 pub mod solution;
+pub mod solution_core;
+#[cfg(all(test, feature = "std"))]
+mod test_util;
+#[cfg(feature = "std")]
+pub mod timeline;
 
+#[cfg(feature = "std")]
+pub use answer::Answer;
+#[cfg(feature = "log")]
+pub use logging::init_default_logger;
+#[cfg(feature = "std")]
+pub use parse::{parse_default, Blocks, Chars, Lines, OptionExt, ResultExt};
+#[cfg(feature = "std")]
 pub use solution::Solution;
@@ -0,0 +1,975 @@
+//! Output formats for a [crate::solution::SolutionResult]
+//!
+//! Results are rendered through a [ResultView], a type-erased copy of the result where both
+//! answers are already converted to strings. This lets the formatting live in the library,
+//! independently of each day's `P1`/`P2` types.
+//!
+//! The format is picked at runtime by [emit] using the `AOC_FORMAT` environment variable:
+//! - `pretty` (default) - the multi-line human readable output
+//! - `json` - a single JSON object
+//! - `csv` - a single `day,title,part1,part2,parse_ns,part1_ns,part2_ns` row
+//! - `markdown` - a small table
+//! - `calendar` - the cell of the day in the calendar of [crate::report::calendar], and the
+//!   whole calendar once all the days of [crate::runner::run_all] are solved
+//! - `quiet` - nothing at all
+//!
+//! In GitHub Actions, [crate::runner::run_all] also appends a table of the results to the job
+//! summary, with a row per failed day too. (See [append_github_summary])
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::Path;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+use humantime::format_duration;
+use thiserror::Error;
+
+use crate::answers::Verification;
+use crate::logging;
+use crate::report::calendar::{self, CalendarOptions};
+use crate::solution::SolutionError;
+
+/// Environment variable read by [emit] to select the [Format].
+pub const FORMAT_ENV: &str = "AOC_FORMAT";
+
+/// Environment variable set by GitHub Actions to the job summary file of the step.
+pub const GITHUB_STEP_SUMMARY_ENV: &str = "GITHUB_STEP_SUMMARY";
+
+/// Heading of the table of [ResultView::to_github_summary] rows.
+pub const GITHUB_SUMMARY_HEADER: &str =
+    "| Day | Title | Part 1 | Part 2 | Time |\n|---:|---|---|---|---:|";
+
+#[derive(Debug, Error)]
+pub enum OutputError {
+    #[error("Unknown output format {0:?} (expected one of: pretty, json, csv, markdown, calendar, quiet)")]
+    UnknownFormat(String),
+    #[error("Failed to write output")]
+    Io(#[from] std::io::Error),
+}
+
+/// Supported output formats. See the [module documentation](self) for details.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Pretty,
+    Json,
+    Csv,
+    Markdown,
+    Calendar,
+    Quiet,
+}
+
+impl FromStr for Format {
+    type Err = OutputError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "pretty" => Ok(Format::Pretty),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            "calendar" => Ok(Format::Calendar),
+            "quiet" => Ok(Format::Quiet),
+            _ => Err(OutputError::UnknownFormat(s.to_owned())),
+        }
+    }
+}
+
+impl Format {
+    /// Reads the format from `AOC_FORMAT`. Defaults to [Format::Pretty] when unset.
+    pub fn from_env() -> Result<Self, OutputError> {
+        match std::env::var(FORMAT_ENV) {
+            Ok(value) => value.parse(),
+            Err(_) => Ok(Format::Pretty),
+        }
+    }
+}
+
+/// Formatting of an answer in the results.
+///
+/// Implemented for every [Display] type, through [NamedAnswer]. Implement it for your own answer
+/// types to control how they are printed, without going through [Display] or [std::fmt::Debug].
+///
+/// Note that the coherence rules only allow it for the types of your crate: a `Vec<Point>` needs
+/// to be wrapped in a type of yours first.
+///
+/// # Example
+/// ```
+/// use std::fmt::Formatter;
+/// use aoc::output::AnswerDisplay;
+///
+/// #[derive(Debug)]
+/// enum Winner {
+///     Elf(usize),
+///     Tie,
+/// }
+///
+/// impl AnswerDisplay for Winner {
+///     fn fmt_answer(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Winner::Elf(elf) => write!(f, "elf #{}", elf),
+///             Winner::Tie => write!(f, "tie"),
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Winner::Elf(3).answer_string(), "elf #3");
+/// assert_eq!(Winner::Tie.answer_string(), "tie");
+/// assert_eq!(42.answer_string(), "42");
+/// ```
+pub trait AnswerDisplay {
+    /// Formats the answer, like [Display::fmt].
+    fn fmt_answer(&self, f: &mut Formatter<'_>) -> std::fmt::Result;
+
+    /// The formatted answer.
+    fn answer_string(&self) -> String {
+        struct Answer<'a, T: ?Sized>(&'a T);
+
+        impl<T: AnswerDisplay + ?Sized> Display for Answer<'_, T> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt_answer(f)
+            }
+        }
+
+        Answer(self).to_string()
+    }
+
+    /// The formatted answer preceded by its named values, when it has some. See [NamedAnswer]
+    fn named_string(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: NamedAnswer + ?Sized> AnswerDisplay for T {
+    fn fmt_answer(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_value(f)
+    }
+
+    fn named_string(&self) -> Option<String> {
+        let values = self.named_values();
+        if values.is_empty() {
+            return None;
+        }
+
+        let values: Vec<_> = values
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+
+        Some(format!("{} => {}", values.join(" "), self.answer_string()))
+    }
+}
+
+/// Answer displayed with the intermediate values it was computed from, for debugging:
+/// `gamma=22 epsilon=9 => 198`.
+///
+/// Only the answer itself is verified, submitted and written by the machine readable formats.
+/// Implemented for every [Display] type, without named values.
+///
+/// # Example
+/// ```
+/// use std::fmt::Formatter;
+/// use aoc::output::{AnswerDisplay, NamedAnswer};
+///
+/// #[derive(Debug)]
+/// struct PowerConsumption {
+///     gamma: u32,
+///     epsilon: u32,
+/// }
+///
+/// impl NamedAnswer for PowerConsumption {
+///     fn named_values(&self) -> Vec<(&str, String)> {
+///         vec![("gamma", self.gamma.to_string()), ("epsilon", self.epsilon.to_string())]
+///     }
+///
+///     fn fmt_value(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{}", self.gamma * self.epsilon)
+///     }
+/// }
+///
+/// let power = PowerConsumption { gamma: 22, epsilon: 9 };
+///
+/// assert_eq!(power.answer_string(), "198");
+/// assert_eq!(power.named_string().as_deref(), Some("gamma=22 epsilon=9 => 198"));
+/// assert_eq!(198.named_string(), None);
+/// ```
+pub trait NamedAnswer {
+    /// The intermediate values, by name.
+    fn named_values(&self) -> Vec<(&str, String)>;
+
+    /// Formats the answer itself, like [Display::fmt].
+    fn fmt_value(&self, f: &mut Formatter<'_>) -> std::fmt::Result;
+}
+
+impl<T: Display + ?Sized> NamedAnswer for T {
+    fn named_values(&self) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+
+    fn fmt_value(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Type-erased view of a [crate::solution::SolutionResult].
+///
+/// Obtained with [crate::solution::SolutionResult::view], or built with [ResultView::new].
+/// Fields may be added in a minor version.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ResultView {
+    pub title: &'static str,
+    pub day: u8,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub parse_duration: Duration,
+    pub part1_duration: Duration,
+    pub part2_duration: Duration,
+    /// Correctness of part 1 and part 2. See [crate::solution::SolutionResult::verification]
+    pub verified: (Verification, Verification),
+    /// Output captured by [crate::Solution::run_captured].
+    pub output: String,
+    /// Outcomes of the part 1 and part 2 submissions, by `Solution::run_and_submit_all`.
+    pub submitted: (Option<String>, Option<String>),
+    /// Answers preceded by their named values, when they have some. See [NamedAnswer]
+    pub named: (Option<String>, Option<String>),
+}
+
+/// Quoted and escaped JSON string.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn submitted(outcome: &Option<String>) -> String {
+    outcome
+        .as_ref()
+        .map(|outcome| format!(" (submitted: {})", outcome))
+        .unwrap_or_default()
+}
+
+fn mark(verification: &Verification) -> String {
+    match verification {
+        Verification::Unknown => String::new(),
+        Verification::Verified => " ✓".to_owned(),
+        Verification::Mismatch { expected: None } => " ✗".to_owned(),
+        Verification::Mismatch {
+            expected: Some(expected),
+        } => format!(" ✗ (expected {})", expected),
+    }
+}
+
+/// Options of the pretty output. See [ResultView::display_with]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Multi-line output under a `====` heading (the default). When `false`, the result is
+    /// printed on a single `Day 01 | part 1 | part 2 | total time` line.
+    pub heading: bool,
+    /// Durations below this floor are displayed as `<floor`, eg: `<1µs`, since they are under
+    /// the timer resolution. Defaults to [DEFAULT_TIME_FLOOR]. `Duration::ZERO` disables it.
+    pub time_floor: Duration,
+}
+
+/// Default [DisplayOptions::time_floor].
+pub const DEFAULT_TIME_FLOOR: Duration = Duration::from_micros(1);
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            heading: true,
+            time_floor: DEFAULT_TIME_FLOOR,
+        }
+    }
+}
+
+impl DisplayOptions {
+    /// Formats a measured duration, clamped to [DisplayOptions::time_floor].
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc::output::DisplayOptions;
+    ///
+    /// let options = DisplayOptions::default();
+    ///
+    /// assert_eq!(options.format_duration(Duration::from_nanos(300)), "<1µs");
+    /// assert_eq!(options.format_duration(Duration::from_micros(20)), "20us");
+    ///
+    /// let exact = DisplayOptions { time_floor: Duration::ZERO, ..options };
+    /// assert_eq!(exact.format_duration(Duration::from_nanos(300)), "300ns");
+    /// ```
+    pub fn format_duration(&self, duration: Duration) -> String {
+        if duration < self.time_floor {
+            let floor = format_duration(self.time_floor).to_string();
+            match floor.strip_suffix("us") {
+                Some(micros) => format!("<{}µs", micros),
+                None => format!("<{}", floor),
+            }
+        } else {
+            format_duration(duration).to_string()
+        }
+    }
+}
+
+/// A [ResultView] displayed with [DisplayOptions]. See [ResultView::display_with]
+pub struct DisplayWith<'a> {
+    view: &'a ResultView,
+    options: DisplayOptions,
+}
+
+impl Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.options.heading {
+            self.view.fmt_pretty(f, &self.options)
+        } else {
+            self.view.fmt_compact(f, &self.options)
+        }
+    }
+}
+
+impl Display for ResultView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.fmt_pretty(f, &DisplayOptions::default())
+    }
+}
+
+impl ResultView {
+    fn fmt_pretty(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> std::fmt::Result {
+        let heading = {
+            let title = match self.title {
+                "" => format!("Day {:02}", self.day),
+                title => format!("Day {:02}: {:?}", self.day, title),
+            };
+            let sep: String = (0..=(title.len() + 1)).map(|_| '=').collect();
+
+            format!("{}\n {}\n{}", sep, title, sep)
+        };
+
+        let (mark1, mark2) = (mark(&self.verified.0), mark(&self.verified.1));
+        let p1 = self.named.0.as_ref().or(self.part1.as_ref());
+        let p2 = self.named.1.as_ref().or(self.part2.as_ref());
+        let (mark1, mark2) = (
+            format!("{}{}", mark1, submitted(&self.submitted.0)),
+            format!("{}{}", mark2, submitted(&self.submitted.1)),
+        );
+
+        match (p1, p2) {
+            (Some(p1), Some(p2)) => {
+                write!(
+                    f,
+                   "{}\nPart 1: '{}'{}\nPart 2: '{}'{}\n----\nTime1:\t\t{}\nTime2:\t\t{}\nParse Time:\t{}\nTotal Time:\t{}",
+                   heading,
+                    p1,
+                    mark1,
+                    p2,
+                    mark2,
+                   options.format_duration(self.part1_duration),
+                   options.format_duration(self.part2_duration),
+                   options.format_duration(self.parse_duration),
+                   options.format_duration(self.part1_duration + self.part2_duration + self.parse_duration),
+                )
+            }
+            (Some(p1), _) => {
+                write!(
+                    f,
+                    "{}\nPart 1: '{}'{}\n----\nTime1:\t\t{}\nParse Time:\t{}\nTotal Time:\t{}",
+                    heading,
+                    p1,
+                    mark1,
+                    options.format_duration(self.part1_duration),
+                    options.format_duration(self.parse_duration),
+                    options.format_duration(self.part1_duration + self.parse_duration),
+                )
+            }
+            (None, Some(p2)) => {
+                write!(
+                    f,
+                    "{}\nPart 2: '{}'{}\n----\nTime2:\t\t{}\nParse Time:\t{}\nTotal Time:\t{}",
+                    heading,
+                    p2,
+                    mark2,
+                    options.format_duration(self.part2_duration),
+                    options.format_duration(self.parse_duration),
+                    options.format_duration(self.part2_duration + self.parse_duration),
+                )
+            }
+            _ => {
+                write!(
+                    f,
+                    "{}\n  {}\tParsing time",
+                    heading,
+                    options.format_duration(self.parse_duration),
+                )
+            }
+        }
+    }
+
+    /// Displays the result with the given options, the [Display] implementation using the
+    /// defaults.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc::output::{DisplayOptions, ResultView};
+    ///
+    /// let mut view = ResultView::new("Report Repair", 1);
+    /// view.part1 = Some("514579".to_owned());
+    /// view.part2 = Some("241861950".to_owned());
+    /// view.parse_duration = Duration::from_micros(5);
+    /// view.part1_duration = Duration::from_micros(20);
+    /// view.part2_duration = Duration::from_micros(100);
+    /// let compact = DisplayOptions { heading: false, ..Default::default() };
+    ///
+    /// assert_eq!(view.display_with(compact).to_string(), "Day 01 | 514579 | 241861950 | 125us");
+    /// ```
+    pub fn display_with(&self, options: DisplayOptions) -> DisplayWith<'_> {
+        DisplayWith {
+            view: self,
+            options,
+        }
+    }
+
+    fn fmt_compact(&self, f: &mut Formatter<'_>, options: &DisplayOptions) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {:02} | {} | {} | {}",
+            self.day,
+            self.part1.as_deref().unwrap_or("-"),
+            self.part2.as_deref().unwrap_or("-"),
+            options.format_duration(self.total_duration()),
+        )
+    }
+
+    pub(crate) fn total_duration(&self) -> Duration {
+        self.parse_duration + self.part1_duration + self.part2_duration
+    }
+
+    fn json(&self) -> String {
+        fn answer(a: &Option<String>) -> String {
+            a.as_deref()
+                .map(json_string)
+                .unwrap_or_else(|| "null".to_owned())
+        }
+
+        format!(
+            "{{\"day\":{},\"title\":{},\"part1\":{},\"part2\":{},\"parse_ns\":{},\"part1_ns\":{},\"part2_ns\":{}}}",
+            self.day,
+            json_string(self.title),
+            answer(&self.part1),
+            answer(&self.part2),
+            self.parse_duration.as_nanos(),
+            self.part1_duration.as_nanos(),
+            self.part2_duration.as_nanos(),
+        )
+    }
+
+    fn csv(&self) -> String {
+        fn field(s: &str) -> String {
+            if s.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", s.replace('"', "\"\""))
+            } else {
+                s.to_owned()
+            }
+        }
+
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.day,
+            field(self.title),
+            self.part1.as_deref().map(field).unwrap_or_default(),
+            self.part2.as_deref().map(field).unwrap_or_default(),
+            self.parse_duration.as_nanos(),
+            self.part1_duration.as_nanos(),
+            self.part2_duration.as_nanos(),
+        )
+    }
+
+    fn markdown(&self) -> String {
+        fn cell(a: &Option<String>) -> String {
+            a.as_deref()
+                .map(|s| format!("`{}`", s.replace('|', "\\|").replace('\n', " ")))
+                .unwrap_or_else(|| "-".to_owned())
+        }
+
+        format!(
+            "| Day | Title | Part 1 | Part 2 | Total Time |\n|---|---|---|---|---|\n| {:02} | {} | {} | {} | {} |",
+            self.day,
+            self.title.replace('|', "\\|"),
+            cell(&self.part1),
+            cell(&self.part2),
+            format_duration(self.total_duration()),
+        )
+    }
+}
+
+impl ResultView {
+    /// View of `day` without answers nor durations, whose fields are then set one by one.
+    pub fn new(title: &'static str, day: u8) -> Self {
+        ResultView {
+            title,
+            day,
+            ..Default::default()
+        }
+    }
+
+    /// Row of the GitHub job summary table (see [GITHUB_SUMMARY_HEADER]), with the
+    /// verification marks of the answers.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc::answers::Verification;
+    /// use aoc::output::ResultView;
+    ///
+    /// let mut view = ResultView::new("No Space Left On Device", 7);
+    /// view.part1 = Some("1792".to_owned());
+    /// view.parse_duration = Duration::from_micros(50);
+    /// view.part1_duration = Duration::from_millis(2);
+    /// view.verified = (Verification::Verified, Verification::Unknown);
+    ///
+    /// assert_eq!(
+    ///     view.to_github_summary(),
+    ///     "| 7 | No Space Left On Device | `1792` ✓ | - | 2ms 50us |"
+    /// );
+    /// ```
+    pub fn to_github_summary(&self) -> String {
+        fn cell(answer: &Option<String>, verification: &Verification) -> String {
+            match answer {
+                Some(answer) => format!("`{}`{}", table_text(answer), mark(verification)),
+                None => "-".to_owned(),
+            }
+        }
+
+        format!(
+            "| {} | {} | {} | {} | {} |",
+            self.day,
+            table_text(self.title),
+            cell(&self.part1, &self.verified.0),
+            cell(&self.part2, &self.verified.1),
+            format_duration(self.total_duration()),
+        )
+    }
+}
+
+/// Row of the GitHub job summary table (see [GITHUB_SUMMARY_HEADER]) for a day that failed
+/// with `error`.
+///
+/// # Example
+/// ```
+/// use aoc::solution::SolutionError;
+///
+/// let error = SolutionError::parse("oops");
+///
+/// assert_eq!(
+///     aoc::output::github_summary_error(7, "No Space Left On Device", &error),
+///     "| 7 | No Space Left On Device | ✗ Invalid Puzzle input: oops | - | - |"
+/// );
+/// ```
+pub fn github_summary_error(day: u8, title: &str, error: &SolutionError) -> String {
+    format!(
+        "| {} | {} | ✗ {} | - | - |",
+        day,
+        table_text(title),
+        table_text(&error.to_string())
+    )
+}
+
+/// `text` on a single line, with its `|` escaped, for a cell of a markdown table.
+fn table_text(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Writes the table of `rows` for a GitHub job summary: [GITHUB_SUMMARY_HEADER] then the rows,
+/// made with [ResultView::to_github_summary] or [github_summary_error].
+pub fn write_github_summary<W: Write>(
+    out: &mut W,
+    rows: impl IntoIterator<Item = String>,
+) -> std::io::Result<()> {
+    writeln!(out, "{}", GITHUB_SUMMARY_HEADER)?;
+    for row in rows {
+        writeln!(out, "{}", row)?;
+    }
+
+    writeln!(out)
+}
+
+/// Appends the table of `rows` to the file of `$GITHUB_STEP_SUMMARY`, which GitHub Actions
+/// renders in the summary of the job. (See [write_github_summary])
+///
+/// Returns whether the table was written: nothing is done outside of GitHub Actions, nor in
+/// the test binaries of `cargo test`, so that running the tests of the days in CI leaves the
+/// summary alone.
+pub fn append_github_summary(rows: impl IntoIterator<Item = String>) -> Result<bool, OutputError> {
+    match std::env::var_os(GITHUB_STEP_SUMMARY_ENV) {
+        Some(path) if !in_test_binary() => append_github_summary_to(Path::new(&path), rows),
+        _ => Ok(false),
+    }
+}
+
+fn append_github_summary_to(
+    path: &Path,
+    rows: impl IntoIterator<Item = String>,
+) -> Result<bool, OutputError> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    write_github_summary(&mut file, rows)?;
+
+    Ok(true)
+}
+
+/// Whether the process is a test binary of `cargo test`, which lives in `target/<profile>/deps`.
+fn in_test_binary() -> bool {
+    std::env::current_exe().is_ok_and(|exe| {
+        exe.parent()
+            .and_then(Path::file_name)
+            .is_some_and(|dir| dir == "deps")
+    })
+}
+
+/// Writes `view` to `out` using the given [Format].
+pub fn write<W: Write>(out: &mut W, view: &ResultView, format: Format) -> std::io::Result<()> {
+    match format {
+        Format::Pretty => writeln!(out, "{}", view),
+        Format::Json => writeln!(out, "{}", view.json()),
+        Format::Csv => writeln!(out, "{}", view.csv()),
+        Format::Markdown => writeln!(out, "{}", view.markdown()),
+        Format::Calendar => write!(
+            out,
+            "{}",
+            calendar::render_day(view, &CalendarOptions::from_env())
+        ),
+        Format::Quiet => Ok(()),
+    }
+}
+
+/// Prints `view` on stdout using the [Format] selected by `AOC_FORMAT`.
+///
+/// Nothing is printed when the variable holds an unknown format. The error is returned instead
+/// so the caller can decide how to fall back. (See [crate::solution!])
+pub fn emit(view: &ResultView) -> Result<(), OutputError> {
+    let format = Format::from_env()?;
+
+    write(&mut std::io::stdout().lock(), view, format)?;
+
+    Ok(())
+}
+
+/// The exit code of a process whose day failed.
+const FAILURE: u8 = 1;
+
+/// Reports `err` on stderr with its causes and hint, and returns the exit code of a process
+/// whose day failed: `1`. (See [crate::error::report])
+///
+/// `aoc::solution!(Day, exit_code)` does the same, with the day in the report. Use this one
+/// when running a day by hand in a `main` returning an [ExitCode].
+///
+/// # Example
+/// ```no_run
+/// use std::process::ExitCode;
+///
+/// use aoc::Solution;
+///# use aoc::solution::Result;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     //-- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type Input = ();type P1 = usize; type P2 = usize;
+///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(123) }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(456) }
+/// }
+///
+/// fn main() -> ExitCode {
+///     match DayXX::run() {
+///         Ok(result) => {
+///             println!("{}", result);
+///             ExitCode::SUCCESS
+///         }
+///         Err(e) => aoc::output::fail(&e),
+///     }
+/// }
+/// ```
+pub fn fail(err: &SolutionError) -> ExitCode {
+    logging::report(format_args!("{}", crate::error::report(err)));
+
+    ExitCode::from(FAILURE)
+}
+
+/// [fail] with the day in the report, as printed by [crate::solution!]. Used by the macros.
+#[doc(hidden)]
+pub fn fail_day(day: u8, year: Option<u16>, title: &str, err: &SolutionError) -> ExitCode {
+    crate::error::print_day_report(day, year, title, err);
+
+    ExitCode::from(FAILURE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view() -> ResultView {
+        ResultView {
+            title: "Some, \"title\"",
+            day: 7,
+            part1: Some("42".to_owned()),
+            part2: None,
+            parse_duration: Duration::from_nanos(1),
+            part1_duration: Duration::from_nanos(20),
+            part2_duration: Duration::from_nanos(300),
+            verified: Default::default(),
+            output: String::new(),
+            submitted: (None, None),
+            named: (None, None),
+        }
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        write(&mut out, &view(), format).expect("write to Vec");
+
+        String::from_utf8(out).expect("utf8 output")
+    }
+
+    #[test]
+    fn format_from_str() {
+        assert_eq!("pretty".parse::<Format>().unwrap(), Format::Pretty);
+        assert_eq!("".parse::<Format>().unwrap(), Format::Pretty);
+        assert_eq!("JSON".parse::<Format>().unwrap(), Format::Json);
+        assert_eq!("csv".parse::<Format>().unwrap(), Format::Csv);
+        assert_eq!("markdown".parse::<Format>().unwrap(), Format::Markdown);
+        assert_eq!("calendar".parse::<Format>().unwrap(), Format::Calendar);
+        assert_eq!("quiet".parse::<Format>().unwrap(), Format::Quiet);
+        assert!(matches!(
+            "yaml".parse::<Format>(),
+            Err(OutputError::UnknownFormat(f)) if f == "yaml"
+        ));
+    }
+
+    #[test]
+    fn pretty_without_title() {
+        let view = ResultView {
+            title: "",
+            ..view()
+        };
+
+        assert!(view.to_string().contains("\n Day 07\n"), "{}", view);
+    }
+
+    #[test]
+    fn pretty() {
+        let out = render(Format::Pretty);
+
+        assert!(out.starts_with("====="));
+        assert!(out.contains("Day 07: \"Some, \\\"title\\\"\""));
+        assert!(out.contains("Part 1: '42'"));
+        assert!(!out.contains("Part 2"));
+    }
+
+    #[test]
+    fn pretty_named() {
+        let view = ResultView {
+            part1: Some("198".to_owned()),
+            named: (Some("gamma=22 epsilon=9 => 198".to_owned()), None),
+            ..view()
+        };
+
+        assert!(view
+            .to_string()
+            .contains("Part 1: 'gamma=22 epsilon=9 => 198'\n"));
+        let mut json = Vec::new();
+        write(&mut json, &view, Format::Json).expect("write to Vec");
+        assert!(String::from_utf8(json)
+            .unwrap()
+            .contains("\"part1\":\"198\""));
+    }
+
+    #[test]
+    fn pretty_part2_only() {
+        let view = ResultView {
+            part1: None,
+            part2: Some("24".to_owned()),
+            verified: (Verification::Unknown, Verification::Verified),
+            ..view()
+        };
+        let out = view.to_string();
+
+        assert!(out.contains("Part 2: '24' ✓\n"), "{}", out);
+        assert!(out.contains("Time2:\t\t<1µs"), "{}", out);
+        assert!(out.contains("Total Time:\t<1µs"), "{}", out);
+        assert!(!out.contains("Part 1") && !out.contains("Time1"), "{}", out);
+    }
+
+    #[test]
+    fn pretty_verified() {
+        let mut view = view();
+        view.part2 = Some("24".to_owned());
+
+        let out = view.to_string();
+        assert!(!out.contains('✓') && !out.contains('✗'));
+
+        view.verified = (
+            Verification::Verified,
+            Verification::Mismatch { expected: None },
+        );
+        let out = view.to_string();
+        assert!(out.contains("Part 1: '42' ✓\n"));
+        assert!(out.contains("Part 2: '24' ✗\n"));
+
+        view.verified.1 = Verification::Mismatch {
+            expected: Some("25".to_owned()),
+        };
+        assert!(view.to_string().contains("Part 2: '24' ✗ (expected 25)\n"));
+    }
+
+    #[test]
+    fn compact() {
+        let options = DisplayOptions {
+            heading: false,
+            time_floor: Duration::ZERO,
+        };
+
+        assert_eq!(
+            view().display_with(options).to_string(),
+            "Day 07 | 42 | - | 321ns"
+        );
+        assert_eq!(
+            view()
+                .display_with(DisplayOptions {
+                    heading: false,
+                    ..Default::default()
+                })
+                .to_string(),
+            "Day 07 | 42 | - | <1µs"
+        );
+        assert_eq!(
+            view().display_with(DisplayOptions::default()).to_string(),
+            view().to_string()
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json),
+            "{\"day\":7,\"title\":\"Some, \\\"title\\\"\",\"part1\":\"42\",\"part2\":null,\"parse_ns\":1,\"part1_ns\":20,\"part2_ns\":300}\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "7,\"Some, \"\"title\"\"\",42,,1,20,300\n"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            render(Format::Markdown),
+            "| Day | Title | Part 1 | Part 2 | Total Time |\n|---|---|---|---|---|\n| 07 | Some, \"title\" | `42` | - | 321ns |\n"
+        );
+    }
+
+    #[test]
+    fn github_summary() {
+        let verified = ResultView {
+            title: "a|b",
+            part2: Some("x\ny".to_owned()),
+            verified: (
+                Verification::Verified,
+                Verification::Mismatch {
+                    expected: Some("z".to_owned()),
+                },
+            ),
+            ..view()
+        };
+
+        let error = github_summary_error(8, "Broken", &SolutionError::parse("line 1\nline 2"));
+
+        let mut out = Vec::new();
+        write_github_summary(
+            &mut out,
+            [
+                view().to_github_summary(),
+                verified.to_github_summary(),
+                error,
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            [
+                "| Day | Title | Part 1 | Part 2 | Time |",
+                "|---:|---|---|---|---:|",
+                "| 7 | Some, \"title\" | `42` | - | 321ns |",
+                "| 7 | a\\|b | `42` ✓ | `x y` ✗ (expected z) | 321ns |",
+                "| 8 | Broken | ✗ Invalid Puzzle input: line 1 line 2 | - | - |",
+                "",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn append_to_github_summary() {
+        let _lock = crate::test_util::env_lock();
+        let path = crate::test_util::temp_dir("github-summary").join("summary.md");
+        std::fs::write(&path, "# Advent of Code\n").unwrap();
+
+        std::env::remove_var(GITHUB_STEP_SUMMARY_ENV);
+        assert!(!append_github_summary([view().to_github_summary()]).unwrap());
+
+        std::env::set_var(GITHUB_STEP_SUMMARY_ENV, &path);
+        let appended = append_github_summary([view().to_github_summary()]);
+        std::env::remove_var(GITHUB_STEP_SUMMARY_ENV);
+        assert!(!appended.unwrap(), "not from cargo test");
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# Advent of Code\n"
+        );
+
+        assert!(append_github_summary_to(&path, [view().to_github_summary()]).unwrap());
+        let summary = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            summary.starts_with("# Advent of Code\n| Day |"),
+            "{}",
+            summary
+        );
+        assert!(summary.contains("| 7 | Some"), "{}", summary);
+    }
+
+    #[test]
+    fn calendar_of_a_day() {
+        let rendered = render(Format::Calendar);
+
+        assert!(rendered.starts_with("┌───────┐\n"), "{}", rendered);
+        assert!(rendered.contains(" 7 ★"), "{}", rendered);
+        assert!(!rendered.contains(" 8 ·"), "{}", rendered);
+    }
+
+    #[test]
+    fn quiet() {
+        assert_eq!(render(Format::Quiet), "");
+    }
+}
@@ -0,0 +1,1076 @@
+//! Helpers for the most common [crate::Solution::parse] patterns
+//!
+//! See also [crate::solution::parse_fromstr].
+
+use std::any::type_name;
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+
+use crate::solution::{Result, SolutionError};
+
+/// Parses every non-blank line of the input with [FromStr], eg: a list of numbers.
+///
+/// The first line that doesn't parse is reported as [SolutionError::InvalidLine], with its
+/// 1-based line number and its text. See [lines_lossy] to skip them instead.
+///
+/// # Example
+/// ```
+/// use aoc::parse::lines;
+/// use aoc::solution::SolutionError;
+///
+/// assert_eq!(lines::<u32>("1721\r\n979\n\n366\n").unwrap(), vec![1721, 979, 366]);
+///
+/// let error = lines::<u32>("1721\nNaN").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid Puzzle input at line 2: \"NaN\"");
+/// ```
+pub fn lines<T: FromStr>(input: &str) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.parse().map_err(|_| SolutionError::InvalidLine {
+                line: index + 1,
+                text: line.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Parses the lines of the input with [FromStr], skipping the ones that don't parse.
+///
+/// # Example
+/// ```
+/// use aoc::parse::lines_lossy;
+///
+/// assert_eq!(lines_lossy::<u32>("1721\nNaN\n979"), vec![1721, 979]);
+/// ```
+pub fn lines_lossy<T: FromStr>(input: &str) -> Vec<T> {
+    input.lines().filter_map(|line| line.parse().ok()).collect()
+}
+
+/// Splits the input in blocks separated by blank lines, eg: the calories of each elf.
+///
+/// Blank lines are the empty or whitespace only lines, ending with `\n` or `\r\n`. Leading,
+/// trailing and consecutive blank lines don't make empty blocks, and the trailing whitespace of
+/// the blocks is trimmed. The blocks borrow the input: a Windows input keeps its `\r\n` inside
+/// the blocks, which [str::lines] removes.
+///
+/// # Example
+/// ```
+/// use aoc::parse::blocks;
+///
+/// let input = "1000\n2000\n\n4000\r\n\r\n5000\n6000\n\n";
+///
+/// assert_eq!(blocks(input), vec!["1000\n2000", "4000", "5000\n6000"]);
+/// ```
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut block: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some((start, end)) = block.take() {
+                blocks.push(&input[start..end]);
+            }
+        } else {
+            let start = block.map_or(offset, |(start, _)| start);
+            block = Some((start, offset + content.trim_end().len()));
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((start, end)) = block {
+        blocks.push(&input[start..end]);
+    }
+
+    blocks
+}
+
+/// Parses each of the [blocks] of the input with `parse`, stopping at the first error.
+///
+/// Deprecated in favor of [blocks_with], which also reports which block failed.
+///
+/// # Example
+/// ```
+///# #![allow(deprecated)]
+/// use aoc::parse::blocks_map;
+/// use aoc::solution::{Result, SolutionError};
+///
+/// fn calories(block: &str) -> Result<u32> {
+///     block
+///         .lines()
+///         .map(|line| line.parse::<u32>().map_err(|_| SolutionError::parse("not a number")))
+///         .sum()
+/// }
+///
+/// assert_eq!(blocks_map("1000\n2000\n\n4000", calories).unwrap(), vec![3000, 4000]);
+/// assert!(blocks_map("1000\n\nNaN", calories).is_err());
+/// ```
+#[deprecated(note = "use blocks_with, which also tells which block failed")]
+pub fn blocks_map<T>(input: &str, parse: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    blocks(input).into_iter().map(parse).collect()
+}
+
+/// Parses each of the [blocks] of the input with `parse`, stopping at the first error.
+///
+/// The error is reported as [SolutionError::InvalidBlock], with the 1-based number of the block.
+///
+/// # Example
+/// ```
+/// use aoc::parse::{blocks_with, lines};
+///
+/// let elves: Vec<Vec<u32>> = blocks_with("1000\n2000\n\n4000", lines).unwrap();
+/// assert_eq!(elves, vec![vec![1000, 2000], vec![4000]]);
+///
+/// let error = blocks_with::<Vec<u32>>("1000\n\n4000\nNaN", lines).unwrap_err();
+/// assert!(error.to_string().starts_with("Invalid Puzzle input in block 2"));
+/// ```
+pub fn blocks_with<T>(input: &str, mut parse: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    blocks(input)
+        .into_iter()
+        .enumerate()
+        .map(|(index, block)| {
+            parse(block).map_err(|e| SolutionError::InvalidBlock {
+                block: index + 1,
+                source: Box::new(e),
+            })
+        })
+        .collect()
+}
+
+/// Parses each of the [blocks] of the input with [FromStr], like [blocks_with].
+///
+/// # Example
+/// ```
+/// use aoc::parse::blocks_parsed;
+///
+/// let groups: Vec<String> = blocks_parsed("abc\n\na\nb\nc").unwrap();
+/// assert_eq!(groups, vec!["abc", "a\nb\nc"]);
+/// ```
+pub fn blocks_parsed<T: FromStr>(input: &str) -> Result<Vec<T>> {
+    blocks_with(input, |block| {
+        block
+            .parse()
+            .map_err(|_| SolutionError::parse(format!("not a valid {}", type_name::<T>())))
+    })
+}
+
+/// Parses a grid of digits, eg: a heightmap, row by row.
+///
+/// A character other than a digit is reported as [SolutionError::InvalidCell], and a row of
+/// another width than the first one as [SolutionError::RaggedRow], with their 1-based position.
+/// The trailing blank lines are ignored. See [crate::grid::Grid] for a grid of any cell.
+///
+/// # Example
+/// ```
+/// use aoc::parse::digit_grid;
+///
+/// assert_eq!(digit_grid("219\r\n398\r\n").unwrap(), vec![vec![2, 1, 9], vec![3, 9, 8]]);
+///
+/// let error = digit_grid("219\n3x8").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid Puzzle input at line 2, column 2: 'x'");
+///
+/// let error = digit_grid("219\n39").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid Puzzle input at line 2: 2 cells wide, expected 3");
+/// ```
+pub fn digit_grid(input: &str) -> Result<Vec<Vec<u8>>> {
+    crate::grid::parse_rows(input, |c| c.to_digit(10).map(|digit| digit as u8))
+}
+
+/// Parses a list of integers separated by commas, eg: `16,1,2,0,4,2,7,1,2,14`.
+///
+/// The whitespace around the integers is ignored, and a blank input is an empty list. The first
+/// token that is not an integer is reported, located in the input. See [wsv_ints] for the lists
+/// separated by whitespace, and [ints] to pick the integers out of any text.
+///
+/// # Example
+/// ```
+/// use aoc::parse::csv_ints;
+///
+/// assert_eq!(csv_ints("3,4, -3,\t4\n").unwrap(), vec![3, 4, -3, 4]);
+///
+/// let error = csv_ints("3,4,x,4").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid Puzzle input: invalid integer \"x\": invalid digit found in string at line 1, column 5"
+/// );
+/// ```
+pub fn csv_ints(input: &str) -> Result<Vec<i64>> {
+    separated_ints(input, input.trim().split(',').map(str::trim))
+}
+
+/// Parses a list of integers separated by whitespace, eg: `0 2 7 0`.
+///
+/// Same as [csv_ints], with any run of whitespace, including newlines, as the separator.
+///
+/// # Example
+/// ```
+/// use aoc::parse::wsv_ints;
+///
+/// assert_eq!(wsv_ints("0  2\t7 -1\n").unwrap(), vec![0, 2, 7, -1]);
+///
+/// let error = wsv_ints("0 2\n7 1.5").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid Puzzle input: invalid integer \"1.5\": invalid digit found in string at line 2, column 3"
+/// );
+/// ```
+pub fn wsv_ints(input: &str) -> Result<Vec<i64>> {
+    separated_ints(input, input.split_whitespace())
+}
+
+/// Parses the `tokens`, slices of `input`, locating the first invalid one.
+fn separated_ints<'a>(input: &str, tokens: impl Iterator<Item = &'a str>) -> Result<Vec<i64>> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    tokens
+        .map(|token| {
+            let offset = token.as_ptr() as usize - input.as_ptr() as usize;
+            let line_idx = input[..offset].matches('\n').count();
+
+            token
+                .parse()
+                .or_parse_err_with(|| format!("invalid integer {:?}", token))
+                .with_token(input, line_idx, token)
+        })
+        .collect()
+}
+
+/// Parses every integer of `s`, ignoring the text around them, eg: `move 3 from 1 to 2`.
+///
+/// An integer is a run of ASCII digits, including a `-` right before it unless that `-` follows
+/// a digit: `x=-5..13` gives `-5` and `13`, while the range `2-4` gives `2` and `4`. The
+/// integers that don't fit in `T` are skipped. See [uints] to ignore the signs.
+///
+/// # Example
+/// ```
+/// use aoc::parse::ints;
+///
+/// assert_eq!(ints::<i32>("target area: x=20..30, y=-10..-5"), vec![20, 30, -10, -5]);
+/// assert_eq!(ints::<u8>("2-4,6-8"), vec![2, 4, 6, 8]);
+/// ```
+pub fn ints<T: FromStr>(s: &str) -> Vec<T> {
+    tokens(s, true)
+        .filter_map(|token| token.parse().ok())
+        .collect()
+}
+
+/// Parses every run of ASCII digits of `s`, ignoring the text and the signs around them.
+/// The integers that don't fit in `T` are skipped.
+///
+/// # Example
+/// ```
+/// use aoc::parse::uints;
+///
+/// assert_eq!(uints::<u32>("x=-5..13"), vec![5, 13]);
+/// ```
+pub fn uints<T: FromStr>(s: &str) -> Vec<T> {
+    tokens(s, false)
+        .filter_map(|token| token.parse().ok())
+        .collect()
+}
+
+/// The first integer of `s` that fits in `T`, following the rules of [ints].
+///
+/// # Example
+/// ```
+/// use aoc::parse::first_int;
+///
+/// assert_eq!(first_int::<i64>("Monkey 3:"), Some(3));
+/// assert_eq!(first_int::<i64>("  Operation: new = old * old"), None);
+/// ```
+pub fn first_int<T: FromStr>(s: &str) -> Option<T> {
+    tokens(s, true).find_map(|token| token.parse().ok())
+}
+
+/// Parses the input with the [FromStr] implementation of `T`, for the inputs of [Lines],
+/// [Blocks] and [Chars] that need no `parse` of their own.
+///
+/// The whole [crate::Solution::parse] of a day with one number per line becomes:
+///
+/// # Example
+/// ```
+/// use aoc::{Lines, Solution};
+/// use aoc::solution::Result;
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     // -- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type P1 = i64; type P2 = usize;
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { input.iter().max().copied() }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(input.len()) }
+///     type Input = Lines<i64>;
+///
+///     fn parse(input: &str) -> Result<Self::Input> {
+///         aoc::parse_default(input)
+///     }
+/// }
+///
+/// assert_eq!(*DayXX::parse("+3\n-2\n").unwrap(), [3, -2]);
+/// assert_eq!(
+///     DayXX::parse("+3\nx").unwrap_err().to_string(),
+///     "Invalid Puzzle input at line 2: \"x\""
+/// );
+/// ```
+pub fn parse_default<T: FromStr<Err = SolutionError>>(input: &str) -> Result<T> {
+    input.parse()
+}
+
+/// Where an error is in the input. See [Context] and [SolutionError::location]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column of the faulty token, in characters. `None` stands for the whole line.
+    pub column: Option<usize>,
+    /// Width of the faulty token, in characters.
+    pub width: usize,
+}
+
+impl Location {
+    /// The whole line at the 1-based `line`.
+    pub fn line(line: usize) -> Self {
+        Location {
+            line,
+            column: None,
+            width: 0,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}", self.line, column),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// Attaches the [Location] of an error in the input to it, reported as
+/// [SolutionError::Located], so it can be shown with [render_diagnostic].
+///
+/// Implemented for the results of the std parsers too, eg: `str::parse::<u32>`.
+///
+/// # Example
+/// ```
+/// use aoc::parse::Context;
+/// use aoc::solution::Result;
+///
+/// fn parse(input: &str) -> Result<Vec<(String, u32)>> {
+///     input
+///         .lines()
+///         .enumerate()
+///         .map(|(i, line)| {
+///             let (name, size) = line.split_once(' ').unwrap_or((line, ""));
+///             Ok((name.to_owned(), size.parse().with_token(input, i, size)?))
+///         })
+///         .collect()
+/// }
+///
+/// let error = parse("a.txt 12\nb.txt 4x").unwrap_err();
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid Puzzle input: invalid digit found in string at line 2, column 7"
+/// );
+/// ```
+pub trait Context<T> {
+    /// Locates the error on the 0-based `line_idx` of `input`, as counted by
+    /// `input.lines().enumerate()`. Its text, without the surrounding whitespace, is underlined.
+    fn with_line(self, input: &str, line_idx: usize) -> Result<T>;
+
+    /// Locates the error on `token`, a slice of the 0-based `line_idx` of `input`. When `token`
+    /// is not a slice of the line, its first occurrence in the line is used.
+    fn with_token(self, input: &str, line_idx: usize, token: &str) -> Result<T>;
+}
+
+impl<T, E: Into<SolutionError>> Context<T> for std::result::Result<T, E> {
+    fn with_line(self, input: &str, line_idx: usize) -> Result<T> {
+        self.map_err(|e| {
+            let text = input.lines().nth(line_idx).unwrap_or_default();
+
+            located(e.into(), line_span(line_idx, text))
+        })
+    }
+
+    fn with_token(self, input: &str, line_idx: usize, token: &str) -> Result<T> {
+        self.map_err(|e| {
+            let text = input.lines().nth(line_idx).unwrap_or_default();
+            let offset = (token.as_ptr() as usize)
+                .checked_sub(text.as_ptr() as usize)
+                .filter(|offset| offset + token.len() <= text.len())
+                .or_else(|| text.find(token));
+
+            let location = match offset {
+                Some(offset) => Location {
+                    line: line_idx + 1,
+                    column: Some(text[..offset].chars().count() + 1),
+                    width: token.chars().count(),
+                },
+                None => line_span(line_idx, text),
+            };
+
+            located(e.into(), location)
+        })
+    }
+}
+
+/// The text of a line without the surrounding whitespace.
+fn line_span(line_idx: usize, text: &str) -> Location {
+    let (column, width) = trimmed_span(text);
+
+    Location {
+        line: line_idx + 1,
+        column: Some(column),
+        width,
+    }
+}
+
+/// The 1-based column and the width of `text` without its surrounding whitespace.
+fn trimmed_span(text: &str) -> (usize, usize) {
+    let indent = text.len() - text.trim_start().len();
+
+    (
+        text[..indent].chars().count() + 1,
+        text.trim().chars().count(),
+    )
+}
+
+fn located(error: SolutionError, location: Location) -> SolutionError {
+    SolutionError::Located {
+        location,
+        source: Box::new(error),
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl<T, E> Sealed for Result<T, E> {}
+    impl<T> Sealed for Option<T> {}
+}
+
+/// Turns the errors of the std parsers, and the missing values, into a
+/// [SolutionError::ParseError] saying what was being parsed.
+///
+/// Shorter than `.map_err(|e| SolutionError::parse(...))` in a [crate::Solution::parse].
+///
+/// # Example
+/// ```
+/// use aoc::ResultExt;
+///
+/// let error = "x".parse::<u32>().or_parse_err("bad size").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid Puzzle input: bad size: invalid digit found in string");
+///
+/// let error = "a b".split_once(',').or_parse_err("missing ','").unwrap_err();
+/// assert_eq!(error.to_string(), "Invalid Puzzle input: missing ','");
+/// ```
+pub trait ResultExt<T>: sealed::Sealed {
+    /// The value, or a [SolutionError::ParseError] of `context`, followed by the error if any.
+    fn or_parse_err(self, context: &str) -> Result<T>;
+
+    /// Same as [ResultExt::or_parse_err], the context being built only on error.
+    fn or_parse_err_with<C: Display>(self, context: impl FnOnce() -> C) -> Result<T>;
+}
+
+impl<T, E: Display> ResultExt<T> for std::result::Result<T, E> {
+    fn or_parse_err(self, context: &str) -> Result<T> {
+        self.or_parse_err_with(|| context)
+    }
+
+    fn or_parse_err_with<C: Display>(self, context: impl FnOnce() -> C) -> Result<T> {
+        self.map_err(|e| SolutionError::parse(format!("{}: {}", context(), e)))
+    }
+}
+
+impl<T> ResultExt<T> for Option<T> {
+    fn or_parse_err(self, context: &str) -> Result<T> {
+        self.or_parse_err_with(|| context)
+    }
+
+    fn or_parse_err_with<C: Display>(self, context: impl FnOnce() -> C) -> Result<T> {
+        self.ok_or_else(|| SolutionError::parse(context().to_string()))
+    }
+}
+
+/// [Option::ok_or] with a [SolutionError::ParseError]. See [ResultExt] for the results.
+///
+/// # Example
+/// ```
+/// use aoc::OptionExt;
+///
+/// let digit = 'x'.to_digit(10).ok_or_parse("not a digit");
+/// assert_eq!(digit.unwrap_err().to_string(), "Invalid Puzzle input: not a digit");
+/// ```
+pub trait OptionExt<T>: sealed::Sealed {
+    /// The value, or a [SolutionError::ParseError] of `message`.
+    fn ok_or_parse(self, message: &str) -> Result<T>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn ok_or_parse(self, message: &str) -> Result<T> {
+        self.or_parse_err(message)
+    }
+}
+
+/// Renders the line of `input` where `error` is, like a compiler diagnostic:
+/// its [Location], the line, then carets under the faulty token.
+///
+/// Returns an empty string when the error has no location. (See [SolutionError::location])
+///
+/// # Example
+/// ```
+/// use aoc::parse::{lines, render_diagnostic};
+///
+/// let input = "1721\n979\n36x6";
+/// let error = lines::<u32>(input).unwrap_err();
+///
+/// assert_eq!(
+///     render_diagnostic(input, &error),
+///     " --> line 3\n3 | 36x6\n  | ^^^^"
+/// );
+/// ```
+pub fn render_diagnostic(input: &str, error: &SolutionError) -> String {
+    let Some(location) = error.location() else {
+        return String::new();
+    };
+
+    let text = input
+        .lines()
+        .nth(location.line.saturating_sub(1))
+        .unwrap_or_default();
+    let (column, width) = match location.column {
+        Some(column) => (column, location.width),
+        None => trimmed_span(text),
+    };
+
+    let number = location.line.to_string();
+    let gutter = " ".repeat(number.len());
+
+    format!(
+        "{gutter}--> {location}\n{number} | {text}\n{gutter} | {}{}",
+        " ".repeat(column.saturating_sub(1)),
+        "^".repeat(width.max(1)),
+    )
+}
+
+/// One value per non-blank line of the input. Parsed by [lines], reporting the bad line.
+///
+/// Dereferences to the slice of the values, for `iter()`, `windows()`, `len()`...
+///
+/// # Example
+/// ```
+/// use aoc::Lines;
+///
+/// let depths: Lines<u32> = aoc::parse_default("199\n200\n208\n210\n200").unwrap();
+///
+/// assert_eq!(depths.windows(2).filter(|w| w[1] > w[0]).count(), 3);
+/// assert_eq!(depths.sum(), 1017);
+/// assert_eq!(depths.pairs().filter(|(a, b)| *a + *b == 408).count(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Lines<T>(pub Vec<T>);
+
+/// One value per [blocks] of the input. Parsed by [blocks_parsed], reporting the bad block.
+///
+/// Dereferences to the slice of the values, eg: `Blocks<Lines<u32>>` for the calories of the elves.
+///
+/// # Example
+/// ```
+/// use aoc::{Blocks, Lines};
+///
+/// let elves: Blocks<Lines<u32>> = aoc::parse_default("1000\n2000\n\n4000").unwrap();
+///
+/// assert_eq!(elves.iter().map(|elf| elf.sum()).max(), Some(4000));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Blocks<T>(pub Vec<T>);
+
+/// The characters of the input, without its trailing whitespace.
+///
+/// Dereferences to the slice of the characters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Chars(pub Vec<char>);
+
+impl<T: FromStr> FromStr for Lines<T> {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        lines(s).map(Lines)
+    }
+}
+
+impl<T: FromStr> FromStr for Blocks<T> {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        blocks_parsed(s).map(Blocks)
+    }
+}
+
+impl FromStr for Chars {
+    type Err = SolutionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Chars(s.trim_end().chars().collect()))
+    }
+}
+
+macro_rules! impl_collection {
+    ($name:ident $(<$t:ident>)?, $item:ty) => {
+        impl<$($t)?> Deref for $name$(<$t>)? {
+            type Target = [$item];
+
+            fn deref(&self) -> &[$item] {
+                &self.0
+            }
+        }
+
+        impl<$($t)?> DerefMut for $name$(<$t>)? {
+            fn deref_mut(&mut self) -> &mut [$item] {
+                &mut self.0
+            }
+        }
+
+        impl<$($t)?> FromIterator<$item> for $name$(<$t>)? {
+            fn from_iter<I: IntoIterator<Item = $item>>(iter: I) -> Self {
+                $name(iter.into_iter().collect())
+            }
+        }
+
+        impl<$($t)?> IntoIterator for $name$(<$t>)? {
+            type Item = $item;
+            type IntoIter = std::vec::IntoIter<$item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.into_iter()
+            }
+        }
+
+        impl<'a, $($t)?> IntoIterator for &'a $name$(<$t>)? {
+            type Item = &'a $item;
+            type IntoIter = std::slice::Iter<'a, $item>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+    };
+}
+
+impl<T> Lines<T> {
+    /// Every pair of distinct lines, in the order of the input: `(a, b)` with `a` before `b`.
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.0
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, a)| self.0[i + 1..].iter().map(move |b| (a, b)))
+    }
+
+    /// The sum of the values.
+    pub fn sum(&self) -> T
+    where
+        T: for<'a> std::iter::Sum<&'a T>,
+    {
+        self.0.iter().sum()
+    }
+}
+
+impl_collection!(Lines<T>, T);
+impl_collection!(Blocks<T>, T);
+impl_collection!(Chars, char);
+
+/// The integers of `s` as slices of it, with their sign when `signed`. See [ints]
+fn tokens(s: &str, signed: bool) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() && !bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+
+        let mut start = index;
+        let negative = start > 0 && bytes[start - 1] == b'-';
+        let after_digit = start > 1 && bytes[start - 2].is_ascii_digit();
+        if signed && negative && !after_digit {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        Some(&s[start..index])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(lines::<u32>("").unwrap(), Vec::<u32>::new());
+        assert_eq!(lines::<u32>("\n  \n").unwrap(), Vec::<u32>::new());
+        assert_eq!(lines::<u32>("1\r\n2\r\n\r\n3\r\n").unwrap(), vec![1, 2, 3]);
+
+        let error = lines::<u32>("1\n2\nthree\n4").unwrap_err();
+        assert!(error.to_string().contains("line 3"), "{}", error);
+        assert!(matches!(
+            error,
+            SolutionError::InvalidLine { line: 3, text } if text == "three"
+        ));
+    }
+
+    #[test]
+    fn parse_lines_lossy() {
+        assert_eq!(lines_lossy::<u32>(""), Vec::<u32>::new());
+        assert_eq!(lines_lossy::<u32>("1\r\n\r\nthree\r\n4"), vec![1, 4]);
+    }
+
+    #[test]
+    fn default_parse() {
+        let numbers: Lines<i64> = parse_default("1\r\n-2\r\n\r\n3\r\n").unwrap();
+        assert_eq!(*numbers, [1, -2, 3]);
+        assert_eq!(numbers.iter().sum::<i64>(), 2);
+        assert!(parse_default::<Lines<i64>>("").unwrap().is_empty());
+        assert!(matches!(
+            parse_default::<Lines<i64>>("1\n2\n3.5"),
+            Err(SolutionError::InvalidLine { line: 3, .. })
+        ));
+
+        let elves: Blocks<Lines<u32>> = parse_default("1000\n2000\n\n4000\n").unwrap();
+        assert_eq!(
+            elves
+                .iter()
+                .map(|elf| elf.iter().sum())
+                .collect::<Vec<u32>>(),
+            vec![3000, 4000]
+        );
+        assert!(matches!(
+            parse_default::<Blocks<Lines<u32>>>("1\n\n2\nNaN"),
+            Err(SolutionError::InvalidBlock { block: 2, .. })
+        ));
+
+        let chars: Chars = parse_default("(()(\n").unwrap();
+        assert_eq!(chars.iter().filter(|&&c| c == '(').count(), 3);
+        assert_eq!(chars.into_iter().collect::<String>(), "(()(");
+    }
+
+    #[test]
+    fn lines_accessors() {
+        let lines: Lines<u32> = [1, 2, 3, 4].into_iter().collect();
+
+        assert_eq!(lines.sum(), 10);
+        assert_eq!(Lines::<u32>::default().sum(), 0);
+        assert_eq!(
+            lines.pairs().map(|(a, b)| (*a, *b)).collect::<Vec<_>>(),
+            vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]
+        );
+        assert_eq!(Lines(vec![1]).pairs().count(), 0);
+        assert_eq!(
+            lines.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>(),
+            vec![1, 1, 1]
+        );
+        assert_eq!((&lines).into_iter().max(), Some(&4));
+        assert_eq!(
+            lines.clone().into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(format!("{:?}", Lines(vec![1])), "Lines([1])");
+
+        let mut sorted = Lines(vec![3, 1, 2]);
+        sorted.sort();
+        assert_eq!(*sorted, [1, 2, 3]);
+    }
+
+    #[test]
+    fn blocks_accessors() {
+        let groups: Blocks<Chars> = parse_default("abc\n\na\nb\nc\n\nab\nac").unwrap();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups.iter().map(|group| group.len()).collect::<Vec<_>>(),
+            vec![3, 5, 5],
+            "the line breaks are characters of a block"
+        );
+        assert_eq!((&groups).into_iter().count(), 3);
+        assert_eq!(groups.clone(), groups);
+    }
+
+    #[test]
+    fn inputs_are_sync() {
+        fn input<T: Send + Sync + Clone + std::fmt::Debug>() {}
+
+        input::<Lines<i64>>();
+        input::<Blocks<Lines<u32>>>();
+        input::<Chars>();
+    }
+
+    #[test]
+    fn integers() {
+        let cases: [(&str, &[i64], &[i64]); 9] = [
+            ("", &[], &[]),
+            ("no numbers here", &[], &[]),
+            ("move 3 from 1 to 2", &[3, 1, 2], &[3, 1, 2]),
+            ("x=-5..13", &[-5, 13], &[5, 13]),
+            ("p=<1,-2,3>", &[1, -2, 3], &[1, 2, 3]),
+            ("2-4,6-8", &[2, 4, 6, 8], &[2, 4, 6, 8]),
+            ("-1 --2 3--4 -", &[-1, -2, 3, -4], &[1, 2, 3, 4]),
+            ("a-1 b-2", &[-1, -2], &[1, 2]),
+            ("007\r\n-0", &[7, 0], &[7, 0]),
+        ];
+
+        for (s, signed, unsigned) in cases {
+            assert_eq!(ints::<i64>(s), signed, "ints of {:?}", s);
+            assert_eq!(uints::<i64>(s), unsigned, "uints of {:?}", s);
+            assert_eq!(
+                first_int::<i64>(s),
+                signed.first().copied(),
+                "first of {:?}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn integers_out_of_range() {
+        assert_eq!(ints::<u8>("-1 2 300 4"), vec![2, 4]);
+        assert_eq!(first_int::<u8>("-1 256 7"), Some(7));
+        assert_eq!(uints::<u8>("-1 256"), vec![1]);
+        assert_eq!(ints::<i8>("é-128ü"), vec![-128], "non ASCII text around");
+    }
+
+    #[test]
+    fn digit_grids() {
+        assert_eq!(digit_grid("").unwrap(), Vec::<Vec<u8>>::new());
+        assert_eq!(
+            digit_grid("01\n23\n\n").unwrap(),
+            vec![vec![0, 1], vec![2, 3]]
+        );
+        assert!(matches!(
+            digit_grid("01\n23\n4-"),
+            Err(SolutionError::InvalidCell {
+                line: 3,
+                column: 2,
+                found: '-'
+            })
+        ));
+        assert!(matches!(
+            digit_grid("01\n234\n56"),
+            Err(SolutionError::RaggedRow {
+                line: 2,
+                width: 3,
+                expected: 2
+            })
+        ));
+        assert!(matches!(
+            digit_grid("01\n\n23"),
+            Err(SolutionError::RaggedRow {
+                line: 2,
+                width: 0,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn blank_line_edge_cases() {
+        assert_eq!(blocks(""), Vec::<&str>::new());
+        assert_eq!(blocks("\n\n\n"), Vec::<&str>::new());
+        assert_eq!(blocks("a\nb"), vec!["a\nb"]);
+        assert_eq!(blocks("\n\na\n\n\n\nb\n\n"), vec!["a", "b"]);
+        assert_eq!(blocks("a\n  \t\nb"), vec!["a", "b"]);
+        assert_eq!(blocks("    [D]\n1 2\n\nmove"), vec!["    [D]\n1 2", "move"]);
+        assert_eq!(blocks("a  \n\n\n\nb \t"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn csv_and_wsv_ints() {
+        assert_eq!(
+            csv_ints("16,1,2,0,4,2,7,1,2,14\n").unwrap(),
+            [16, 1, 2, 0, 4, 2, 7, 1, 2, 14]
+        );
+        assert_eq!(csv_ints(" -1 , +2 ").unwrap(), [-1, 2]);
+        assert_eq!(csv_ints("42").unwrap(), [42]);
+        assert_eq!(csv_ints(" \n").unwrap(), [0_i64; 0]);
+        assert_eq!(wsv_ints("").unwrap(), [0_i64; 0]);
+        assert_eq!(wsv_ints("\t5 -6\r\n7\n\n8").unwrap(), [5, -6, 7, 8]);
+
+        let error = csv_ints("1,2,,3").unwrap_err();
+        assert_eq!(error, crate::solution::ErrorKind::Parse);
+        assert_eq!(
+            error.location(),
+            Some(Location {
+                line: 1,
+                column: Some(5),
+                width: 0
+            })
+        );
+
+        let input = "1,2\n,99999999999999999999";
+        let error = csv_ints(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input: invalid integer \"99999999999999999999\": number too large to fit \
+            in target type at line 2, column 2"
+        );
+        assert_eq!(
+            render_diagnostic(input, &error),
+            " --> line 2, column 2\n2 | ,99999999999999999999\n  |  ^^^^^^^^^^^^^^^^^^^^"
+        );
+
+        let error = wsv_ints("1 2\n 3 x3").unwrap_err();
+        assert_eq!(
+            error.location(),
+            Some(Location {
+                line: 2,
+                column: Some(4),
+                width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn parse_error_extensions() {
+        let error = "-1".parse::<u8>().or_parse_err("bad count").unwrap_err();
+        assert_eq!(error, crate::solution::ErrorKind::Parse);
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input: bad count: invalid digit found in string"
+        );
+
+        let line = 3;
+        let error = ""
+            .parse::<u32>()
+            .or_parse_err_with(|| format!("line {}", line))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input: line 3: cannot parse integer from empty string"
+        );
+
+        assert_eq!(Some(1).or_parse_err("unused").unwrap(), 1);
+        assert_eq!(
+            None::<u8>
+                .or_parse_err_with(|| 'x')
+                .unwrap_err()
+                .to_string(),
+            "Invalid Puzzle input: x"
+        );
+        assert_eq!(
+            None::<u8>.ok_or_parse("no value").unwrap_err().to_string(),
+            "Invalid Puzzle input: no value"
+        );
+        assert_eq!(Ok::<_, String>(2).or_parse_err("unused").unwrap(), 2);
+    }
+
+    #[test]
+    fn diagnostics() {
+        let input = "12 x4\n  34 56\n78 9y";
+
+        let error = "x4".parse::<u32>().with_token(input, 0, "x4").unwrap_err();
+        assert_eq!(
+            render_diagnostic(input, &error),
+            " --> line 1, column 4\n1 | 12 x4\n  |    ^^"
+        );
+
+        let error = Err::<(), _>(SolutionError::parse("odd"))
+            .with_line(input, 1)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input: odd at line 2, column 3"
+        );
+        assert_eq!(
+            render_diagnostic(input, &error),
+            " --> line 2, column 3\n2 |   34 56\n  |   ^^^^^"
+        );
+
+        let last = &input[input.len() - 2..];
+        let error = last.parse::<u32>().with_token(input, 2, last).unwrap_err();
+        assert_eq!(
+            render_diagnostic(input, &error),
+            " --> line 3, column 4\n3 | 78 9y\n  |    ^^"
+        );
+    }
+
+    #[test]
+    fn diagnostic_locations() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n9\n1x";
+
+        let error = lines::<u8>(input).unwrap_err();
+        assert_eq!(
+            render_diagnostic(input, &error),
+            "  --> line 10\n10 | 1x\n   | ^^"
+        );
+
+        let error = digit_grid("12\n3x").unwrap_err();
+        assert_eq!(
+            render_diagnostic("12\n3x", &error),
+            " --> line 2, column 2\n2 | 3x\n  |  ^"
+        );
+
+        let error = blocks_parsed::<u32>("1\n\nx").unwrap_err();
+        assert_eq!(error.location(), None);
+        assert_eq!(render_diagnostic("1\n\nx", &error), "");
+
+        let error = SolutionError::Located {
+            location: Location {
+                line: 1,
+                column: Some(0),
+                width: 1,
+            },
+            source: Box::new(SolutionError::parse("odd")),
+        };
+        assert_eq!(
+            render_diagnostic("ab", &error),
+            " --> line 1, column 0\n1 | ab\n  | ^"
+        );
+    }
+
+    #[test]
+    fn block_errors() {
+        assert_eq!(blocks_parsed::<u32>("1\n\n\n2").unwrap(), vec![1, 2]);
+
+        let error = blocks_parsed::<u32>("1\r\n\r\n2\r\n\r\nthree\r\n").unwrap_err();
+        assert!(matches!(
+            error,
+            SolutionError::InvalidBlock { block: 3, .. }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input in block 3: Invalid Puzzle input: not a valid u32"
+        );
+
+        let error = blocks_with("1\n2\n\n3\nfour", lines::<u32>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input in block 2: Invalid Puzzle input at line 2: \"four\""
+        );
+    }
+
+    #[test]
+    fn windows_line_endings() {
+        let input = "ecl:gry\r\nbyr:1937\r\n\r\niyr:2013\r\n\r\n";
+
+        assert_eq!(blocks(input), vec!["ecl:gry\r\nbyr:1937", "iyr:2013"]);
+        assert_eq!(
+            blocks(input)[0].lines().collect::<Vec<_>>(),
+            vec!["ecl:gry", "byr:1937"]
+        );
+    }
+}
@@ -0,0 +1,289 @@
+//! Timing of the phases of a solution: parsing, part 1 and part 2.
+//!
+//! With the `tracing` feature, each run is wrapped in an `aoc.run{day, year}` span and each
+//! phase in an `aoc.parse`, `aoc.part1` or `aoc.part2` span with the same fields (plus `part`),
+//! closed by an event carrying the measured duration. The phases having their own span names,
+//! flamegraphs (`tracing-flame`...) attribute the time of each part separately.
+
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use humantime::format_duration;
+
+use crate::{logging, time};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Span of a whole run. Does nothing without the `tracing` feature.
+pub(crate) struct Span {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    dispatch: tracing::Dispatch,
+}
+
+/// Creates the span of a run of the solution of `day`/`year`.
+pub(crate) fn run_span(_day: u8, _year: Option<u16>) -> Span {
+    Span {
+        #[cfg(feature = "tracing")]
+        span: tracing::info_span!("aoc.run", day = _day, year = _year),
+        #[cfg(feature = "tracing")]
+        dispatch: tracing::dispatcher::get_default(|dispatch| dispatch.clone()),
+    }
+}
+
+impl Span {
+    /// Runs `f` inside the span.
+    ///
+    /// This can be called from other threads (eg: run_par's workers) so that their phases
+    /// are children of the run.
+    pub(crate) fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        #[cfg(feature = "tracing")]
+        return tracing::dispatcher::with_default(&self.dispatch, || self.span.in_scope(f));
+
+        #[cfg(not(feature = "tracing"))]
+        f()
+    }
+}
+
+/// Runs a phase of the solution of `day` and returns it with its execution Duration.
+///
+/// The start and the end of the phase are logged at debug level.
+pub(crate) fn timed<T>(
+    day: u8,
+    _year: Option<u16>,
+    phase: Phase,
+    f: impl FnOnce() -> T,
+) -> (T, Duration) {
+    #[cfg(feature = "tracing")]
+    let span = match phase {
+        Phase::Parse => tracing::info_span!("aoc.parse", day, year = _year),
+        Phase::Part1 => tracing::info_span!("aoc.part1", day, year = _year, part = 1u8),
+        Phase::Part2 => tracing::info_span!("aoc.part2", day, year = _year, part = 2u8),
+    };
+    #[cfg(feature = "tracing")]
+    let _entered = span.enter();
+
+    logging::debug(format_args!("Day {:02}: {} started", day, phase));
+
+    let (result, duration) = time!(f());
+
+    logging::debug(format_args!(
+        "Day {:02}: {} done in {}",
+        day,
+        phase,
+        format_duration(duration)
+    ));
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        duration_ns = duration.as_nanos() as u64,
+        "{} done in {}",
+        phase,
+        format_duration(duration)
+    );
+
+    (result, duration)
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing::{Event, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use tracing_subscriber::registry::LookupSpan;
+
+    use crate::solution::{Result, SolutionError};
+    use crate::Solution;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct SpanRecord {
+        name: &'static str,
+        fields: Vec<(String, String)>,
+        parent: Option<&'static str>,
+    }
+
+    /// Span name and fields of an event.
+    type EventRecord = (Option<&'static str>, Vec<(String, String)>);
+
+    #[derive(Default)]
+    struct Fields(Vec<(String, String)>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .push((field.name().to_owned(), format!("{:?}", value)));
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Capture {
+        spans: Arc<Mutex<Vec<SpanRecord>>>,
+        events: Arc<Mutex<Vec<EventRecord>>>,
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Capture {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            attrs.record(&mut fields);
+            let parent = ctx
+                .span(id)
+                .and_then(|span| span.parent())
+                .map(|p| p.name());
+
+            self.spans.lock().unwrap().push(SpanRecord {
+                name: attrs.metadata().name(),
+                fields: fields.0,
+                parent,
+            });
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            let span = ctx.event_span(event).map(|span| span.name());
+
+            self.events.lock().unwrap().push((span, fields.0));
+        }
+    }
+
+    struct Traced;
+    impl Solution for Traced {
+        const TITLE: &'static str = "Traced";
+        const DAY: u8 = 7;
+        const YEAR: Option<u16> = Some(2022);
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input + 1)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(input + 2)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("40".to_owned())
+        }
+    }
+
+    fn capture(f: impl FnOnce()) -> Capture {
+        let capture = Capture::default();
+        let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+        tracing::subscriber::with_default(subscriber, f);
+
+        capture
+    }
+
+    fn field(name: &str, value: &str) -> (String, String) {
+        (name.to_owned(), value.to_owned())
+    }
+
+    fn assert_run_spans(capture: &Capture) {
+        let spans = capture.spans.lock().unwrap();
+
+        assert!(spans.contains(&SpanRecord {
+            name: "aoc.run",
+            fields: vec![field("day", "7"), field("year", "2022")],
+            parent: None,
+        }));
+        assert!(spans.contains(&SpanRecord {
+            name: "aoc.parse",
+            fields: vec![field("day", "7"), field("year", "2022")],
+            parent: Some("aoc.run"),
+        }));
+        for (name, part) in [("aoc.part1", "1"), ("aoc.part2", "2")] {
+            assert!(
+                spans.contains(&SpanRecord {
+                    name,
+                    fields: vec![
+                        field("day", "7"),
+                        field("year", "2022"),
+                        field("part", part),
+                    ],
+                    parent: Some("aoc.run"),
+                }),
+                "missing span for part {}: {:?}",
+                part,
+                spans
+            );
+        }
+
+        let events = capture.events.lock().unwrap();
+        let closing = events
+            .iter()
+            .filter(|(_, fields)| fields.iter().any(|(name, _)| name == "duration_ns"))
+            .map(|(span, _)| *span)
+            .collect::<Vec<_>>();
+        assert_eq!(closing.len(), 3);
+        assert!(closing.contains(&Some("aoc.parse")));
+        assert!(closing.contains(&Some("aoc.part1")));
+        assert!(closing.contains(&Some("aoc.part2")));
+    }
+
+    #[test]
+    fn run_spans() {
+        let capture = capture(|| {
+            Traced::run().unwrap();
+        });
+
+        assert_run_spans(&capture);
+    }
+
+    #[test]
+    fn run_par_spans_are_children_of_the_run() {
+        let capture = capture(|| {
+            Traced::run_par().unwrap();
+        });
+
+        assert_run_spans(&capture);
+    }
+
+    #[test]
+    fn test_part_spans() {
+        let capture = capture(|| {
+            Traced::test_part1_silent("1").unwrap();
+            Traced::test_part2_silent("1").unwrap();
+        });
+
+        let spans = capture.spans.lock().unwrap();
+        assert_eq!(spans.iter().filter(|s| s.name == "aoc.parse").count(), 2);
+        assert!(spans.contains(&SpanRecord {
+            name: "aoc.part1",
+            fields: vec![field("day", "7"), field("year", "2022"), field("part", "1")],
+            parent: None,
+        }));
+        assert!(spans.contains(&SpanRecord {
+            name: "aoc.part2",
+            fields: vec![field("day", "7"), field("year", "2022"), field("part", "2")],
+            parent: None,
+        }));
+    }
+}
@@ -0,0 +1,48 @@
+//! Reports over a whole season
+//!
+//! A [Season] gathers the results of several days, typically the results of
+//! [crate::runner::run_all]. [calendar] renders it as a calendar of the month.
+
+use std::collections::BTreeMap;
+
+use crate::output::ResultView;
+use crate::solution::Result;
+
+pub mod calendar;
+
+/// Results of the days of a season, by day.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Season {
+    pub year: Option<u16>,
+    pub days: BTreeMap<u8, ResultView>,
+}
+
+impl Season {
+    /// Season without any result.
+    pub fn new(year: Option<u16>) -> Self {
+        Season {
+            year,
+            days: BTreeMap::new(),
+        }
+    }
+
+    /// Season of the solved days of `results`. The days that failed are missing.
+    pub fn from_results(year: Option<u16>, results: &[Result<ResultView>]) -> Self {
+        let mut season = Self::new(year);
+        for view in results.iter().filter_map(|result| result.as_ref().ok()) {
+            season.insert(view.clone());
+        }
+
+        season
+    }
+
+    /// Adds the result of a day, replacing a previous one.
+    pub fn insert(&mut self, view: ResultView) {
+        self.days.insert(view.day, view);
+    }
+
+    /// Result of `day`, when solved.
+    pub fn get(&self, day: u8) -> Option<&ResultView> {
+        self.days.get(&day)
+    }
+}
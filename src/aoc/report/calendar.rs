@@ -0,0 +1,318 @@
+//! Calendar of a season: the 25 days in a 5×5 grid, with their stars and run time
+//!
+//! ```text
+//! Advent of Code 2022
+//! ┌───────┬───────┬───────┬───────┬───────┐
+//! │  1 ★★ │  2 ★  │  3 ·  │  4 ·  │  5 ·  │
+//! │ 12ms  │ 500ms │       │       │       │
+//! ├───────┼───────┼───────┼───────┼───────┤
+//! ...
+//! ```
+//!
+//! `★★` is a day with both answers, `★` a day with only the first one. With colors, the days
+//! are colored by run time (see [CalendarOptions]) and the missing days are dimmed.
+//!
+//! A single day is rendered as its own cell by [render_day].
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::output::ResultView;
+use crate::report::Season;
+
+/// Environment variable disabling the colors when set. See [CalendarOptions::from_env]
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// Command line flag disabling the colors. See [CalendarOptions::from_env]
+pub const NO_COLOR_ARG: &str = "--no-color";
+
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Width of the content of a cell, without its padding.
+const CELL: usize = 5;
+
+/// Options of [render].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarOptions {
+    /// Colors the days with ANSI escape codes.
+    pub color: bool,
+    /// Days solved under this time are green.
+    pub fast: Duration,
+    /// Days solved under this time are yellow, red above.
+    pub slow: Duration,
+}
+
+impl Default for CalendarOptions {
+    fn default() -> Self {
+        CalendarOptions {
+            color: true,
+            fast: Duration::from_millis(100),
+            slow: Duration::from_secs(1),
+        }
+    }
+}
+
+impl CalendarOptions {
+    /// Default options, without colors when stdout is not a terminal, `NO_COLOR` is set or
+    /// `--no-color` is given on the command line.
+    pub fn from_env() -> Self {
+        use std::io::IsTerminal;
+
+        let color = std::env::var_os(NO_COLOR_ENV).is_none()
+            && !no_color_arg(std::env::args())
+            && std::io::stdout().is_terminal();
+
+        CalendarOptions {
+            color,
+            ..Self::default()
+        }
+    }
+
+    /// Plain output, without colors.
+    pub fn no_color(self) -> Self {
+        CalendarOptions {
+            color: false,
+            ..self
+        }
+    }
+
+    fn paint(&self, content: &str, color: &str) -> String {
+        let content = format!("{:<width$}", content, width = CELL);
+
+        if self.color {
+            format!("{}{}{}", color, content, RESET)
+        } else {
+            content
+        }
+    }
+}
+
+/// Whether `--no-color` is among the command line `args`.
+///
+/// # Example
+/// ```
+/// use aoc::report::calendar::no_color_arg;
+///
+/// assert!(no_color_arg(["day07", "--no-color"].map(String::from)));
+/// assert!(!no_color_arg(["day07", "--input", "foo.txt"].map(String::from)));
+/// ```
+pub fn no_color_arg<I: IntoIterator<Item = String>>(args: I) -> bool {
+    args.into_iter().skip(1).any(|arg| arg == NO_COLOR_ARG)
+}
+
+/// Renders the calendar of `season`, followed by a legend.
+pub fn render(season: &Season, options: &CalendarOptions) -> String {
+    let mut out = String::new();
+    let border = |left: &str, middle: &str, right: &str| {
+        let line = vec!["─".repeat(CELL + 2); 5].join(middle);
+        format!("{}{}{}\n", left, line, right)
+    };
+
+    match season.year {
+        Some(year) => out.push_str(&format!("Advent of Code {}\n", year)),
+        None => out.push_str("Advent of Code\n"),
+    }
+    out.push_str(&border("┌", "┬", "┐"));
+
+    for week in 0..5u8 {
+        if week > 0 {
+            out.push_str(&border("├", "┼", "┤"));
+        }
+
+        let cells: Vec<_> = (1..=5)
+            .map(|n| cell(season.get(week * 5 + n), week * 5 + n, options))
+            .collect();
+        for line in 0..2 {
+            out.push('│');
+            for cell in &cells {
+                let _ = write!(out, " {} │", cell[line]);
+            }
+            out.push('\n');
+        }
+    }
+    out.push_str(&border("└", "┴", "┘"));
+
+    let _ = writeln!(out, "★★ both parts  ★ part 1 only  · not solved");
+    let _ = writeln!(
+        out,
+        "Time: {} < {} <= {} < {} <= {}",
+        legend("fast", GREEN, options),
+        short_duration(options.fast),
+        legend("medium", YELLOW, options),
+        short_duration(options.slow),
+        legend("slow", RED, options),
+    );
+
+    out
+}
+
+/// Renders the cell of the day of `view` alone, like in the calendar of its season.
+pub fn render_day(view: &ResultView, options: &CalendarOptions) -> String {
+    let border = "─".repeat(CELL + 2);
+    let [stars, time] = cell(Some(view), view.day, options);
+
+    format!("┌{0}┐\n│ {1} │\n│ {2} │\n└{0}┘\n", border, stars, time)
+}
+
+/// The two lines of the cell of `day`, solved with `view`: its stars, then its run time.
+fn cell(view: Option<&ResultView>, day: u8, options: &CalendarOptions) -> [String; 2] {
+    let Some(view) = view else {
+        return [
+            options.paint(&format!("{:>2} ·", day), DIM),
+            options.paint("", DIM),
+        ];
+    };
+
+    let stars = match (&view.part1, &view.part2) {
+        (Some(_), Some(_)) => "★★",
+        (Some(_), None) | (None, Some(_)) => "★",
+        (None, None) => "·",
+    };
+    let time = view.total_duration();
+    let color = if time < options.fast {
+        GREEN
+    } else if time < options.slow {
+        YELLOW
+    } else {
+        RED
+    };
+
+    [
+        options.paint(&format!("{:>2} {}", day, stars), color),
+        options.paint(&short_duration(time), color),
+    ]
+}
+
+fn legend(label: &str, color: &str, options: &CalendarOptions) -> String {
+    if options.color {
+        format!("{}{}{}", color, label, RESET)
+    } else {
+        label.to_owned()
+    }
+}
+
+/// Duration in at most 5 characters: `850µs`, `12ms`, `2.5s`, `42s`, `3m`.
+fn short_duration(duration: Duration) -> String {
+    if duration < Duration::from_millis(1) {
+        format!("{}µs", duration.as_micros())
+    } else if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else if duration < Duration::from_secs(10) {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else if duration < Duration::from_secs(1000) {
+        format!("{}s", duration.as_secs())
+    } else {
+        format!("{}m", duration.as_secs() / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u8, parts: u8, millis: u64) -> ResultView {
+        ResultView {
+            title: "",
+            day,
+            part1: (parts >= 1).then(|| "1".to_owned()),
+            part2: (parts >= 2).then(|| "2".to_owned()),
+            parse_duration: Duration::ZERO,
+            part1_duration: Duration::from_millis(millis),
+            part2_duration: Duration::ZERO,
+            verified: Default::default(),
+            output: String::new(),
+            submitted: (None, None),
+            named: (None, None),
+        }
+    }
+
+    fn season() -> Season {
+        let mut season = Season::new(Some(2022));
+        season.insert(day(1, 2, 12));
+        season.insert(day(2, 1, 500));
+        season.insert(day(7, 2, 2_500));
+        season.insert(day(25, 2, 42_000));
+
+        season
+    }
+
+    #[test]
+    fn plain_calendar() {
+        let rendered = render(&season(), &CalendarOptions::default().no_color());
+
+        assert_eq!(
+            rendered,
+            [
+                "Advent of Code 2022",
+                "┌───────┬───────┬───────┬───────┬───────┐",
+                "│  1 ★★ │  2 ★  │  3 ·  │  4 ·  │  5 ·  │",
+                "│ 12ms  │ 500ms │       │       │       │",
+                "├───────┼───────┼───────┼───────┼───────┤",
+                "│  6 ·  │  7 ★★ │  8 ·  │  9 ·  │ 10 ·  │",
+                "│       │ 2.5s  │       │       │       │",
+                "├───────┼───────┼───────┼───────┼───────┤",
+                "│ 11 ·  │ 12 ·  │ 13 ·  │ 14 ·  │ 15 ·  │",
+                "│       │       │       │       │       │",
+                "├───────┼───────┼───────┼───────┼───────┤",
+                "│ 16 ·  │ 17 ·  │ 18 ·  │ 19 ·  │ 20 ·  │",
+                "│       │       │       │       │       │",
+                "├───────┼───────┼───────┼───────┼───────┤",
+                "│ 21 ·  │ 22 ·  │ 23 ·  │ 24 ·  │ 25 ★★ │",
+                "│       │       │       │       │ 42s   │",
+                "└───────┴───────┴───────┴───────┴───────┘",
+                "★★ both parts  ★ part 1 only  · not solved",
+                "Time: fast < 100ms <= medium < 1.0s <= slow",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn colored_calendar() {
+        let rendered = render(&season(), &CalendarOptions::default());
+
+        assert!(rendered
+            .contains("│ \x1b[32m 1 ★★\x1b[0m │ \x1b[33m 2 ★ \x1b[0m │ \x1b[2m 3 · \x1b[0m │"));
+        assert!(rendered.contains("\x1b[31m42s  \x1b[0m"));
+        assert!(rendered.contains("\x1b[32mfast\x1b[0m"));
+    }
+
+    #[test]
+    fn single_day() {
+        assert_eq!(
+            render_day(&day(7, 1, 12), &CalendarOptions::default().no_color()),
+            ["┌───────┐", "│  7 ★  │", "│ 12ms  │", "└───────┘", ""].join("\n")
+        );
+    }
+
+    #[test]
+    fn no_color_flag() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert!(no_color_arg(args(&[
+            "day07",
+            "--input",
+            "in.txt",
+            "--no-color"
+        ])));
+        assert!(!no_color_arg(args(&["day07"])));
+        assert!(
+            !no_color_arg(args(&["--no-color"])),
+            "the program name is not a flag"
+        );
+    }
+
+    #[test]
+    fn short_durations() {
+        assert_eq!(short_duration(Duration::from_micros(850)), "850µs");
+        assert_eq!(short_duration(Duration::from_millis(999)), "999ms");
+        assert_eq!(short_duration(Duration::from_millis(2_540)), "2.5s");
+        assert_eq!(short_duration(Duration::from_secs(999)), "999s");
+        assert_eq!(short_duration(Duration::from_secs(3_600)), "60m");
+    }
+}
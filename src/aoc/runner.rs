@@ -0,0 +1,867 @@
+//! Running several days at once
+//!
+//! Use the [crate::run_all!] macro, or [crate::run_all_par!] with the `rayon` feature,
+//! rather than calling this module directly.
+//!
+//! Days with a [Solution::YEAR] that are not unlocked yet are skipped rather than failing on
+//! their missing input. Pass `--include-locked` or set `AOC_INCLUDE_LOCKED=1` to run them anyway.
+//!
+//! With `AOC_FORMAT=calendar`, the days are not printed one by one: the calendar of the season
+//! is printed after the summary instead. (See [crate::report::calendar])
+//!
+//! To check a whole season in CI, [crate::run_season!] sums the days up in a [SeasonOutcome]
+//! whose [SeasonOutcome::into_exit_code] fails when a day is wrong, broken or unsolved.
+
+use std::fmt;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use humantime::format_duration;
+
+use crate::answers::Verification;
+use crate::output::{self, AnswerDisplay, Format, ResultView};
+use crate::report::calendar::{self, CalendarOptions};
+use crate::report::Season;
+use crate::solution::{PartStatus, Result, SolutionError, SolutionResult};
+use crate::{logging, time, Solution};
+
+/// Environment variable running the days that are not unlocked yet when set to `1`.
+/// Same as the [INCLUDE_LOCKED_ARG] command line flag.
+pub const INCLUDE_LOCKED_ENV: &str = "AOC_INCLUDE_LOCKED";
+
+/// Command line flag running the days that are not unlocked yet.
+pub const INCLUDE_LOCKED_ARG: &str = "--include-locked";
+
+/// Environment variable accepting the days without an answer in a [SeasonOutcome] when set
+/// to `1`, eg: early in December. Same as the [ALLOW_INCOMPLETE_ARG] command line flag.
+pub const ALLOW_INCOMPLETE_ENV: &str = "AOC_ALLOW_INCOMPLETE";
+
+/// Command line flag accepting the days without an answer in a [SeasonOutcome].
+pub const ALLOW_INCOMPLETE_ARG: &str = "--allow-incomplete";
+
+/// Why a day was not run. See [SolutionError::Skipped]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The puzzle of the day is not unlocked yet. See [unlock_time]
+    NotYetUnlocked,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::NotYetUnlocked => write!(f, "not unlocked yet"),
+        }
+    }
+}
+
+/// Unlock instant of a puzzle: midnight US Eastern time on `day` of December.
+///
+/// Advent of Code runs in December, when US Eastern time is always EST, so the unlock is
+/// computed as 05:00 UTC without a time zone database.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // Days since the epoch of December 1st, from Howard Hinnant's days_from_civil.
+    let year = year as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = 275; // March 1st to December 1st
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let december = era * 146_097 + day_of_era - 719_468;
+
+    let days = (december + day as i64 - 1) as u64;
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + 5 * 3_600)
+}
+
+/// Whether the days that are not unlocked yet are run: [INCLUDE_LOCKED_ARG] is on the command
+/// line or [INCLUDE_LOCKED_ENV] is set to `1`.
+pub fn include_locked() -> bool {
+    std::env::args().any(|arg| arg == INCLUDE_LOCKED_ARG)
+        || std::env::var(INCLUDE_LOCKED_ENV).is_ok_and(|value| value.trim() == "1")
+}
+
+/// Whether the days without an answer are accepted: [ALLOW_INCOMPLETE_ARG] is on the command
+/// line or [ALLOW_INCOMPLETE_ENV] is set to `1`.
+pub fn allow_incomplete() -> bool {
+    std::env::args().any(|arg| arg == ALLOW_INCOMPLETE_ARG)
+        || std::env::var(ALLOW_INCOMPLETE_ENV).is_ok_and(|value| value.trim() == "1")
+}
+
+/// Type-erased day, as given to [run_all] and [run_all_par].
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub year: Option<u16>,
+    /// The [Solution::PART1] and [Solution::PART2] of the day.
+    pub parts: (PartStatus, PartStatus),
+    run: fn() -> Result<ResultView>,
+    run_captured: fn() -> Result<ResultView>,
+}
+
+impl Day {
+    /// Erases the type of a [Solution]. Its [Solution::run_par] is used to run it,
+    /// or [Solution::run_captured] by [run_all_par].
+    pub fn of<S>() -> Self
+    where
+        S: Solution,
+        S::P1: AnswerDisplay,
+        S::P2: AnswerDisplay,
+    {
+        fn run<S>() -> Result<ResultView>
+        where
+            S: Solution,
+            S::P1: AnswerDisplay,
+            S::P2: AnswerDisplay,
+        {
+            S::run_par()
+                .and_then(SolutionResult::checked)
+                .map(|result| result.view())
+        }
+
+        fn run_captured<S>() -> Result<ResultView>
+        where
+            S: Solution,
+            S::P1: AnswerDisplay,
+            S::P2: AnswerDisplay,
+        {
+            S::run_captured()
+                .and_then(SolutionResult::checked)
+                .map(|result| result.view())
+        }
+
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            year: S::YEAR,
+            parts: (S::PART1, S::PART2),
+            run: run::<S>,
+            run_captured: run_captured::<S>,
+        }
+    }
+
+    /// Runs the day.
+    pub fn run(&self) -> Result<ResultView> {
+        (self.run)()
+    }
+
+    /// Runs the day, capturing what it prints. See [Solution::run_captured]
+    pub fn run_captured(&self) -> Result<ResultView> {
+        (self.run_captured)()
+    }
+
+    /// Whether the puzzle of the day is still locked at `now`.
+    /// Days without a [Solution::YEAR] are never locked.
+    pub fn is_locked(&self, now: SystemTime) -> bool {
+        self.year
+            .is_some_and(|year| now < unlock_time(year, self.day))
+    }
+
+    /// Runs the day with `run`, unless it is still locked at `now`.
+    fn run_unless_locked(
+        &self,
+        now: SystemTime,
+        include_locked: bool,
+        run: fn(&Day) -> Result<ResultView>,
+    ) -> Result<ResultView> {
+        if !include_locked && self.is_locked(now) {
+            Err(SolutionError::Skipped(SkipReason::NotYetUnlocked))
+        } else {
+            run(self)
+        }
+    }
+}
+
+/// Prints a day's result the same way [crate::solution!] does.
+fn print(day: &Day, result: &Result<ResultView>) {
+    match result {
+        Ok(view) => {
+            print!("{}", view.output);
+
+            if calendar_format() {
+                return;
+            }
+            if let Err(e) = output::emit(view) {
+                eprintln!("{}", e);
+                println!("{}", view)
+            }
+        }
+        Err(SolutionError::Skipped(reason)) => {
+            logging::info(format_args!("Day {} - skipped: {}", day.day, reason))
+        }
+        Err(e) => crate::error::print_day_report(day.day, day.year, day.title, e),
+    }
+}
+
+fn calendar_format() -> bool {
+    matches!(Format::from_env(), Ok(Format::Calendar))
+}
+
+/// Prints the calendar of the season with `AOC_FORMAT=calendar`.
+fn print_calendar(days: &[Day], results: &[Result<ResultView>]) {
+    if calendar_format() {
+        let year = days.iter().find_map(|day| day.year);
+        let season = Season::from_results(year, results);
+
+        print!(
+            "\n{}",
+            calendar::render(&season, &CalendarOptions::from_env())
+        );
+    }
+}
+
+/// Appends the results to the job summary in GitHub Actions, with a row per failed day.
+/// The days not unlocked yet are left out. See [output::append_github_summary]
+fn github_summary(days: &[Day], results: &[Result<ResultView>]) {
+    let rows = days
+        .iter()
+        .zip(results)
+        .filter_map(|(day, result)| match result {
+            Ok(view) => Some(view.to_github_summary()),
+            Err(SolutionError::Skipped(_)) => None,
+            Err(e) => Some(output::github_summary_error(day.day, day.title, e)),
+        });
+
+    if let Err(e) = output::append_github_summary(rows) {
+        logging::warn(format_args!(
+            "Note: the GitHub job summary was not written: {}",
+            e
+        ));
+    }
+}
+
+fn print_summary(results: &[Result<ResultView>], wall_clock: Duration) {
+    let solved: Vec<_> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+    let skipped = results
+        .iter()
+        .filter(|r| matches!(r, Err(SolutionError::Skipped(_))))
+        .count();
+    let cumulated: Duration = solved
+        .iter()
+        .map(|view| view.parse_duration + view.part1_duration + view.part2_duration)
+        .sum();
+
+    println!(
+        "\n{} days solved out of {}\nCumulated Time:\t{}\nWall Clock:\t{}",
+        solved.len(),
+        results.len() - skipped,
+        format_duration(cumulated),
+        format_duration(wall_clock),
+    );
+    if skipped > 0 {
+        println!("Skipped:\t{} days not unlocked yet", skipped);
+    }
+}
+
+/// Runs each day one after the other, printing their result as soon as they are solved.
+///
+/// Returns the results in the given order. Locked days are skipped (See [run_all_at])
+pub fn run_all(days: &[Day]) -> Vec<Result<ResultView>> {
+    run_all_at(days, SystemTime::now(), include_locked())
+}
+
+/// [run_all] as of `now`: the days still locked at `now` are not run, and their result is
+/// [SolutionError::Skipped], unless `include_locked` is set.
+pub fn run_all_at(days: &[Day], now: SystemTime, include_locked: bool) -> Vec<Result<ResultView>> {
+    let (results, wall_clock) = time!(days
+        .iter()
+        .map(|day| {
+            let result = day.run_unless_locked(now, include_locked, Day::run);
+            print(day, &result);
+            result
+        })
+        .collect::<Vec<_>>());
+
+    print_summary(&results, wall_clock);
+    print_calendar(days, &results);
+    github_summary(days, &results);
+
+    results
+}
+
+/// Runs the days concurrently on rayon's thread pool, then prints their results in day order.
+///
+/// What each day prints with [crate::println!] is captured and printed along with its result.
+/// (See [crate::capture])
+///
+/// Returns the results sorted by day. Locked days are skipped (See [run_all_par_at])
+#[cfg(feature = "rayon")]
+pub fn run_all_par(days: &[Day]) -> Vec<Result<ResultView>> {
+    run_all_par_at(days, SystemTime::now(), include_locked())
+}
+
+/// [run_all_par] as of `now`. See [run_all_at]
+#[cfg(feature = "rayon")]
+pub fn run_all_par_at(
+    days: &[Day],
+    now: SystemTime,
+    include_locked: bool,
+) -> Vec<Result<ResultView>> {
+    use rayon::prelude::*;
+
+    let mut days = days.to_vec();
+    days.sort_by_key(|day| day.day);
+
+    let (results, wall_clock) = time!(days
+        .par_iter()
+        .map(|day| day.run_unless_locked(now, include_locked, Day::run_captured))
+        .collect::<Vec<_>>());
+
+    for (day, result) in days.iter().zip(&results) {
+        print(day, result);
+    }
+    print_summary(&results, wall_clock);
+    print_calendar(&days, &results);
+    github_summary(&days, &results);
+
+    results
+}
+
+/// Status of a day in a [SeasonOutcome].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayStatus {
+    /// Both parts have an answer, unless [PartStatus::Skipped], and no known answer contradicts
+    /// them.
+    Ok,
+    /// A part that is not [PartStatus::Skipped] has no answer.
+    MissingAnswer,
+    /// An answer doesn't match the known one. (See [crate::answers])
+    VerificationFailed,
+    /// The day failed to run.
+    Error,
+    /// The day was not run, eg: it is not unlocked yet.
+    Skipped,
+}
+
+/// How a day of a season went. See [SeasonOutcome]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayOutcome {
+    pub day: u8,
+    pub title: &'static str,
+    pub status: DayStatus,
+    /// What went wrong, for the days that are not [DayStatus::Ok].
+    pub message: Option<String>,
+}
+
+impl DayOutcome {
+    fn new(day: &Day, result: &Result<ResultView>) -> Self {
+        let (status, message) = match result {
+            Ok(view) => Self::check(day, view),
+            Err(SolutionError::Skipped(reason)) => (DayStatus::Skipped, Some(reason.to_string())),
+            Err(e @ SolutionError::WrongAnswer { .. }) => {
+                (DayStatus::VerificationFailed, Some(e.to_string()))
+            }
+            Err(e @ SolutionError::MissingAnswer { .. }) => {
+                (DayStatus::MissingAnswer, Some(e.to_string()))
+            }
+            Err(e) => (DayStatus::Error, Some(e.to_string())),
+        };
+
+        DayOutcome {
+            day: day.day,
+            title: day.title,
+            status,
+            message,
+        }
+    }
+
+    fn check(day: &Day, view: &ResultView) -> (DayStatus, Option<String>) {
+        let parts = [
+            (1, &view.part1, &view.verified.0, day.parts.0),
+            (2, &view.part2, &view.verified.1, day.parts.1),
+        ];
+
+        for (part, answer, verified, _) in parts {
+            if let Verification::Mismatch { expected } = verified {
+                let message = format!(
+                    "wrong answer for part {}: got {}, expected {}",
+                    part,
+                    answer.as_deref().unwrap_or("no answer"),
+                    expected.as_deref().unwrap_or("another answer")
+                );
+                return (DayStatus::VerificationFailed, Some(message));
+            }
+        }
+        for (part, answer, _, status) in parts {
+            if answer.is_none() && status != PartStatus::Skipped {
+                let message = format!("no answer for part {}", part);
+                return (DayStatus::MissingAnswer, Some(message));
+            }
+        }
+
+        (DayStatus::Ok, None)
+    }
+
+    /// Whether the day fails the season.
+    fn fails(&self, allow_incomplete: bool) -> bool {
+        match self.status {
+            DayStatus::Ok | DayStatus::Skipped => false,
+            DayStatus::MissingAnswer => !allow_incomplete,
+            DayStatus::VerificationFailed | DayStatus::Error => true,
+        }
+    }
+}
+
+impl fmt::Display for DayOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} - {:?}", self.day, self.title)?;
+        match &self.message {
+            Some(message) => write!(f, ": {}", message),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Machine-checkable outcome of a season run with [crate::run_season!].
+///
+/// Its [fmt::Display] lists the failed days, and the incomplete ones when they are allowed.
+///
+/// # Example
+/// ```no_run
+/// use std::process::ExitCode;
+///
+///# use aoc::Solution;
+///# use aoc::solution::Result;
+///# struct Day01;
+///# impl Solution for Day01 {
+///#     const TITLE: &'static str = "";const DAY: u8 = 1;
+///#     type Input = (); type P1 = usize; type P2 = usize;
+///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(1) }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+///# }
+///# type Day02 = Day01;
+/// // cargo run --release -- --allow-incomplete
+/// fn main() -> ExitCode {
+///     aoc::run_season!(Day01, Day02).into_exit_code()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonOutcome {
+    pub days: Vec<DayOutcome>,
+    /// Whether the days without an answer are accepted. (See [allow_incomplete])
+    pub allow_incomplete: bool,
+}
+
+impl SeasonOutcome {
+    /// The outcome of the `results` of [run_all], in the order of `days`.
+    pub fn new(days: &[Day], results: &[Result<ResultView>], allow_incomplete: bool) -> Self {
+        SeasonOutcome {
+            days: days
+                .iter()
+                .zip(results)
+                .map(|(day, result)| DayOutcome::new(day, result))
+                .collect(),
+            allow_incomplete,
+        }
+    }
+
+    /// The days failing the season.
+    pub fn failures(&self) -> impl Iterator<Item = &DayOutcome> {
+        self.days
+            .iter()
+            .filter(|day| day.fails(self.allow_incomplete))
+    }
+
+    /// Whether no day fails the season.
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Success, or `1` when a day fails the season. Skipped days never fail it.
+    pub fn into_exit_code(self) -> ExitCode {
+        if self.is_success() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::from(1)
+        }
+    }
+}
+
+impl fmt::Display for SeasonOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures: Vec<_> = self.failures().collect();
+        let ran = self
+            .days
+            .iter()
+            .filter(|day| day.status != DayStatus::Skipped)
+            .count();
+
+        match failures.len() {
+            0 => write!(f, "All {} days passed", ran)?,
+            failed => {
+                write!(f, "{} of {} days failed:", failed, ran)?;
+                for day in failures {
+                    write!(f, "\n  {}", day)?;
+                }
+            }
+        }
+
+        let incomplete = self
+            .days
+            .iter()
+            .filter(|day| self.allow_incomplete && day.status == DayStatus::MissingAnswer);
+        for (index, day) in incomplete.enumerate() {
+            if index == 0 {
+                write!(f, "\nIncomplete (allowed):")?;
+            }
+            write!(f, "\n  {}", day)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Runs the days with [run_all], then prints the failures of their [SeasonOutcome] on stderr.
+/// Used by [crate::run_season!].
+pub fn run_season(days: &[Day]) -> SeasonOutcome {
+    let outcome = SeasonOutcome::new(days, &run_all(days), allow_incomplete());
+
+    if outcome.is_success() {
+        logging::info(format_args!("{}", outcome));
+    } else {
+        logging::warn(format_args!("\n{}", outcome));
+    }
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::SolutionError;
+
+    macro_rules! fake_day {
+        ($name:ident, $day:expr, $input:expr) => {
+            struct $name;
+            impl Solution for $name {
+                const TITLE: &'static str = stringify!($name);
+                const DAY: u8 = $day;
+                type Input = u32;
+                type P1 = u32;
+                type P2 = u32;
+
+                fn parse(input: &str) -> Result<Self::Input> {
+                    input
+                        .parse()
+                        .map_err(|_| SolutionError::parse("not a number"))
+                }
+
+                fn part1(input: &Self::Input) -> Option<Self::P1> {
+                    Some(input * 10)
+                }
+
+                fn part2(input: &Self::Input) -> Option<Self::P2> {
+                    Some(input * 100)
+                }
+
+                fn get_input() -> Result<String> {
+                    Ok($input.to_owned())
+                }
+            }
+        };
+    }
+
+    fake_day!(First, 1, "1");
+    fake_day!(Second, 2, "2");
+    fake_day!(Broken, 3, "not a number");
+
+    fn answers(results: &[Result<ResultView>]) -> Vec<Option<(String, String)>> {
+        results
+            .iter()
+            .map(|r| {
+                r.as_ref()
+                    .ok()
+                    .map(|v| (v.part1.clone().unwrap(), v.part2.clone().unwrap()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn run_all_keeps_the_given_order() {
+        let results = crate::run_all!(Second, Broken, First);
+
+        assert_eq!(
+            answers(&results),
+            vec![
+                Some(("20".to_owned(), "200".to_owned())),
+                None,
+                Some(("10".to_owned(), "100".to_owned())),
+            ]
+        );
+    }
+
+    struct Chatty;
+    impl Solution for Chatty {
+        const TITLE: &'static str = "Chatty";
+        const DAY: u8 = 4;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            crate::println!("part 1 of {}", input);
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            crate::println!("part 2 of {}", input);
+            Some(*input)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("4".to_owned())
+        }
+    }
+
+    #[test]
+    fn run_captured_keeps_the_output() {
+        let day = Day::of::<Chatty>();
+
+        assert_eq!(
+            day.run_captured().unwrap().output,
+            "part 1 of 4\npart 2 of 4\n"
+        );
+        assert_eq!(Day::of::<First>().run_captured().unwrap().output, "");
+    }
+
+    /// Day `D` of 2022, whose input is only available once unlocked.
+    struct Numbered<const D: u8>;
+    impl<const D: u8> Solution for Numbered<D> {
+        const TITLE: &'static str = "Numbered";
+        const DAY: u8 = D;
+        const YEAR: Option<u16> = Some(2022);
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(*input)
+        }
+
+        fn get_input() -> Result<String> {
+            if D > 10 {
+                return Err(SolutionError::Network("404 Not Found".to_owned()));
+            }
+            Ok(D.to_string())
+        }
+    }
+
+    macro_rules! season {
+        ($($day:literal)*) => {
+            vec![$(Day::of::<Numbered<$day>>()),*]
+        };
+    }
+
+    fn december_10th() -> SystemTime {
+        unlock_time(2022, 10) + Duration::from_secs(3_600)
+    }
+
+    #[test]
+    fn unlock_times() {
+        let unlock = unlock_time(2022, 10);
+
+        assert_eq!(
+            unlock.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            1_670_648_400 // 2022-12-10T05:00:00Z
+        );
+        assert!(Day::of::<Numbered<10>>().is_locked(unlock - Duration::from_secs(1)));
+        assert!(!Day::of::<Numbered<10>>().is_locked(unlock));
+        assert!(!Day::of::<First>().is_locked(UNIX_EPOCH));
+    }
+
+    #[test]
+    fn locked_days_are_skipped() {
+        let days = season!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25);
+
+        let results = run_all_at(&days, december_10th(), false);
+
+        assert_eq!(results.len(), 25);
+        for (day, result) in (1..).zip(&results) {
+            match result {
+                Ok(view) if day <= 10 => assert_eq!(view.part1, Some(day.to_string())),
+                Err(SolutionError::Skipped(SkipReason::NotYetUnlocked)) if day > 10 => {}
+                _ => panic!(
+                    "unexpected result for day {}: {:?}",
+                    day,
+                    result.as_ref().err()
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn locked_days_can_be_included() {
+        let days = season!(10 11);
+
+        let results = run_all_at(&days, december_10th(), true);
+
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(SolutionError::Network(_))));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn run_all_par_skips_locked_days() {
+        let days = season!(12 9 11);
+
+        let results = run_all_par_at(&days, december_10th(), false);
+
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(SolutionError::Skipped(_))));
+        assert!(matches!(results[2], Err(SolutionError::Skipped(_))));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn run_all_par_sorts_by_day() {
+        let results = crate::run_all_par!(Second, Broken, First);
+
+        assert_eq!(
+            answers(&results),
+            vec![
+                Some(("10".to_owned(), "100".to_owned())),
+                Some(("20".to_owned(), "200".to_owned())),
+                None,
+            ]
+        );
+    }
+
+    fake_day!(Healthy, 1, "1");
+    fake_day!(Failing, 3, "not a number");
+
+    struct Answerless;
+    impl Solution for Answerless {
+        const TITLE: &'static str = "Answerless";
+        const DAY: u8 = 2;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("2".to_owned())
+        }
+    }
+
+    /// Has no part 2, like every day 25.
+    struct Christmas;
+    impl Solution for Christmas {
+        const TITLE: &'static str = "Christmas";
+        const DAY: u8 = 25;
+        const PART2: PartStatus = PartStatus::Skipped;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("25".to_owned())
+        }
+    }
+
+    #[test]
+    fn season_outcomes() {
+        let days = [
+            Day::of::<Healthy>(),
+            Day::of::<Answerless>(),
+            Day::of::<Failing>(),
+            Day::of::<Numbered<25>>(),
+        ];
+        let results = run_all_at(&days, december_10th(), false);
+
+        let outcome = SeasonOutcome::new(&days, &results, false);
+        let statuses: Vec<_> = outcome.days.iter().map(|day| day.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                DayStatus::Ok,
+                DayStatus::MissingAnswer,
+                DayStatus::Error,
+                DayStatus::Skipped
+            ]
+        );
+        assert_eq!(
+            outcome.to_string(),
+            "2 of 3 days failed:\n  \
+             Day 2 - \"Answerless\": no answer for part 2\n  \
+             Day 3 - \"Failing\": Invalid Puzzle input: not a number"
+        );
+        assert_eq!(outcome.into_exit_code(), ExitCode::from(1));
+
+        let allowed = SeasonOutcome::new(&days, &results, true);
+        assert_eq!(
+            allowed.to_string(),
+            "1 of 3 days failed:\n  \
+             Day 3 - \"Failing\": Invalid Puzzle input: not a number\n\
+             Incomplete (allowed):\n  \
+             Day 2 - \"Answerless\": no answer for part 2"
+        );
+        assert_eq!(allowed.into_exit_code(), ExitCode::from(1));
+
+        let healthy = SeasonOutcome::new(&days[..2], &results[..2], true);
+        assert_eq!(
+            healthy.to_string(),
+            "All 2 days passed\nIncomplete (allowed):\n  Day 2 - \"Answerless\": no answer for part 2"
+        );
+        assert_eq!(healthy.into_exit_code(), ExitCode::SUCCESS);
+
+        let christmas = [Day::of::<Christmas>()];
+        let outcome = SeasonOutcome::new(&christmas, &[christmas[0].run()], false);
+        assert_eq!(outcome.days[0].status, DayStatus::Ok);
+        assert_eq!(outcome.to_string(), "All 1 days passed");
+    }
+
+    #[test]
+    fn wrong_answers_fail_the_season() {
+        let mut view = Day::of::<Healthy>().run().unwrap();
+        view.verified.1 = Verification::Mismatch {
+            expected: Some("99".to_owned()),
+        };
+
+        let outcome = SeasonOutcome::new(&[Day::of::<Healthy>()], &[Ok(view)], true);
+
+        assert_eq!(outcome.days[0].status, DayStatus::VerificationFailed);
+        assert_eq!(
+            outcome.to_string(),
+            "1 of 1 days failed:\n  Day 1 - \"Healthy\": wrong answer for part 2: got 100, expected 99"
+        );
+        assert!(!outcome.is_success());
+    }
+}
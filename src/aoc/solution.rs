@@ -5,24 +5,357 @@
 //! You usually want to use this module when you need to implement the [Solution] trait.
 //!
 
+use std::any::type_name;
 use std::fmt::{Debug, Display, Formatter};
+use std::io::{BufRead, BufReader, IsTerminal, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
 use humantime::format_duration;
 use thiserror::Error;
 
-use crate::time;
+use crate::answers::{self, ExpectedAnswers, Verification};
+use crate::input::InputKind;
+use crate::output::{AnswerDisplay, DisplayOptions, ResultView};
+use crate::phase::{self, Phase};
+pub use crate::solution_core::Timings;
+use crate::timeline::Timeline;
+use crate::{capture, debug, input, logging};
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum SolutionError {
-    #[error("Invalid Puzzle input")]
-    ParseError,
-    #[error("Missing Puzzle input")]
-    PuzzleInput(#[from] std::io::Error),
+    /// The input doesn't parse, with a message saying why. See [SolutionError::parse]
+    ///
+    /// Converted from the errors of the std parsers (eg: [std::num::ParseIntError]), which are
+    /// kept as the `source`, so `?` can be used in [Solution::parse].
+    #[error("Invalid Puzzle input: {message}")]
+    ParseError {
+        message: String,
+        #[source]
+        source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+    },
+    /// A line of the input that doesn't parse. See [crate::parse::lines]
+    #[error("Invalid Puzzle input at line {line}: {text:?}")]
+    InvalidLine { line: usize, text: String },
+    /// A character of a grid that doesn't parse. See [crate::parse::digit_grid]
+    #[error("Invalid Puzzle input at line {line}, column {column}: {found:?}")]
+    InvalidCell {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// A row of a grid whose width differs from the first row's. See [crate::parse::digit_grid]
+    #[error("Invalid Puzzle input at line {line}: {width} cells wide, expected {expected}")]
+    RaggedRow {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// A block of the input that doesn't parse. See [crate::parse::blocks_with]
+    #[error("Invalid Puzzle input in block {block}: {source}")]
+    InvalidBlock {
+        block: usize,
+        source: Box<SolutionError>,
+    },
+    /// An error at a [crate::parse::Location] of the input. See [crate::parse::Context]
+    #[error("{source} at {location}")]
+    Located {
+        location: crate::parse::Location,
+        source: Box<SolutionError>,
+    },
+    /// An [std::io::Error] reading the input at `path`, shared so the error is [Clone].
+    /// (See [SolutionError::path] and [SolutionError::io_kind])
+    #[error("Missing Puzzle input at {path:?}: {source}")]
+    PuzzleInput {
+        path: PathBuf,
+        source: Arc<std::io::Error>,
+    },
+    #[error("Missing Puzzle input: {0}")]
+    MissingInput(input::MissingInput),
+    #[error("Empty Puzzle input: {path_or_source}")]
+    EmptyInput { path_or_source: String },
+    #[deprecated(note = "reported as SolutionError::Part, which tells which part failed")]
     #[error("Error while running solution")]
     Run,
+    /// A part failed, eg: it panicked in [Solution::run_par]. (See [Panicked])
+    #[error("Error while running part {part}: {source}")]
+    Part {
+        part: u8,
+        source: Arc<dyn std::error::Error + Send + Sync>,
+    },
+    #[error("Request to Advent of Code failed: {0}")]
+    Network(String),
+    #[error("Invalid input path template: {0}")]
+    InputTemplate(String),
+    #[error("Keyring access failed: {0}")]
+    Keyring(String),
+    #[error("Encrypted input: {0}")]
+    Encryption(String),
+    #[error("Wrong answer for part {part} of day {day:02}: got {actual}, expected {expected}")]
+    WrongAnswer {
+        day: u8,
+        part: u8,
+        expected: String,
+        actual: String,
+    },
+    /// The answers changed between the runs of [Solution::run_repeated].
+    #[error("Answers differ between runs: {first} on run 1, {other} on run {run}")]
+    Nondeterministic {
+        run: usize,
+        first: String,
+        other: String,
+    },
+    /// A part has no answer while it is not [PartStatus::Skipped], with the result of the run.
+    /// See [Solution::run_strict]
+    #[error("No answer for part {part} of day {:02}", .partial.day)]
+    MissingAnswer { part: u8, partial: Box<ResultView> },
+    /// The answers were rejected by [Solution::validate_answers].
+    #[error("Answers failed validation: {0}")]
+    InvalidAnswers(String),
+    #[error("Answer not submitted: {0}")]
+    RefusedAnswer(String),
+    #[error("Skipped: {0}")]
+    Skipped(crate::runner::SkipReason),
+    #[error("Input of day {day:02} doesn't match its recorded checksum: recorded sha256 {recorded}, got {actual}")]
+    InputChecksum {
+        day: u8,
+        recorded: String,
+        actual: String,
+    },
+    /// An [std::io::Error] on a file kept by the runner at `path`, eg: the history of
+    /// [crate::bench].
+    #[error("Couldn't access {path:?}: {source}")]
+    Storage {
+        path: PathBuf,
+        source: Arc<std::io::Error>,
+    },
+}
+
+/// Category of a [SolutionError], for the tests and tools that only care about what failed.
+/// (See [SolutionError::kind])
+///
+/// # Example
+/// ```
+/// use aoc::solution::{ErrorKind, SolutionError};
+///
+/// let error = SolutionError::parse("not a number");
+///
+/// assert_eq!(error, ErrorKind::Parse);
+/// assert_ne!(error, ErrorKind::Input);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input doesn't parse.
+    Parse,
+    /// The input can't be found or read, or isn't the expected one.
+    Input,
+    /// A part failed while running.
+    Run,
+    /// The answers are wrong, rejected or not submitted.
+    Answer,
+    /// The website can't be reached, or the session token can't be read.
+    Network,
+    /// The day was not run.
+    Skipped,
+    /// A file kept by the runner can't be read or written.
+    Storage,
+}
+
+impl PartialEq<ErrorKind> for SolutionError {
+    fn eq(&self, kind: &ErrorKind) -> bool {
+        self.kind() == *kind
+    }
+}
+
+impl PartialEq<SolutionError> for ErrorKind {
+    fn eq(&self, error: &SolutionError) -> bool {
+        *self == error.kind()
+    }
+}
+
+impl SolutionError {
+    /// The category of the error. A [SolutionError::Located] error has its source's.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            SolutionError::ParseError { .. }
+            | SolutionError::InvalidLine { .. }
+            | SolutionError::InvalidCell { .. }
+            | SolutionError::RaggedRow { .. }
+            | SolutionError::InvalidBlock { .. } => ErrorKind::Parse,
+            SolutionError::Located { source, .. } => source.kind(),
+            SolutionError::PuzzleInput { .. }
+            | SolutionError::MissingInput(_)
+            | SolutionError::EmptyInput { .. }
+            | SolutionError::InputTemplate(_)
+            | SolutionError::Encryption(_)
+            | SolutionError::InputChecksum { .. } => ErrorKind::Input,
+            #[allow(deprecated)]
+            SolutionError::Run => ErrorKind::Run,
+            SolutionError::Part { .. } | SolutionError::Nondeterministic { .. } => ErrorKind::Run,
+            SolutionError::WrongAnswer { .. }
+            | SolutionError::MissingAnswer { .. }
+            | SolutionError::InvalidAnswers(_)
+            | SolutionError::RefusedAnswer(_) => ErrorKind::Answer,
+            SolutionError::Network(_) | SolutionError::Keyring(_) => ErrorKind::Network,
+            SolutionError::Skipped(_) => ErrorKind::Skipped,
+            SolutionError::Storage { .. } => ErrorKind::Storage,
+        }
+    }
+
+    /// A [SolutionError::ParseError] saying why the input doesn't parse.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::solution::SolutionError;
+    ///
+    /// let error = SolutionError::parse("bad digit");
+    /// assert_eq!(error.to_string(), "Invalid Puzzle input: bad digit");
+    /// ```
+    pub fn parse(message: impl Into<String>) -> Self {
+        SolutionError::ParseError {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// The input file the error is about: the path that failed to be read, or the preferred
+    /// path of a missing input.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            SolutionError::PuzzleInput { path, .. } => Some(path),
+            SolutionError::MissingInput(missing) => missing.attempted.first().map(PathBuf::as_path),
+            _ => None,
+        }
+    }
+
+    /// The kind of the I/O error reading the input, eg: to tell a missing input
+    /// ([std::io::ErrorKind::NotFound]) from an unreadable one.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::ErrorKind;
+    /// use aoc::solution::SolutionError;
+    ///
+    /// let error = SolutionError::from(std::io::Error::from(ErrorKind::PermissionDenied));
+    ///
+    /// assert_eq!(error.io_kind(), Some(ErrorKind::PermissionDenied));
+    /// assert_eq!(SolutionError::parse("not a number").io_kind(), None);
+    /// ```
+    pub fn io_kind(&self) -> Option<std::io::ErrorKind> {
+        match self {
+            SolutionError::PuzzleInput { source, .. } => Some(source.kind()),
+            SolutionError::MissingInput(_) => Some(std::io::ErrorKind::NotFound),
+            _ => None,
+        }
+    }
+
+    /// Where the error is in the input, if known. See [crate::parse::render_diagnostic]
+    ///
+    /// The lines of the errors inside [SolutionError::InvalidBlock] are relative to their block,
+    /// so they have no location.
+    pub fn location(&self) -> Option<crate::parse::Location> {
+        match self {
+            SolutionError::Located { location, .. } => Some(*location),
+            SolutionError::InvalidLine { line, .. } | SolutionError::RaggedRow { line, .. } => {
+                Some(crate::parse::Location::line(*line))
+            }
+            SolutionError::InvalidCell { line, column, .. } => Some(crate::parse::Location {
+                line: *line,
+                column: Some(*column),
+                width: 1,
+            }),
+            _ => None,
+        }
+    }
+
+    /// What to do about the error, printed under it by [crate::solution!] and
+    /// [crate::run_all!].
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            SolutionError::MissingInput(missing) => missing
+                .attempted
+                .first()
+                .map(|path| format!("Create {}", path.display())),
+            SolutionError::PuzzleInput { .. } => {
+                Some("Check that the input can be read".to_owned())
+            }
+            SolutionError::EmptyInput { path_or_source } => {
+                Some(format!("Paste the puzzle input in {}", path_or_source))
+            }
+            SolutionError::ParseError { .. } => {
+                Some("parse() failed: the input was read, check Solution::parse".to_owned())
+            }
+            SolutionError::InvalidLine { line, .. } => Some(format!(
+                "parse() failed on line {}: check the input or Solution::parse",
+                line
+            )),
+            SolutionError::InvalidCell { line, column, .. } => Some(format!(
+                "parse() failed on line {}, column {}: check the input or Solution::parse",
+                line, column
+            )),
+            SolutionError::RaggedRow { line, .. } => Some(format!(
+                "The rows of a grid must have the same width, check line {} of the input",
+                line
+            )),
+            SolutionError::InvalidBlock { block, .. } => Some(format!(
+                "parse() failed on block {}: check the input or Solution::parse",
+                block
+            )),
+            SolutionError::Located { source, .. } => source.hint(),
+            #[allow(deprecated)]
+            SolutionError::Run => {
+                Some("The solver panicked, see the panic message above".to_owned())
+            }
+            SolutionError::Part { part, .. } => Some(format!(
+                "Solution::part{} failed, see the panic message above",
+                part
+            )),
+            SolutionError::Nondeterministic { .. } => Some(
+                "The answers must only depend on the input: look for randomness, HashMap \
+                 iteration order or state kept between runs"
+                    .to_owned(),
+            ),
+            SolutionError::MissingAnswer { part, .. } => Some(format!(
+                "Solution::part{} returned None: solve it, or set PART{} to PartStatus::Skipped",
+                part, part
+            )),
+            SolutionError::InvalidAnswers(_) => Some(
+                "Solution::validate_answers rejected the answers, check part1 and part2".to_owned(),
+            ),
+            SolutionError::Storage { path, .. } => Some(format!(
+                "Check that {} can be read and written",
+                path.display()
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The parsing and solving of every [Solution], without its input files nor its timing.
+impl<S: Solution> crate::solution_core::SolutionCore for S {
+    type Input = S::Input;
+    type P1 = S::P1;
+    type P2 = S::P2;
+    type Error = SolutionError;
+
+    fn parse(input: &str) -> Result<S::Input> {
+        <S as Solution>::parse(input)
+    }
+
+    fn part1(input: &S::Input) -> Option<S::P1> {
+        <S as Solution>::part1(input)
+    }
+
+    fn part2(input: &S::Input) -> Option<S::P2> {
+        <S as Solution>::part2(input)
+    }
 }
 
+#[derive(Clone)]
 pub struct SolutionResult<P1, P2> {
     title: &'static str,
     day: u8,
@@ -31,134 +364,128 @@ pub struct SolutionResult<P1, P2> {
     parse_duration: Duration,
     part1_duration: Duration,
     part2_duration: Duration,
+    verified: (Verification, Verification),
+    expected: ExpectedAnswers,
+    output: String,
+    submitted: (Option<String>, Option<String>),
+}
+
+/// An [std::io::Error] at an [input::UNKNOWN_PATH]. The input readers of [crate::input] report
+/// the path.
+impl From<std::io::Error> for SolutionError {
+    fn from(e: std::io::Error) -> Self {
+        SolutionError::PuzzleInput {
+            path: PathBuf::from(input::UNKNOWN_PATH),
+            source: Arc::new(e),
+        }
+    }
+}
+
+/// Conversions from the errors of the std parsers into a [SolutionError::ParseError] that
+/// keeps them as its source.
+macro_rules! impl_from_parse_error {
+    ($($error:ty),*) => {$(
+        impl From<$error> for SolutionError {
+            fn from(e: $error) -> Self {
+                SolutionError::ParseError {
+                    message: e.to_string(),
+                    source: Some(Arc::new(e)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_parse_error!(
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::char::CharTryFromError,
+    std::str::Utf8Error
+);
+
+impl From<std::convert::Infallible> for SolutionError {
+    fn from(e: std::convert::Infallible) -> Self {
+        match e {}
+    }
 }
 
 pub type Result<T> = std::result::Result<T, SolutionError>;
 
-impl<P1: Display, P2: Display> Display for SolutionResult<P1, P2> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let heading = {
-            let title = format!("Day {:02}: {:?}", self.day, self.title,);
-            let sep: String = (0..=(title.len() + 1)).map(|_| '=').collect();
+/// The panic of a part, as the source of a [SolutionError::Part].
+#[derive(Debug, Clone, Error)]
+#[error("panicked: {0}")]
+pub struct Panicked(pub String);
 
-            format!("{}\n {}\n{}", sep, title, sep)
-        };
+impl Panicked {
+    /// The message of a panic caught with [std::panic::catch_unwind].
+    pub fn from_payload(payload: &(dyn std::any::Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_owned());
 
-        match (&self.part1, &self.part2) {
-            (Some(p1), Some(p2)) => {
-                write!(
-                    f,
-                   "{}\nPart 1: '{}'\nPart 2: '{}'\n----\nTime1:\t\t{}\nTime2:\t\t{}\nParse Time:\t{}\nTotal Time:\t{}",
-                   heading,
-                    p1,
-                    p2,
-                   format_duration(self.part1_duration),
-                   format_duration(self.part2_duration),
-                   format_duration(self.parse_duration),
-                   format_duration(self.part1_duration + self.part2_duration + self.parse_duration),
-                )
-            }
-            (Some(p1), _) => {
-                write!(
-                    f,
-                    "{}\nPart 1: '{}'\n----\nTime1:\t\t{}\nParse Time:\t{}\nTotal Time:\t{}",
-                    heading,
-                    p1,
-                    format_duration(self.part1_duration),
-                    format_duration(self.parse_duration),
-                    format_duration(self.part1_duration + self.parse_duration),
-                )
-            }
-            _ => {
-                write!(
-                    f,
-                    "{}\n  {}\tParsing time",
-                    heading,
-                    format_duration(self.parse_duration),
-                )
-            }
+        Panicked(message)
+    }
+
+    /// The [SolutionError::Part] of the given `part`.
+    pub(crate) fn in_part(payload: Box<dyn std::any::Any + Send>, part: u8) -> SolutionError {
+        SolutionError::Part {
+            part,
+            source: Arc::new(Panicked::from_payload(payload.as_ref())),
         }
     }
 }
 
-/// Main trait for Advent of Code Daily challenges.
-///
-/// This trait includes a generic runner part, a utility part and methods to implement each day.
-///
-/// ### The runner part includes:
-/// - [Solution::test_part1]
-/// - [Solution::test_part2]
-/// - [Solution::run]
-/// - [Solution::run_par]
-///
-/// Those associated methods are implemented by default and are intended to be used as is.
-///
-/// ### The utility part includes:
-/// - [Solution:get_input]
-///
-/// The utility part comes pre define but can be overwritten if one chooses to.
-///
-/// ### The methods to be implemented each day are:
-/// - [Solution::TITLE] - used for displaying the solution
-/// - [Solution::DAY] - used by [Solution::get_input]'s default implementation
-/// - [Solution::parse] - pre process the puzzle input for the other parts
-/// - [Solution::part1] - solution for part 1
-/// - [Solution::part2] - solution for part 2
-///
-/// # Example
-/// ```
-/// use aoc::Solution;
-/// use aoc::solution::Result;
-/// struct DayXX;
-///
-/// impl Solution for DayXX {
-///     const TITLE: &'static str = "";
-///     const DAY: u8 = 0;
-///     
-///     type Input = ();
-///     type P1 = ();
-///     type P2 = ();
-///
-///     fn parse(input: &str) -> Result<Self::Input> {
-///         Ok(())
-///     }
-///
-///     fn part1(input: &Self::Input) -> Option<Self::P1> {
-///         None
-///     }
+/// Answer of a part that is not solved yet: the `P2` of an [crate::implement!] without `part_2`.
 ///
-///     fn part2(input: &Self::Input) -> Option<Self::P2> {
-///         None
-///     }
-///
-///#     fn get_input() -> Result<String> {
-///#         Ok("".to_owned())
-///#     }
-/// }
-///
-/// // create a file `inputs/DAY_00.txt` then:
-/// DayXX::run().expect("Day should run");
-/// ```
-///
-pub trait Solution {
-    const TITLE: &'static str;
-    const DAY: u8;
+/// It has no value, so the part's answer is always `None`. Unlike `()`, it can be displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unsolved {}
 
-    /// Puzzle input type.
-    /// it's the output value of [Solution::parse]
-    /// and is consumed by [Solution::part1] and [Solution::part2]
-    type Input: Sync;
+impl Display for Unsolved {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
 
-    /// Part 1 Solution type.
-    /// it's the output value of [Solution::part1]
-    type P1: Send + Debug;
+/// Whether a part is expected to have an answer. See [Solution::PART1] and [Solution::PART2]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PartStatus {
+    /// The part is solved: [Solution::run_strict] reports a `None` answer.
+    #[default]
+    Implemented,
+    /// The part has no answer on purpose, eg: the part 2 of day 25.
+    Skipped,
+}
 
-    /// Part 2 Solution type.
-    /// it's the output value of [Solution::part2]
-    type P2: Send + Debug;
+/// Environment variable silencing [Solution::test_part1] and [Solution::test_part2] when set to `1`.
+pub const QUIET_ENV: &str = "AOC_QUIET";
 
-    /// Takes the puzzle input as &str and parses it to something more flexible
-    /// to solve the exercises.
+fn quiet() -> bool {
+    static QUIET: OnceLock<bool> = OnceLock::new();
+
+    *QUIET.get_or_init(|| is_quiet(std::env::var(QUIET_ENV).ok().as_deref()))
+}
+
+fn is_quiet(value: Option<&str>) -> bool {
+    matches!(value.map(str::trim), Some("1") | Some("true"))
+}
+
+fn single_cpu() -> bool {
+    static SINGLE_CPU: OnceLock<bool> = OnceLock::new();
+
+    *SINGLE_CPU.get_or_init(|| is_single_cpu(std::thread::available_parallelism().ok()))
+}
+
+fn is_single_cpu(parallelism: Option<NonZeroUsize>) -> bool {
+    parallelism.is_some_and(|cpus| cpus.get() == 1)
+}
+
+impl<P1, P2> SolutionResult<P1, P2> {
+    /// Records whether the part 1 and part 2 answers are known to be correct.
+    ///
+    /// Verified answers are displayed with a ✓ (correct) or a ✗ (incorrect) marker.
     ///
     /// # Example
     /// ```
@@ -167,35 +494,63 @@ pub trait Solution {
     ///
     /// struct DayXX;
     /// impl Solution for DayXX {
-    ///     //--snip--
-    ///
+    ///     // -- snip --
     ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///     type Input = Vec<usize>;
-    ///#     type P1 = usize; type P2 = usize;
-    ///
-    ///     fn parse(input: &str) -> Result<Self::Input> {
-    ///         Ok(input
-    ///             .lines()
-    ///             .filter_map(|line| line.parse().ok())
-    ///             .collect())
-    ///      }
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
     ///#
     ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///#         Some(0)
+    ///#         Some(24000)
     ///#     }
     ///#
     ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
-    ///#         Some(0)
+    ///#         Some(45000)
+    ///#     }
+    ///#
+    ///#     fn get_input() -> Result<String> {
+    ///#         Ok("input".to_owned())
     ///#     }
     /// }
-    /// ```
     ///
-    fn parse(input: &str) -> Result<Self::Input>;
-
-    /// Takes the [Solution::parse]'s output and return the solution for part 1
+    /// let result = DayXX::run().expect("Day should run");
+    /// let verified = result.with_verified(true, false);
     ///
-    /// You must implement this method. If the method cannot be implemented,
-    /// return None as a placeholder
+    /// assert_eq!(verified.verified(), Some((true, false)));
+    /// assert!(verified.to_string().contains("Part 1: '24000' ✓"));
+    /// assert!(verified.to_string().contains("Part 2: '45000' ✗"));
+    /// ```
+    pub fn with_verified(mut self, part1: bool, part2: bool) -> Self {
+        let verification = |correct| match correct {
+            true => Verification::Verified,
+            false => Verification::Mismatch { expected: None },
+        };
+
+        self.verified = (verification(part1), verification(part2));
+        self
+    }
+
+    /// Known answers of the day, loaded by [Solution::run] from `answers/DAY_XX.txt`.
+    /// (See [crate::answers])
+    pub fn expected(&self) -> &ExpectedAnswers {
+        &self.expected
+    }
+
+    /// What the day printed with [crate::println!], when run with [Solution::run_captured].
+    /// Empty otherwise.
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Outcomes of the part 1 and part 2 submissions, when run with
+    /// [Solution::run_and_submit_all]. `None` for a part that wasn't submitted.
+    pub fn submitted(&self) -> (Option<&str>, Option<&str>) {
+        (self.submitted.0.as_deref(), self.submitted.1.as_deref())
+    }
+
+    /// Moves the answers out of the result, along with the durations of its phases.
     ///
     /// # Example
     /// ```
@@ -204,258 +559,553 @@ pub trait Solution {
     ///
     /// struct DayXX;
     /// impl Solution for DayXX {
-    ///     //-- snip --
+    ///     // -- snip --
     ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = ();type P1 = (); type P2 = ();
+    ///#     type Input = ();type P1 = String; type P2 = usize;
     ///#
     ///#     fn parse(input: &str) -> Result<Self::Input> {
     ///#         Ok(())
     ///#         }
     ///#
-    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///         None
-    ///     }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some("CMZ".to_owned())
+    ///#     }
     ///#
     ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
     ///#         None
     ///#     }
-    /// }    
-    ///```
+    ///#
+    ///#     fn get_input() -> Result<String> {
+    ///#         Ok("input".to_owned())
+    ///#     }
+    /// }
     ///
-    fn part1(input: &Self::Input) -> Option<Self::P1>;
-    /// Takes the [Solution::parse]'s output and return the solution for part 2
+    /// let (part1, part2, timings) = DayXX::run().expect("Day should run").into_parts();
+    ///
+    /// assert_eq!(part1, Some("CMZ".to_owned()));
+    /// assert_eq!(part2, None);
+    /// assert_eq!(timings.total(), timings.parse + timings.part1 + timings.part2);
+    /// ```
+    pub fn into_parts(self) -> (Option<P1>, Option<P2>, Timings) {
+        let [parse, part1, part2] = self.durations();
+
+        (
+            self.part1,
+            self.part2,
+            Timings {
+                parse,
+                part1,
+                part2,
+            },
+        )
+    }
+
+    /// Parse, part 1 and part 2 durations.
+    fn durations(&self) -> [Duration; 3] {
+        [
+            self.parse_duration,
+            self.part1_duration,
+            self.part2_duration,
+        ]
+    }
+}
+
+impl<P1: AnswerDisplay, P2: AnswerDisplay> SolutionResult<P1, P2> {
+    /// Type-erased copy of this result, used by the [crate::output] formats.
+    ///
+    /// Answers are formatted with [AnswerDisplay].
+    pub fn view(&self) -> ResultView {
+        ResultView {
+            title: self.title,
+            day: self.day,
+            part1: self.part1.as_ref().map(AnswerDisplay::answer_string),
+            part2: self.part2.as_ref().map(AnswerDisplay::answer_string),
+            parse_duration: self.parse_duration,
+            part1_duration: self.part1_duration,
+            part2_duration: self.part2_duration,
+            verified: self.verification(),
+            output: self.output.clone(),
+            submitted: self.submitted.clone(),
+            named: (
+                self.part1.as_ref().and_then(AnswerDisplay::named_string),
+                self.part2.as_ref().and_then(AnswerDisplay::named_string),
+            ),
+        }
+    }
+
+    /// Whether the part 1 and part 2 answers are known to be correct, if recorded for both.
+    pub fn verified(&self) -> Option<(bool, bool)> {
+        match self.verification() {
+            (Verification::Unknown, _) | (_, Verification::Unknown) => None,
+            (part1, part2) => Some((!part1.is_mismatch(), !part2.is_mismatch())),
+        }
+    }
+
+    /// Comparison of the answers with the ones given to [SolutionResult::with_verified],
+    /// or else with the known answers of `answers/DAY_XX.txt`. (See [crate::answers])
     ///
-    /// You must implement this method. If the method cannot be implemented
-    /// (eg: part 2 unavailable), return None as a placeholder    
     /// # Example
     /// ```
+    /// use aoc::answers::Verification;
     /// use aoc::Solution;
     ///# use aoc::solution::Result;
     ///
     /// struct DayXX;
     /// impl Solution for DayXX {
-    ///     //-- snip --
+    ///     // -- snip --
     ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = ();type P1 = (); type P2 = ();
-    ///#
-    ///#     fn parse(input: &str) -> Result<Self::Input> {
-    ///#         Ok(())
-    ///#         }
-    ///#
-    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///#         None
-    ///#     }
-    ///#
-    ///     fn part2(input: &Self::Input) -> Option<Self::P2> {
-    ///         None
-    ///     }
-    /// }    
-    ///```
-    fn part2(input: &Self::Input) -> Option<Self::P2>;
-
-    /// Utility method used to test Part 1.
-    ///
-    /// This is generally used in unit tests but can also be used in the main function
-    /// Use it to test your solution against smaller inputs and for debugging.
-    ///
-    /// # Example
-    /// ```
-    /// use aoc::Solution;
-    ///# use aoc::solution::{Result, SolutionError};
-    ///
-    /// struct DayXX;
-    /// impl Solution for DayXX {
-    ///     // -- snip --
-    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = usize; type P1 = usize; type P2 = usize;
-    ///#
-    ///    fn parse(input: &str) -> Result<Self::Input> {
-    ///        match input.parse() {
-    ///           Ok(num) => Ok(num),
-    ///           Err(_) => Err(SolutionError::ParseError)
-    ///        }   
-    ///     }
-    ///#
-    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///         Some(*input)
-    ///     }
-    ///#
-    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
-    ///#         Some(0)
-    ///#     }
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(1792) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(3021) }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
     /// }
     ///
-    /// #[cfg(test)]
-    /// mod tests {
-    ///
-    /// # use std::assert_eq;
-    /// #[test]
-    ///     fn test() {
-    ///         let (actual, _) = DayXX::test_part1("123");
-    ///         assert_eq!(actual, Some(123));
-    ///     }
-    /// }
+    /// // without answers/DAY_00.txt
+    /// let result = DayXX::run().expect("Day should run");
     ///
+    /// assert_eq!(result.verification(), (Verification::Unknown, Verification::Unknown));
     /// ```
-    fn test_part1(input: &str) -> Result<(Option<Self::P1>, Duration)> {
-        let (input, parse_time) = time!(Self::parse(input)?);
-        let (actual, time) = time!(Self::part1(&input));
-        let total_time = time + parse_time;
+    pub fn verification(&self) -> (Verification, Verification) {
+        let verify =
+            |verified: &Verification, expected: &Option<String>, actual: Option<String>| {
+                match verified {
+                    Verification::Unknown => {
+                        Verification::check(expected.as_deref(), actual.as_deref())
+                    }
+                    verified => verified.clone(),
+                }
+            };
 
-        println!("Part1: {:?} (in {})", actual, format_duration(total_time));
+        (
+            verify(
+                &self.verified.0,
+                &self.expected.part1,
+                self.part1.as_ref().map(AnswerDisplay::answer_string),
+            ),
+            verify(
+                &self.verified.1,
+                &self.expected.part2,
+                self.part2.as_ref().map(AnswerDisplay::answer_string),
+            ),
+        )
+    }
 
-        Ok((actual, total_time))
+    /// This result, or [SolutionError::WrongAnswer] when an answer doesn't match the known one
+    /// and `AOC_STRICT_ANSWERS=1`. Used by [crate::solution!] and [crate::run_all!].
+    pub fn checked(self) -> Result<Self> {
+        if !answers::strict() {
+            return Ok(self);
+        }
+
+        let (part1, part2) = self.verification();
+        for (part, verification, actual) in [
+            (
+                1,
+                part1,
+                self.part1.as_ref().map(AnswerDisplay::answer_string),
+            ),
+            (
+                2,
+                part2,
+                self.part2.as_ref().map(AnswerDisplay::answer_string),
+            ),
+        ] {
+            if let Verification::Mismatch { expected } = verification {
+                return Err(SolutionError::WrongAnswer {
+                    day: self.day,
+                    part,
+                    expected: expected.unwrap_or_else(|| "another answer".to_owned()),
+                    actual: actual.unwrap_or_else(|| "no answer".to_owned()),
+                });
+            }
+        }
+
+        Ok(self)
     }
 
-    /// Utility method used to test Part 2.
+    /// This result, or [SolutionError::MissingAnswer] for the first part without an answer that
+    /// isn't [PartStatus::Skipped]. The error keeps the [ResultView] of this result.
+    pub fn solved(self, part1: PartStatus, part2: PartStatus) -> Result<Self> {
+        let missing = |answered: bool, status| !answered && status != PartStatus::Skipped;
+
+        let part = if missing(self.part1.is_some(), part1) {
+            1
+        } else if missing(self.part2.is_some(), part2) {
+            2
+        } else {
+            return Ok(self);
+        };
+
+        Err(SolutionError::MissingAnswer {
+            part,
+            partial: Box::new(self.view()),
+        })
+    }
+}
+
+impl<P1: PartialEq + AnswerDisplay, P2: PartialEq + AnswerDisplay> SolutionResult<P1, P2> {
+    /// Compares this result with `other`, typically the same day after a refactoring.
     ///
-    /// This is generally used in unit tests but can also be used in the main function
-    /// Use it to test your solution against smaller inputs and for debugging.
+    /// Time deltas are relative to `self`.
     ///
     /// # Example
     /// ```
     /// use aoc::Solution;
-    ///# use aoc::solution::{Result, SolutionError};
+    ///# use aoc::solution::Result;
     ///
     /// struct DayXX;
     /// impl Solution for DayXX {
     ///     // -- snip --
     ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = i32; type P1 = i32; type P2 = i32;
-    ///#
-    ///    fn parse(input: &str) -> Result<Self::Input> {
-    ///        match input.parse() {
-    ///           Ok(num) => Ok(num),
-    ///           Err(_) => Err(SolutionError::ParseError)
-    ///        }   
-    ///     }
-    ///#
-    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///#         Some(*input)
-    ///#     }
-    ///#
-    ///     fn part2(input: &Self::Input) -> Option<Self::P2> {
-    ///         Some(-*input)
-    ///     }
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(24000) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(45000) }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
     /// }
     ///
-    /// #[cfg(test)]
-    /// mod tests {
-    ///
-    /// # use std::assert_eq;
-    /// #[test]
-    ///     fn test() {
-    ///         let (actual, _) = DayXX::test_part2("123");
-    ///         assert_eq!(actual, Some(-123));
-    ///     }
-    /// }
+    /// let before = DayXX::run().expect("Day should run");
+    /// let after = DayXX::run_par().expect("Day should run");
+    /// let diff = before.diff(&after);
     ///
+    /// assert!(diff.is_unchanged());
+    /// println!("{}", diff); // parse: +3% time; part1: unchanged, -32% time; part2: ...
     /// ```
-    fn test_part2(input: &str) -> Result<(Option<Self::P2>, Duration)> {
-        let (input, parse_time) = time!(Self::parse(input)?);
-        let (actual, time) = time!(Self::part2(&input));
-        let total_time = time + parse_time;
+    pub fn diff(&self, other: &Self) -> SolutionDiff {
+        fn answer<P: PartialEq + AnswerDisplay>(
+            before: &Option<P>,
+            after: &Option<P>,
+        ) -> AnswerDiff {
+            if before == after {
+                AnswerDiff::Unchanged
+            } else {
+                AnswerDiff::Changed {
+                    before: before.as_ref().map(AnswerDisplay::answer_string),
+                    after: after.as_ref().map(AnswerDisplay::answer_string),
+                }
+            }
+        }
 
-        println!("Part2: {:?} (in {})", actual, format_duration(total_time));
+        SolutionDiff {
+            parse_time_delta: time_delta(self.parse_duration, other.parse_duration),
+            part1: PartDiff {
+                answer: answer(&self.part1, &other.part1),
+                time_delta: time_delta(self.part1_duration, other.part1_duration),
+            },
+            part2: PartDiff {
+                answer: answer(&self.part2, &other.part2),
+                time_delta: time_delta(self.part2_duration, other.part2_duration),
+            },
+        }
+    }
+}
 
-        Ok((actual, total_time))
+/// Relative change from `before` to `after`, in percent. `None` when `before` is zero.
+fn time_delta(before: Duration, after: Duration) -> Option<f64> {
+    if before.is_zero() {
+        return None;
     }
 
-    /// Optional overridable method.
-    /// By default, the Self::get_input() will seek an input file under `"<root>/inputs/DAY_<XX>.txt"`
-    ///
-    /// The `<XX>` part corresponds to the [Solution::DAY] value.
-    ///
-    /// If one wants to overwrite the input file for a given solution, then it's possible to
-    /// overwrite this method.
-    ///
-    /// Example
-    /// -------
-    /// ```
-    /// use aoc::solution::Result;
-    ///
-    /// fn get_input() -> Result<String> {
-    ///     Ok("Some Hardcoded Input".to_owned())
-    /// }
-    ///
-    /// ```
-    fn get_input() -> Result<String> {
-        let path = format!("inputs/DAY_{:02}.txt", Self::DAY);
-        let input = std::fs::read_to_string(&path)?;
+    Some((after.as_secs_f64() - before.as_secs_f64()) / before.as_secs_f64() * 100.0)
+}
+
+/// Comparison of two answers of a part. See [SolutionResult::diff]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerDiff {
+    Unchanged,
+    Changed {
+        before: Option<String>,
+        after: Option<String>,
+    },
+}
+
+/// Comparison of a part of two results. See [SolutionResult::diff]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartDiff {
+    pub answer: AnswerDiff,
+    /// Relative time change in percent, when measurable.
+    pub time_delta: Option<f64>,
+}
+
+/// Comparison of two results of the same day. See [SolutionResult::diff]
+///
+/// Displayed as `parse: +3% time; part1: unchanged, -32% time; part2: CHANGED 10 -> 12, +5% time`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolutionDiff {
+    /// Relative parse time change in percent, when measurable.
+    pub parse_time_delta: Option<f64>,
+    pub part1: PartDiff,
+    pub part2: PartDiff,
+}
 
-        Ok(input)
+impl SolutionDiff {
+    /// Whether both answers are the same.
+    pub fn is_unchanged(&self) -> bool {
+        self.part1.answer == AnswerDiff::Unchanged && self.part2.answer == AnswerDiff::Unchanged
     }
+}
 
-    /// Solution Runner
+/// Results of the same day on several inputs, displayed side by side. See [Solution::run_many]
+///
+/// ```text
+/// Input | Part 1 | Part 2 | Time
+/// #1    | 1721   | 2700   | 12us
+/// #2    | Invalid Puzzle input: not a number
+/// ```
+#[derive(Debug, Clone)]
+pub struct SideBySide {
+    rows: Vec<std::result::Result<ResultView, String>>,
+}
+
+impl SideBySide {
+    pub fn new<P1: AnswerDisplay, P2: AnswerDisplay>(
+        results: &[Result<SolutionResult<P1, P2>>],
+    ) -> Self {
+        Self {
+            rows: results
+                .iter()
+                .map(|result| {
+                    result
+                        .as_ref()
+                        .map(SolutionResult::view)
+                        .map_err(ToString::to_string)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Display for SideBySide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let options = DisplayOptions::default();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| match row {
+                Ok(view) => vec![
+                    format!("#{}", i + 1),
+                    view.part1.clone().unwrap_or_else(|| "-".to_owned()),
+                    view.part2.clone().unwrap_or_else(|| "-".to_owned()),
+                    options.format_duration(
+                        view.parse_duration + view.part1_duration + view.part2_duration,
+                    ),
+                ],
+                Err(e) => vec![format!("#{}", i + 1), e.clone()],
+            })
+            .collect();
+
+        let header = ["Input", "Part 1", "Part 2", "Time"].map(str::to_owned);
+        let mut widths = header.clone().map(|cell| cell.chars().count());
+        for row in rows.iter().filter(|row| row.len() == header.len()) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(header.as_slice()).chain(rows.iter().map(Vec::as_slice)) {
+            let last = row.len() - 1;
+            for (i, cell) in row.iter().enumerate() {
+                if i == last {
+                    writeln!(f, "{}", cell)?;
+                } else {
+                    write!(f, "{:width$} | ", cell, width = widths[i])?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_delta(f: &mut Formatter<'_>, delta: Option<f64>) -> std::fmt::Result {
+    match delta {
+        Some(delta) => write!(f, "{:+.0}% time", delta),
+        None => write!(f, "n/a time"),
+    }
+}
+
+impl Display for PartDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            AnswerDiff::Unchanged => write!(f, "unchanged, ")?,
+            AnswerDiff::Changed { before, after } => write!(
+                f,
+                "CHANGED {} -> {}, ",
+                before.as_deref().unwrap_or("-"),
+                after.as_deref().unwrap_or("-")
+            )?,
+        }
+
+        fmt_delta(f, self.time_delta)
+    }
+}
+
+impl Display for SolutionDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parse: ")?;
+        fmt_delta(f, self.parse_time_delta)?;
+        write!(f, "; part1: {}; part2: {}", self.part1, self.part2)
+    }
+}
+
+impl<P1: AnswerDisplay, P2: AnswerDisplay> Display for SolutionResult<P1, P2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.view())
+    }
+}
+
+/// Main trait for Advent of Code Daily challenges.
+///
+/// This trait includes a generic runner part, a utility part and methods to implement each day.
+///
+/// ### The runner part includes:
+/// - [Solution::test_part1]
+/// - [Solution::test_part2]
+/// - [Solution::test_part1_silent]
+/// - [Solution::test_part2_silent]
+/// - [Solution::test_part1_with]
+/// - [Solution::test_part2_with]
+/// - [Solution::run]
+/// - [Solution::run_par]
+/// - [Solution::run_streaming]
+/// - [Solution::run_captured]
+/// - [Solution::run_timeline]
+///
+/// Those associated methods are implemented by default and are intended to be used as is.
+///
+/// ### The utility part includes:
+/// - [Solution:get_input]
+/// - [Solution::input_path]
+/// - [Solution::parse_reader]
+/// - [Solution::visualize]
+///
+/// The utility part comes pre define but can be overwritten if one chooses to.
+///
+/// ### The methods to be implemented each day are:
+/// - [Solution::TITLE] - used for displaying the solution
+/// - [Solution::DAY] - used by [Solution::get_input]'s default implementation
+/// - [Solution::YEAR] - optional, used by [Solution::INPUT_TEMPLATE]
+/// - [Solution::INPUT_TEMPLATE] - optional, location of the input file
+/// - [Solution::parse] - pre process the puzzle input for the other parts (or [crate::no_parse!])
+/// - [Solution::part1] - solution for part 1
+/// - [Solution::part2] - solution for part 2
+///
+/// # Example
+/// ```
+/// use aoc::Solution;
+/// use aoc::solution::Result;
+/// struct DayXX;
+///
+/// impl Solution for DayXX {
+///     const TITLE: &'static str = "";
+///     const DAY: u8 = 0;
+///     
+///     type Input = ();
+///     type P1 = ();
+///     type P2 = ();
+///
+///     fn parse(input: &str) -> Result<Self::Input> {
+///         Ok(())
+///     }
+///
+///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///         None
+///     }
+///
+///     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///         None
+///     }
+///
+///#     fn get_input() -> Result<String> {
+///#         Ok("input".to_owned())
+///#     }
+/// }
+///
+/// // create a file `inputs/DAY_00.txt` then:
+/// DayXX::run().expect("Day should run");
+/// ```
+///
+pub trait Solution {
+    /// Puzzle's title. With the `download` feature and [Solution::YEAR] set, an empty title
+    /// is looked up on the puzzle page for display. (See [crate::client::fetch_title])
+    const TITLE: &'static str;
+    const DAY: u8;
+
+    /// Optional puzzle's year. Used by the `{year}` placeholder of [Solution::INPUT_TEMPLATE].
+    const YEAR: Option<u16> = None;
+
+    /// Template of the input file path used by [Solution::input_path].
     ///
-    /// This is the main entry point that we want to call for each day.
+    /// Supported placeholders are `{day}`, `{day:02}` (zero padded) and `{year}`.
+    /// A leading `inputs/` directory is replaced by `AOC_INPUT_DIR` when set.
+    /// (See [crate::input::render_template])
     ///
-    /// This method is in charge of:
-    /// - Reading the input file. (path provided by Self::get_input_path)
-    /// - Parsing the input file. (Self::parse - must be implemented)
-    /// - Solving Part1. (Self::part1 - must be implemented)
-    /// - Solving Part2. (Self::part2 - must be implemented)
-    /// - Returning a SolutionResult or a SolutionError
+    /// With the default template, when [Solution::YEAR] is set, `inputs/{year}/DAY_{day:02}.txt`
+    /// is looked up first, falling back to `inputs/DAY_{day:02}.txt`.
     ///
-    /// Example
-    /// -------
+    /// # Example
     /// ```
     /// use aoc::Solution;
     ///# use aoc::solution::Result;
     ///
-    /// struct DayXX;
-    /// impl Solution for DayXX {
-    ///     // -- snip --
-    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    /// struct Day05;
+    /// impl Solution for Day05 {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = ""; const DAY: u8 = 5;
+    ///     const YEAR: Option<u16> = Some(2022);
+    ///     const INPUT_TEMPLATE: &'static str = "data/{year}/{day:02}/input";
+    ///#     type Input = (); type P1 = (); type P2 = ();
     ///#
     ///#     fn parse(input: &str) -> Result<Self::Input> {
     ///#         Ok(())
-    ///#         }
+    ///#     }
     ///#
     ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
-    ///#         Some(0)
+    ///#         None
     ///#     }
     ///#
     ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
-    ///#         Some(0)
+    ///#         None
     ///#     }
     /// }
     ///
-    /// fn run_solution() {
-    ///     match DayXX::run() {
-    ///         Ok(solution) => println!("{}", solution),
-    ///         Err(e) => println!("Failed to solve day: {}", e)
-    ///     }   
-    /// }
-    ///
+    /// assert!(Day05::input_path().unwrap().ends_with("data/2022/05/input"));
     /// ```
-    fn run() -> Result<SolutionResult<Self::P1, Self::P2>> {
-        let input = Self::get_input()?;
+    const INPUT_TEMPLATE: &'static str = input::DEFAULT_TEMPLATE;
 
-        let (input, parse_time) = time!(Self::parse(&input)?);
-        let (p1, t1) = time!(Self::part1(&input));
-        let (p2, t2) = time!(Self::part2(&input));
+    /// Whether [Solution::get_input] removes the trailing newlines of the input.
+    ///
+    /// Set it to `false` for puzzles where trailing whitespace is significant.
+    /// (See [crate::input::normalize])
+    const TRIM_TRAILING_NEWLINE: bool = true;
 
-        Ok(SolutionResult {
-            title: Self::TITLE,
-            day: Self::DAY,
-            parse_duration: parse_time,
-            part1: p1,
-            part1_duration: t1,
-            part2: p2,
-            part2_duration: t2,
-        })
-    }
+    /// Whether [Solution::get_input] converts `\r\n` line endings to `\n`.
+    /// (See [crate::input::normalize])
+    const NORMALIZE_CRLF: bool = true;
 
-    /// Parallel Solution runner
+    /// Whether [Solution::parse] merely copies the input, in which case [Solution::run] and
+    /// [Solution::run_par] don't time it. Set by [crate::no_parse!], along with the parse.
+    const IDENTITY_PARSE: bool = false;
+
+    /// Whether an empty (or whitespace only) input is valid.
     ///
-    /// Runs [Solution::part1] and [Solution::part2] in parallel to optimize execution speed
+    /// By default, [Solution::run] and [Solution::run_par] refuse it with
+    /// [SolutionError::EmptyInput] rather than solving a botched download.
+    const ALLOW_EMPTY_INPUT: bool = false;
+
+    /// Whether [Solution::run] and [Solution::run_par] compare the answers with the known ones
+    /// of `answers/DAY_XX.txt`, when the file exists. (See [crate::answers])
+    const VERIFY_ANSWERS: bool = true;
+
+    /// Known answer of part 1, compared with the [AnswerDisplay] form of [Solution::part1]'s
+    /// by [Solution::run] and [Solution::run_par], like the answers of `answers/DAY_XX.txt`.
     ///
-    /// See [Solution::run] for reference
+    /// When both are set, the constant wins over the answers file.
+    /// (See [SolutionResult::verification])
     ///
-    /// Example
-    /// -------
+    /// # Example
     /// ```
     /// use aoc::Solution;
     ///# use aoc::solution::Result;
@@ -464,11 +1114,71 @@ pub trait Solution {
     /// impl Solution for DayXX {
     ///     // -- snip --
     ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
-    ///#     type Input = ();type P1 = usize;type P2 = usize;
-    ///#
-    ///#     fn parse(input: &str) -> Result<Self::Input> {
-    ///#         Ok(())
-    ///#         }
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(24000) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(45001) }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
+    ///     const EXPECTED_P1: Option<&'static str> = Some("24000");
+    ///     const EXPECTED_P2: Option<&'static str> = Some("45000");
+    /// }
+    ///
+    /// let result = DayXX::run().expect("Day should run");
+    ///
+    /// assert_eq!(result.verified(), Some((true, false)));
+    /// assert!(result.to_string().contains("Part 2: '45001' ✗ (expected 45000)"));
+    /// ```
+    const EXPECTED_P1: Option<&'static str> = None;
+
+    /// Known answer of part 2. See [Solution::EXPECTED_P1]
+    const EXPECTED_P2: Option<&'static str> = None;
+
+    /// Whether part 1 is expected to have an answer in [Solution::run_strict].
+    const PART1: PartStatus = PartStatus::Implemented;
+
+    /// Whether part 2 is expected to have an answer in [Solution::run_strict].
+    const PART2: PartStatus = PartStatus::Implemented;
+
+    /// Puzzle input type.
+    /// it's the output value of [Solution::parse]
+    /// and is consumed by [Solution::part1] and [Solution::part2]
+    type Input: Sync;
+
+    /// Part 1 Solution type.
+    /// it's the output value of [Solution::part1]
+    type P1: Send + Debug;
+
+    /// Part 2 Solution type.
+    /// it's the output value of [Solution::part2]
+    type P2: Send + Debug;
+
+    /// Takes the puzzle input as &str and parses it to something more flexible
+    /// to solve the exercises.
+    ///
+    /// The errors of the std parsers convert into [SolutionError::ParseError], so `?` can be
+    /// used instead of hiding them with `.ok()`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::error::Error;
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //--snip--
+    ///
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///     type Input = Vec<usize>;
+    ///#     type P1 = usize; type P2 = usize;
+    ///
+    ///     fn parse(input: &str) -> Result<Self::Input> {
+    ///         let mut numbers = vec![];
+    ///         for line in input.lines() {
+    ///             numbers.push(line.parse()?);
+    ///         }
+    ///         Ok(numbers)
+    ///      }
     ///#
     ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
     ///#         Some(0)
@@ -479,41 +1189,2844 @@ pub trait Solution {
     ///#     }
     /// }
     ///
-    /// fn run_solution() {
-    ///     match DayXX::run_par() {
-    ///         Ok(solution) => println!("{}", solution),
-    ///         Err(e) => println!("Failed to solve day: {}", e)
-    ///     }   
+    /// assert_eq!(DayXX::parse("1\n2").unwrap(), vec![1, 2]);
+    ///
+    /// let error = DayXX::parse("1\ntwo").unwrap_err();
+    /// assert_eq!(error.to_string(), "Invalid Puzzle input: invalid digit found in string");
+    /// assert!(error.source().is_some());
+    /// ```
+    ///
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Optional overridable method.
+    /// Parses the puzzle input knowing the day and year it is parsed for, so that one parser
+    /// can be shared by similar days. The runners and the test helpers call it with
+    /// [Solution::DAY] and [Solution::YEAR].
+    ///
+    /// By default, the context is ignored and [Solution::parse] is called. Don't implement
+    /// `parse` with `parse_ctx` then: without an override of `parse_ctx`, they would call each
+    /// other until the stack overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// fn shared_parse(input: &str, day: u8) -> Result<Vec<u32>> {
+    ///     // day 21 has comma separated numbers, the others one per line
+    ///     let separator = if day == 21 { ',' } else { '\n' };
+    ///     input
+    ///         .split(separator)
+    ///         .map(|n| n.trim().parse().map_err(|_| SolutionError::parse("not a number")))
+    ///         .collect()
     /// }
     ///
-    /// ```    
-    fn run_par() -> Result<SolutionResult<Self::P1, Self::P2>> {
-        let input = Self::get_input()?;
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //--snip--
+    ///#     const TITLE: &'static str = "";
+    ///     const DAY: u8 = 21;
+    ///     type Input = Vec<u32>;
+    ///#     type P1 = u32; type P2 = u32;
+    ///
+    ///     fn parse(input: &str) -> Result<Self::Input> {
+    ///         shared_parse(input, Self::DAY)
+    ///     }
+    ///
+    ///     fn parse_ctx(input: &str, day: u8, _year: Option<u16>) -> Result<Self::Input> {
+    ///         shared_parse(input, day)
+    ///     }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(input.iter().sum())
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         None
+    ///#     }
+    /// }
+    ///
+    /// assert_eq!(DayXX::test_part1_silent("1,2,3").unwrap().0, Some(6));
+    /// ```
+    fn parse_ctx(input: &str, _day: u8, _year: Option<u16>) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    /// Optional overridable method.
+    /// Parses the puzzle input from a reader instead of a `&str`. Used by [Solution::run_streaming].
+    ///
+    /// By default, the whole reader is read to a String which is then given to
+    /// [Solution::parse_ctx].
+    /// Days with huge inputs can override it to parse line by line without loading the whole file.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::BufRead;
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //--snip--
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///     type Input = u64;
+    ///#     type P1 = u64; type P2 = u64;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Self::parse_reader(input.as_bytes())
+    ///#     }
+    ///
+    ///     fn parse_reader<R: BufRead>(reader: R) -> Result<Self::Input> {
+    ///         let mut sum = 0;
+    ///         for line in reader.lines() {
+    ///             sum += line?.parse::<u64>()?;
+    ///         }
+    ///         Ok(sum)
+    ///     }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(*input)
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         None
+    ///#     }
+    /// }
+    ///
+    /// assert_eq!(DayXX::parse_reader("1\n2\n3".as_bytes()).unwrap(), 6);
+    /// ```
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
 
-        let (input, parse_time) = time!(Self::parse(&input)?);
+        Self::parse_ctx(&input, Self::DAY, Self::YEAR)
+    }
 
-        let scope = crossbeam_utils::thread::scope(|s| {
-            let solve1 = s.spawn(|_| time!(Self::part1(&input)));
-            let solve2 = s.spawn(|_| time!(Self::part2(&input)));
+    /// Takes the [Solution::parse]'s output and return the solution for part 1
+    ///
+    /// You must implement this method. If the method cannot be implemented,
+    /// return None as a placeholder
+    ///
+    /// For a product or a sum of large values, prefer [crate::math::checked_product] and
+    /// [crate::math::checked_sum]: a release build wraps around on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = (); type P2 = ();
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
+    ///#
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///         None
+    ///     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         None
+    ///#     }
+    /// }    
+    ///```
+    ///
+    fn part1(input: &Self::Input) -> Option<Self::P1>;
+
+    /// Optional overridable method.
+    /// Lazily computed candidates for part 1: the runners take the first one, so the part 1
+    /// time is the time to the first answer.
+    ///
+    /// Defaults to [Solution::part1]. Override it for the "first value satisfying X" puzzles,
+    /// and keep [Solution::part1] consistent with it, eg: `Self::part1_iter(input).next()`.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type P1 = u64; type P2 = u64;
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    ///#     fn get_input() -> Result<String> { Ok("7".to_owned()) }
+    ///     type Input = u64;
+    ///
+    ///     fn parse(input: &str) -> Result<Self::Input> {
+    ///         input.trim().parse().map_err(|_| aoc::solution::SolutionError::parse("not a number"))
+    ///     }
+    ///
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///         Self::part1_iter(input).next()
+    ///     }
+    ///
+    ///     fn part1_iter<'a>(input: &'a Self::Input) -> Box<dyn Iterator<Item = Self::P1> + 'a>
+    ///     where
+    ///         Self::P1: 'a,
+    ///     {
+    ///         Box::new((2..).filter(move |n| n * n % input == 1))
+    ///     }
+    /// }
+    ///
+    /// let result = DayXX::run().expect("Day should run");
+    ///
+    /// assert_eq!(result.view().part1.as_deref(), Some("6"));
+    /// ```
+    fn part1_iter<'a>(input: &'a Self::Input) -> Box<dyn Iterator<Item = Self::P1> + 'a>
+    where
+        Self::P1: 'a,
+    {
+        Box::new(Self::part1(input).into_iter())
+    }
+    /// Takes the [Solution::parse]'s output and return the solution for part 2
+    ///
+    /// You must implement this method. If the method cannot be implemented
+    /// (eg: part 2 unavailable), return None as a placeholder    
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = (); type P2 = ();
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         None
+    ///#     }
+    ///#
+    ///     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///         None
+    ///     }
+    /// }    
+    ///```
+    fn part2(input: &Self::Input) -> Option<Self::P2>;
 
-            let solve1 = solve1.join();
-            let solve2 = solve2.join();
+    /// Optional overridable method.
+    /// Renders the puzzle state as ASCII art. Used by the [crate::visualize!] macro.
+    ///
+    /// This is an extension point for the grid / simulation puzzles and has no effect on solving.
+    /// Defaults to `None`, in which case nothing is displayed.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type P1 = (); type P2 = ();
+    ///     type Input = Vec<Vec<bool>>;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(input.lines().map(|line| line.chars().map(|c| c == '#').collect()).collect())
+    ///#     }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         None
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         None
+    ///#     }
+    ///
+    ///     fn visualize(input: &Self::Input) -> Option<String> {
+    ///         let rows: Vec<String> = input
+    ///             .iter()
+    ///             .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+    ///             .collect();
+    ///
+    ///         Some(rows.join("\n"))
+    ///     }
+    /// }
+    ///
+    /// let input = DayXX::parse("#.\n.#").unwrap();
+    /// assert_eq!(DayXX::visualize(&input), Some("#.\n.#".to_owned()));
+    /// ```
+    fn visualize(_input: &Self::Input) -> Option<String> {
+        None
+    }
 
-            (solve1, solve2)
-        })
-        .map_err(|_| SolutionError::Run)?;
+    /// Optional overridable method.
+    /// Sanity-checks the answers at the end of [Solution::run] and [Solution::run_par], to catch
+    /// an obviously wrong answer before submitting it.
+    ///
+    /// An error fails the run as [SolutionError::InvalidAnswers]. Defaults to `Ok(())`.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     //-- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = (); type P1 = u32; type P2 = u32;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(45000) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(24000) }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
+    ///
+    ///     fn validate_answers(p1: &Option<u32>, p2: &Option<u32>) -> Result<()> {
+    ///         match (p1, p2) {
+    ///             (Some(p1), Some(p2)) if p1 >= p2 => Err(SolutionError::InvalidAnswers(
+    ///                 "the top three elves carry more than the top one".to_owned(),
+    ///             )),
+    ///             _ => Ok(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(matches!(DayXX::run(), Err(SolutionError::InvalidAnswers(_))));
+    /// ```
+    fn validate_answers(_p1: &Option<Self::P1>, _p2: &Option<Self::P2>) -> Result<()> {
+        Ok(())
+    }
 
-        match scope {
-            (Ok((part1, part1_duration)), Ok((part2, part2_duration))) => Ok(SolutionResult {
-                title: Self::TITLE,
-                day: Self::DAY,
-                parse_duration: parse_time,
-                part1,
-                part1_duration,
-                part2,
-                part2_duration,
-            }),
-            _ => Err(SolutionError::Run),
+    /// Utility method used to test Part 1.
+    ///
+    /// This is generally used in unit tests but can also be used in the main function
+    /// Use it to test your solution against smaller inputs and for debugging.
+    ///
+    /// The result is printed unless the `AOC_QUIET` environment variable is set to `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = usize; type P1 = usize; type P2 = usize;
+    ///#
+    ///    fn parse(input: &str) -> Result<Self::Input> {
+    ///        match input.parse() {
+    ///           Ok(num) => Ok(num),
+    ///           Err(_) => Err(SolutionError::parse("not a number"))
+    ///        }   
+    ///     }
+    ///#
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///         Some(*input)
+    ///     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         Some(0)
+    ///#     }
+    /// }
+    ///
+    /// let (actual, _) = DayXX::test_part1("123").expect("couldn't run test");
+    /// assert_eq!(actual, Some(123));
+    ///
+    /// ```
+    fn test_part1(input: &str) -> Result<(Option<Self::P1>, Duration)> {
+        let (actual, total_time) = Self::test_part1_silent(input)?;
+
+        if !quiet() {
+            logging::info(format_args!(
+                "Part1: {:?} (in {})",
+                actual,
+                format_duration(total_time)
+            ));
+        }
+
+        Ok((actual, total_time))
+    }
+
+    /// Same as [Solution::test_part1] without printing the result.
+    ///
+    /// This is the variant used by the [crate::test!] macro.
+    fn test_part1_silent(input: &str) -> Result<(Option<Self::P1>, Duration)> {
+        Self::test_part1_with(input, |input| Self::parse_ctx(input, Self::DAY, Self::YEAR))
+    }
+
+    /// Same as [Solution::test_part1_silent] but the input is parsed with `parse`
+    /// instead of [Solution::parse].
+    ///
+    /// Useful when an example needs a different parse configuration than the real input.
+    /// (See [crate::test!]'s `with_parse` form and [crate::example!]'s `day`/`year` context)
+    fn test_part1_with<F>(input: &str, parse: F) -> Result<(Option<Self::P1>, Duration)>
+    where
+        F: FnOnce(&str) -> Result<Self::Input>,
+    {
+        let (input, parse_time) =
+            phase::timed(Self::DAY, Self::YEAR, Phase::Parse, || parse(input));
+        let input = input?;
+        let (actual, time) = phase::timed(Self::DAY, Self::YEAR, Phase::Part1, || {
+            Self::part1_iter(&input).next()
+        });
+
+        Ok((actual, time + parse_time))
+    }
+
+    /// Utility method used to test Part 2.
+    ///
+    /// This is generally used in unit tests but can also be used in the main function
+    /// Use it to test your solution against smaller inputs and for debugging.
+    ///
+    /// The result is printed unless the `AOC_QUIET` environment variable is set to `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = i32; type P1 = i32; type P2 = i32;
+    ///#
+    ///    fn parse(input: &str) -> Result<Self::Input> {
+    ///        match input.parse() {
+    ///           Ok(num) => Ok(num),
+    ///           Err(_) => Err(SolutionError::parse("not a number"))
+    ///        }   
+    ///     }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(*input)
+    ///#     }
+    ///#
+    ///     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///         Some(-*input)
+    ///     }
+    /// }
+    ///
+    /// let (actual, _) = DayXX::test_part2("123").expect("couldn't run test");
+    /// assert_eq!(actual, Some(-123));
+    ///
+    /// ```
+    fn test_part2(input: &str) -> Result<(Option<Self::P2>, Duration)> {
+        let (actual, total_time) = Self::test_part2_silent(input)?;
+
+        if !quiet() {
+            logging::info(format_args!(
+                "Part2: {:?} (in {})",
+                actual,
+                format_duration(total_time)
+            ));
+        }
+
+        Ok((actual, total_time))
+    }
+
+    /// Same as [Solution::test_part2] without printing the result.
+    ///
+    /// This is the variant used by the [crate::test!] macro.
+    fn test_part2_silent(input: &str) -> Result<(Option<Self::P2>, Duration)> {
+        Self::test_part2_with(input, |input| Self::parse_ctx(input, Self::DAY, Self::YEAR))
+    }
+
+    /// Same as [Solution::test_part2_silent] but the input is parsed with `parse`
+    /// instead of [Solution::parse].
+    ///
+    /// Useful when an example needs a different parse configuration than the real input.
+    /// (See [crate::test!]'s `with_parse` form and [crate::example!]'s `day`/`year` context)
+    fn test_part2_with<F>(input: &str, parse: F) -> Result<(Option<Self::P2>, Duration)>
+    where
+        F: FnOnce(&str) -> Result<Self::Input>,
+    {
+        let (input, parse_time) =
+            phase::timed(Self::DAY, Self::YEAR, Phase::Parse, || parse(input));
+        let input = input?;
+        let (actual, time) =
+            phase::timed(Self::DAY, Self::YEAR, Phase::Part2, || Self::part2(&input));
+
+        Ok((actual, time + parse_time))
+    }
+
+    /// Optional overridable method.
+    /// By default, the Self::get_input() will seek an input file under `"<root>/inputs/DAY_<XX>.txt"`
+    ///
+    /// The `<XX>` part corresponds to the [Solution::DAY] value.
+    /// The `inputs` directory can be changed with the `AOC_INPUT_DIR` environment variable.
+    /// (See [crate::input])
+    ///
+    /// The `AOC_INPUT_DAY_<XX>` environment variable is read first when it is set.
+    /// (See [Solution::get_input_env])
+    ///
+    /// The standard input is read instead when `AOC_INPUT=-` is set, or when the file is missing
+    /// and the input is piped. (See [Solution::get_input_from])
+    ///
+    /// With the `download` feature, a missing file is downloaded from the website when
+    /// [Solution::YEAR] is set and a session cookie is available. (See [crate::client])
+    ///
+    /// With the `embed-inputs` feature, an input embedded in the binary is used before any file.
+    /// (See [crate::embed])
+    ///
+    /// The input is normalized according to [Solution::TRIM_TRAILING_NEWLINE] and
+    /// [Solution::NORMALIZE_CRLF]. The inputs given to [Solution::test_part1] and
+    /// [Solution::test_part2] are used as is.
+    ///
+    /// If one wants to overwrite the input file for a given solution, then it's possible to
+    /// overwrite this method.
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// use aoc::solution::Result;
+    ///
+    /// fn get_input() -> Result<String> {
+    ///     Ok("Some Hardcoded Input".to_owned())
+    /// }
+    ///
+    /// ```
+    fn get_input() -> Result<String> {
+        let stdin = std::io::stdin();
+        let is_terminal = stdin.is_terminal();
+
+        Self::get_input_from(stdin.lock(), is_terminal)
+    }
+
+    /// Reads the input file at [Solution::input_path], or `stdin` instead.
+    ///
+    /// `stdin` stands for the standard input, `is_terminal` tells whether it is interactive.
+    /// (See [crate::input::read_or_stdin])
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = ();type P2 = ();
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// // No `inputs/DAY_00.txt` file: the piped input is used.
+    /// let input = DayXX::get_input_from("1721\n979".as_bytes(), false).unwrap();
+    ///
+    /// assert_eq!(input, "1721\n979");
+    /// ```
+    fn get_input_from<R: Read>(stdin: R, is_terminal: bool) -> Result<String> {
+        let var = input::input_var(Self::DAY);
+        if std::env::var_os(&var).is_some() {
+            return Self::get_input_env(&var);
+        }
+
+        #[cfg(feature = "embed-inputs")]
+        if let Some(input) = crate::embed::embedded(Self::YEAR, Self::DAY) {
+            if !input::stdin_requested() {
+                return Ok(input::normalize(
+                    input.to_owned(),
+                    Self::TRIM_TRAILING_NEWLINE,
+                    Self::NORMALIZE_CRLF,
+                ));
+            }
+        }
+
+        let input = input::read_or_stdin(Self::input_path(), stdin, is_terminal);
+
+        #[cfg(feature = "download")]
+        let input = match input {
+            Err(SolutionError::MissingInput(missing)) => {
+                crate::client::download_missing(Self::YEAR, Self::DAY, missing)
+            }
+            input => input,
+        };
+
+        let input = input?;
+
+        Ok(input::normalize(
+            input,
+            Self::TRIM_TRAILING_NEWLINE,
+            Self::NORMALIZE_CRLF,
+        ))
+    }
+
+    /// Reads the input from the environment variable `var`, eg: in a sandboxed CI.
+    ///
+    /// The input is normalized like [Solution::get_input]'s. A missing variable is reported as
+    /// [SolutionError::PuzzleInput] naming it. (See [crate::input::read_env])
+    ///
+    /// [Solution::get_input] reads `AOC_INPUT_DAY_<XX>` with it when set.
+    /// (See [crate::input::input_var])
+    fn get_input_env(var: &str) -> Result<String> {
+        Ok(input::normalize(
+            input::read_env(var)?,
+            Self::TRIM_TRAILING_NEWLINE,
+            Self::NORMALIZE_CRLF,
+        ))
+    }
+
+    /// Reads the puzzle input of the given [InputKind].
+    ///
+    /// [InputKind::Real] is [Solution::get_input]. [InputKind::Example] reads the
+    /// [Solution::INPUT_TEMPLATE] path with an `_example` suffix, eg: `inputs/DAY_07_example.txt`.
+    /// (See [crate::input::read_example])
+    ///
+    /// Both are normalized the same way, and a missing file error tells which kind was sought.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::input::InputKind;
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = ();type P2 = ();
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// // No `inputs/DAY_00_example.txt` file
+    /// let error = DayXX::get_input_kind(InputKind::Example).unwrap_err();
+    ///
+    /// assert!(error.to_string().contains("DAY_00_example.txt (example input)"));
+    /// ```
+    fn get_input_kind(kind: InputKind) -> Result<String> {
+        match kind {
+            InputKind::Real => Self::get_input(),
+            InputKind::Example => {
+                let input = input::read_example(&input::candidates(
+                    Self::INPUT_TEMPLATE,
+                    Self::DAY,
+                    Self::YEAR,
+                )?)?;
+
+                Ok(input::normalize(
+                    input,
+                    Self::TRIM_TRAILING_NEWLINE,
+                    Self::NORMALIZE_CRLF,
+                ))
+            }
+        }
+    }
+
+    /// Reads the input file at `path` instead of [Solution::input_path], eg: a path given on the
+    /// command line. (See [crate::solution_file!])
+    ///
+    /// The path is resolved like `AOC_INPUT_DIR` (See [crate::input::locate]) and the input is
+    /// normalized like [Solution::get_input]'s. The error mentions the path.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = ();type P2 = ();
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { None }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// let error = DayXX::get_input_file("inputs/elsewhere.txt").unwrap_err();
+    ///
+    /// assert!(error.to_string().contains("elsewhere.txt"));
+    /// ```
+    fn get_input_file(path: &str) -> Result<String> {
+        let input = input::read(&input::locate(path))?;
+
+        Ok(input::normalize(
+            input,
+            Self::TRIM_TRAILING_NEWLINE,
+            Self::NORMALIZE_CRLF,
+        ))
+    }
+
+    /// Optional overridable method.
+    /// Path of the puzzle input file, used by [Solution::get_input] and [Solution::run_streaming].
+    ///
+    /// Defaults to [Solution::INPUT_TEMPLATE] rendered with [Solution::DAY] and [Solution::YEAR],
+    /// where a leading `inputs` directory can be changed with the `AOC_INPUT_DIR` environment
+    /// variable. (See [crate::input::candidates] and [crate::input::find])
+    fn input_path() -> Result<PathBuf> {
+        input::find(&input::candidates(
+            Self::INPUT_TEMPLATE,
+            Self::DAY,
+            Self::YEAR,
+        )?)
+    }
+
+    /// Solution Runner
+    ///
+    /// This is the main entry point that we want to call for each day.
+    ///
+    /// This method is in charge of:
+    /// - Reading the input file. (path provided by Self::get_input_path)
+    /// - Parsing the input file. (Self::parse - must be implemented)
+    /// - Solving Part1. (Self::part1 - must be implemented)
+    /// - Solving Part2. (Self::part2 - must be implemented)
+    /// - Returning a SolutionResult or a SolutionError
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(0)
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         Some(0)
+    ///#     }
+    /// }
+    ///
+    /// fn run_solution() {
+    ///     match DayXX::run() {
+    ///         Ok(solution) => println!("{}", solution),
+    ///         Err(e) => println!("Failed to solve day: {}", e)
+    ///     }   
+    /// }
+    ///
+    /// ```
+    fn run() -> Result<SolutionResult<Self::P1, Self::P2>> {
+        phase::run_span(Self::DAY, Self::YEAR).in_scope(|| {
+            let prepared = prepare::<Self>(Self::get_input()?)?;
+            let (input, parse_time) = parse_input::<Self>(&prepared.input)?;
+
+            finish::<Self>(Some(&prepared), parse_time, solve::<Self>(&input))
+        })
+    }
+
+    /// Runs the day on the given input instead of [Solution::get_input].
+    ///
+    /// The input is normalized like [Solution::get_input]'s, but its checksum is not checked.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = Vec<u32>; type P1 = u32; type P2 = u32;
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         input.lines().map(|l| l.parse().map_err(|_| SolutionError::parse("not a number"))).collect()
+    ///#     }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { input.iter().max().copied() }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(input.iter().sum()) }
+    /// }
+    ///
+    /// let result = DayXX::run_with_input("1721\r\n979\r\n").expect("Day should run");
+    ///
+    /// assert_eq!(result.view().part1.as_deref(), Some("1721"));
+    /// ```
+    fn run_with_input(input: &str) -> Result<SolutionResult<Self::P1, Self::P2>> {
+        phase::run_span(Self::DAY, Self::YEAR).in_scope(|| {
+            let input = input::normalize(
+                input.to_owned(),
+                Self::TRIM_TRAILING_NEWLINE,
+                Self::NORMALIZE_CRLF,
+            );
+            if !Self::ALLOW_EMPTY_INPUT && input.trim().is_empty() {
+                return Err(SolutionError::EmptyInput {
+                    path_or_source: "run_with_input()".to_owned(),
+                });
+            }
+
+            let (input, parse_time) = parse_input::<Self>(&input)?;
+
+            Ok(unverified::<Self>(parse_time, solve::<Self>(&input)))
+        })
+    }
+
+    /// Runs the day on the input file at `path` instead of [Solution::get_input].
+    /// (See [Solution::get_input_file])
+    ///
+    /// Like [Solution::run_with_input], the checksum of the input is not checked.
+    fn run_file(path: &str) -> Result<SolutionResult<Self::P1, Self::P2>> {
+        phase::run_span(Self::DAY, Self::YEAR).in_scope(|| {
+            let input = Self::get_input_file(path)?;
+            if !Self::ALLOW_EMPTY_INPUT && input.trim().is_empty() {
+                return Err(SolutionError::EmptyInput {
+                    path_or_source: path.to_owned(),
+                });
+            }
+
+            let (input, parse_time) = parse_input::<Self>(&input)?;
+
+            finish::<Self>(None, parse_time, solve::<Self>(&input))
+        })
+    }
+
+    /// Runs the day on each of the given inputs, eg: the inputs of a study group.
+    ///
+    /// An input failing doesn't stop the others: each one has its own result.
+    /// [SideBySide] displays them as a table. (See [Solution::run_with_input])
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    /// use aoc::solution::SideBySide;
+    ///# use aoc::solution::{Result, SolutionError};
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = Vec<u32>; type P1 = u32; type P2 = u32;
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         input.lines().map(|l| l.parse().map_err(|_| SolutionError::parse("not a number"))).collect()
+    ///#     }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { input.iter().max().copied() }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(input.iter().sum()) }
+    /// }
+    ///
+    /// let results = DayXX::run_many(&["1721\n979", "oops", "366\n299"]);
+    ///
+    /// assert!(results[0].is_ok() && results[1].is_err() && results[2].is_ok());
+    /// println!("{}", SideBySide::new(&results));
+    /// ```
+    fn run_many(inputs: &[&str]) -> Vec<Result<SolutionResult<Self::P1, Self::P2>>> {
+        inputs
+            .iter()
+            .map(|input| Self::run_with_input(input))
+            .collect()
+    }
+
+    /// Runs the day `n` times, at least once, eg: to benchmark it casually.
+    ///
+    /// The durations of the result are the means of the runs, its answers the first run's.
+    /// Answers differing between runs are reported as [SolutionError::Nondeterministic].
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = u32; type P1 = u32; type P2 = u32;
+    ///#     fn get_input() -> Result<String> { Ok("21".to_owned()) }
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(input.parse()?) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(input * 2) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// let result = DayXX::run_repeated(10).expect("Day should run");
+    ///
+    /// assert_eq!(result.view().part1.as_deref(), Some("42"));
+    /// ```
+    fn run_repeated(n: usize) -> Result<SolutionResult<Self::P1, Self::P2>> {
+        let first = Self::run()?;
+        let answers = |result: &SolutionResult<Self::P1, Self::P2>| {
+            format!("{:?} / {:?}", result.part1, result.part2)
+        };
+        let mut durations = first.durations();
+
+        for run in 2..=n {
+            let result = Self::run()?;
+            if answers(&result) != answers(&first) {
+                return Err(SolutionError::Nondeterministic {
+                    run,
+                    first: answers(&first),
+                    other: answers(&result),
+                });
+            }
+
+            for (total, duration) in durations.iter_mut().zip(result.durations()) {
+                *total += duration;
+            }
+        }
+
+        let runs = u32::try_from(n.max(1)).unwrap_or(u32::MAX);
+        let [parse_duration, part1_duration, part2_duration] = durations.map(|total| total / runs);
+
+        Ok(SolutionResult {
+            parse_duration,
+            part1_duration,
+            part2_duration,
+            ..first
+        })
+    }
+
+    /// Runs the day `iterations` times, at least once, and appends the median durations of its
+    /// phases to the benchmark history at `path`. (See [crate::bench])
+    ///
+    /// A total median more than `threshold` percent slower than the previous benchmark of the day
+    /// is warned about, and returned as the [crate::bench::Regression] of the report.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = u32; type P1 = u32; type P2 = u32;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(input.parse()?) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(input * 2) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// // GIT_COMMIT=$(git rev-parse --short HEAD) cargo run --release
+    /// let report = DayXX::bench_and_record(20, "bench/history.csv", 10.0).expect("Day should run");
+    ///
+    /// if let Some(regression) = report.regression {
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    fn bench_and_record(
+        iterations: usize,
+        path: impl AsRef<Path>,
+        threshold: f64,
+    ) -> Result<crate::bench::BenchReport> {
+        let mut durations = [(); 3].map(|_| Vec::with_capacity(iterations.max(1)));
+        for _ in 0..iterations.max(1) {
+            for (phase, duration) in durations.iter_mut().zip(Self::run()?.durations()) {
+                phase.push(duration);
+            }
+        }
+
+        let [parse, part1, part2] = durations.map(|mut phase| crate::bench::median(&mut phase));
+        let record = crate::bench::BenchRecord {
+            year: Self::YEAR,
+            day: Self::DAY,
+            commit: crate::bench::commit(),
+            median: Timings {
+                parse,
+                part1,
+                part2,
+            },
+        };
+
+        let report = crate::bench::record(path.as_ref(), record, threshold)?;
+        if let Some(regression) = &report.regression {
+            logging::warn(format_args!(
+                "Day {} - {:?} is {}",
+                Self::DAY,
+                Self::TITLE,
+                regression
+            ));
+        }
+
+        Ok(report)
+    }
+
+    /// Same as [Solution::run], but a part without an answer is a [SolutionError::MissingAnswer],
+    /// eg: to fail a CI job on an unsolved part.
+    ///
+    /// A part whose [Solution::PART1] / [Solution::PART2] is [PartStatus::Skipped] may have no
+    /// answer. When both parts are missing, part 1 is reported. The answer of the other part and
+    /// the durations are kept in the error.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    /// use aoc::solution::{PartStatus, SolutionError};
+    ///# use aoc::solution::Result;
+    ///
+    /// struct Day25;
+    /// impl Solution for Day25 {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 25;
+    ///#     type Input = u32; type P1 = u32; type P2 = u32;
+    ///#     fn get_input() -> Result<String> { Ok("21".to_owned()) }
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(input.parse()?) }
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(input * 2) }
+    ///     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    /// }
+    ///
+    /// match Day25::run_strict() {
+    ///     Err(SolutionError::MissingAnswer { part, partial }) => {
+    ///         assert_eq!(part, 2);
+    ///         assert_eq!(partial.part1.as_deref(), Some("42"));
+    ///     }
+    ///     _ => panic!("part 2 is not solved"),
+    /// }
+    /// ```
+    ///
+    /// With `const PART2: PartStatus = PartStatus::Skipped;`, the same day runs fine.
+    fn run_strict() -> Result<SolutionResult<Self::P1, Self::P2>>
+    where
+        Self::P1: AnswerDisplay,
+        Self::P2: AnswerDisplay,
+    {
+        Self::run()?.solved(Self::PART1, Self::PART2)
+    }
+
+    /// [Solution::run_par] version of [Solution::run_strict].
+    fn run_par_strict() -> Result<SolutionResult<Self::P1, Self::P2>>
+    where
+        Self::P1: AnswerDisplay,
+        Self::P2: AnswerDisplay,
+    {
+        Self::run_par()?.solved(Self::PART1, Self::PART2)
+    }
+
+    /// Captured Solution runner
+    ///
+    /// Same as [Solution::run], but what the day prints with [crate::print!] and
+    /// [crate::println!] is stored in [SolutionResult::output] instead. (See [crate::capture])
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::{println, Solution};
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
+    ///
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///         println!("debugging part 1");
+    ///         Some(42)
+    ///     }
+    /// }
+    ///
+    /// let result = DayXX::run_captured().expect("Day should run");
+    ///
+    /// assert_eq!(result.output(), "debugging part 1\n");
+    /// ```
+    fn run_captured() -> Result<SolutionResult<Self::P1, Self::P2>> {
+        let (result, output) = capture::captured(Self::run);
+
+        result.map(|result| SolutionResult { output, ..result })
+    }
+
+    /// Runs the solution and returns both answers formatted with [AnswerDisplay],
+    /// `None` for an unsolved part.
+    ///
+    /// Days with different answer types can then be collected in a single report.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = usize; type P2 = String;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(42) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
+    /// }
+    ///
+    /// let (part1, part2) = DayXX::answers_as_strings().expect("Day should run");
+    ///
+    /// println!("| {:02} | {} | {} |", DayXX::DAY, part1.as_deref().unwrap_or("-"), part2.as_deref().unwrap_or("-"));
+    ///# assert_eq!(part1.as_deref(), Some("42"));
+    ///# assert_eq!(part2, None);
+    /// ```
+    fn answers_as_strings() -> Result<(Option<String>, Option<String>)>
+    where
+        Self::P1: AnswerDisplay,
+        Self::P2: AnswerDisplay,
+    {
+        let view = Self::run()?.view();
+
+        Ok((view.part1, view.part2))
+    }
+
+    /// Runs the day then submits its answers with the [crate::client::find_session_for] session.
+    ///
+    /// Only available with the `fetch` feature. See [Solution::run_and_submit_all_with]
+    #[cfg(feature = "fetch")]
+    fn run_and_submit_all() -> Result<SolutionResult<Self::P1, Self::P2>>
+    where
+        Self::P1: AnswerDisplay,
+        Self::P2: AnswerDisplay,
+    {
+        let session = crate::client::find_session_for(Self::YEAR).ok_or_else(|| {
+            SolutionError::Network(format!(
+                "no session cookie: set {}",
+                crate::client::SESSION_ENV
+            ))
+        })?;
+
+        Self::run_and_submit_all_with(&crate::client::Client::from_session(session))
+    }
+
+    /// Runs the day then submits its answers with `client`.
+    ///
+    /// Part 1 is submitted first, and part 2 only once part 1 is accepted (correct, or already
+    /// solved). A wrong part 1 stops there. Each step is printed as it happens, and the outcomes
+    /// are displayed next to the answers. (See [SolutionResult::submitted])
+    ///
+    /// [Solution::YEAR] must be set. Only available with the `fetch` feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use aoc::client::Client;
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 1;
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { Ok(()) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(24000) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(45000) }
+    ///     const YEAR: Option<u16> = Some(2022);
+    /// }
+    ///
+    /// let result = DayXX::run_and_submit_all_with(&Client::new("<session cookie>"))
+    ///     .expect("Day should run");
+    ///
+    /// println!("{}", result); // Part 1: '24000' (submitted: correct)...
+    /// ```
+    #[cfg(feature = "fetch")]
+    fn run_and_submit_all_with(
+        client: &crate::client::Client,
+    ) -> Result<SolutionResult<Self::P1, Self::P2>>
+    where
+        Self::P1: AnswerDisplay,
+        Self::P2: AnswerDisplay,
+    {
+        use crate::client::SubmitOutcome;
+
+        let year = Self::YEAR.ok_or_else(|| {
+            SolutionError::RefusedAnswer(format!(
+                "the year of day {:02} is unknown, set Solution::YEAR",
+                Self::DAY
+            ))
+        })?;
+
+        let mut result = Self::run()?;
+        let view = result.view();
+
+        let part1 = submit_part(client, year, Self::DAY, 1, &view.part1, view.part1_duration)?;
+        let part2 = match &part1 {
+            Some(SubmitOutcome::Correct | SubmitOutcome::AlreadySolved) => {
+                submit_part(client, year, Self::DAY, 2, &view.part2, view.part2_duration)?
+            }
+            _ => {
+                logging::info(format_args!(
+                    "Day {:02}: part 1 not accepted, part 2 not submitted",
+                    Self::DAY
+                ));
+                None
+            }
+        };
+
+        result.submitted = (
+            part1.as_ref().map(ToString::to_string),
+            part2.as_ref().map(ToString::to_string),
+        );
+
+        Ok(result)
+    }
+
+    /// Same as [Solution::run], also returning the [Timeline] of the run: the offset and the
+    /// duration of each stage, from a single [std::time::Instant] taken at the start.
+    ///
+    /// # Example
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = u32; type P1 = u32; type P2 = u32;
+    ///#     fn parse(input: &str) -> Result<Self::Input> { aoc::solution::parse_fromstr(input) }
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(input + 1) }
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> { Some(input * 2) }
+    ///#     fn get_input() -> Result<String> { Ok("21".to_owned()) }
+    /// }
+    ///
+    /// let (result, timeline) = DayXX::run_timeline().expect("Day should run");
+    ///
+    /// assert_eq!(result.view().part2.as_deref(), Some("42"));
+    /// assert!(timeline.part2.offset >= timeline.part1.end());
+    /// println!("{}", timeline);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn run_timeline() -> Result<(SolutionResult<Self::P1, Self::P2>, Timeline)> {
+        phase::run_span(Self::DAY, Self::YEAR).in_scope(|| {
+            let mut timeline = Timeline::new();
+
+            let (input, stage) = timeline.stage(Self::get_input);
+            timeline.input = stage;
+            let prepared = prepare::<Self>(input?)?;
+
+            let (parsed, stage) = timeline.stage(|| parse_input::<Self>(&prepared.input));
+            timeline.parse = stage;
+            let (parsed, parse_time) = parsed?;
+
+            let (part1, stage) = timeline.stage(|| solve_part1::<Self>(&parsed));
+            timeline.part1 = stage;
+            let (part2, stage) = timeline.stage(|| solve_part2::<Self>(&parsed));
+            timeline.part2 = stage;
+
+            let result = finish::<Self>(Some(&prepared), parse_time, (part1, part2))?;
+
+            Ok((result, timeline))
+        })
+    }
+
+    /// Streaming Solution runner
+    ///
+    /// Same as [Solution::run] but the input file ([Solution::input_path]) is opened as a
+    /// buffered reader and given to [Solution::parse_reader] instead of being read to a String.
+    ///
+    /// Days that don't override [Solution::parse_reader] behave exactly like [Solution::run].
+    ///
+    /// Example
+    /// -------
+    /// ```no_run
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = usize; type P2 = usize;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(0)
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         Some(0)
+    ///#     }
+    /// }
+    ///
+    /// let solution = DayXX::run_streaming().expect("Day should run");
+    /// println!("{}", solution);
+    /// ```
+    fn run_streaming() -> Result<SolutionResult<Self::P1, Self::P2>> {
+        phase::run_span(Self::DAY, Self::YEAR).in_scope(|| {
+            let path = Self::input_path()?;
+            let mut reader = BufReader::new(input::open(&path)?);
+            input::skip_bom(&mut reader)?;
+
+            let (input, parse_time) = phase::timed(Self::DAY, Self::YEAR, Phase::Parse, || {
+                Self::parse_reader(reader)
+            });
+            if input.is_err() {
+                debug::reduce_on_parse_error::<Self>(|| std::fs::read_to_string(&path).ok());
+            }
+
+            finish::<Self>(None, parse_time, solve::<Self>(&input?))
+        })
+    }
+
+    /// Parallel Solution runner
+    ///
+    /// Runs [Solution::part1] and [Solution::part2] in parallel to optimize execution speed
+    ///
+    /// On a single CPU, the threads can't pay off and this falls back to [Solution::run].
+    ///
+    /// The threads are named after the day and part, eg: `day7-part2`, so they can be told
+    /// apart in the panic messages and the profilers.
+    ///
+    /// See [Solution::run] for reference
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// use aoc::Solution;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = ();type P1 = usize;type P2 = usize;
+    ///#
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok(())
+    ///#         }
+    ///#
+    ///#     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///#         Some(0)
+    ///#     }
+    ///#
+    ///#     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///#         Some(0)
+    ///#     }
+    /// }
+    ///
+    /// fn run_solution() {
+    ///     match DayXX::run_par() {
+    ///         Ok(solution) => println!("{}", solution),
+    ///         Err(e) => println!("Failed to solve day: {}", e)
+    ///     }   
+    /// }
+    ///
+    /// ```    
+    fn run_par() -> Result<SolutionResult<Self::P1, Self::P2>> {
+        if single_cpu() {
+            Self::run()
+        } else {
+            run_threads::<Self>()
+        }
+    }
+
+    /// Same as [Solution::run_par], running both parts on a rayon `pool`.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// The parts run within [rayon::ThreadPool::install], so the rayon iterators they use
+    /// share the pool: once the cheap part is done, the heavy one gets every thread.
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// use aoc::Solution;
+    /// use rayon::prelude::*;
+    ///# use aoc::solution::Result;
+    ///
+    /// struct DayXX;
+    /// impl Solution for DayXX {
+    ///     // -- snip --
+    ///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+    ///#     type Input = Vec<u64>;type P1 = usize;type P2 = u64;
+    ///#
+    ///#     fn get_input() -> Result<String> { Ok("input".to_owned()) }
+    ///#     fn parse(input: &str) -> Result<Self::Input> {
+    ///#         Ok((0..1_000).collect())
+    ///#     }
+    ///#
+    ///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+    ///         Some(input.len())
+    ///     }
+    ///
+    ///     fn part2(input: &Self::Input) -> Option<Self::P2> {
+    ///         Some(input.par_iter().map(|n| n * n).sum())
+    ///     }
+    /// }
+    ///
+    /// let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    /// let solution = DayXX::run_par_with(&pool).unwrap();
+    ///
+    /// assert_eq!(solution.view().part2.as_deref(), Some("332833500"));
+    /// ```
+    #[cfg(feature = "rayon")]
+    fn run_par_with(pool: &rayon::ThreadPool) -> Result<SolutionResult<Self::P1, Self::P2>> {
+        let span = phase::run_span(Self::DAY, Self::YEAR);
+
+        span.in_scope(|| {
+            let prepared = prepare::<Self>(Self::get_input()?)?;
+            let (input, parse_time) = parse_input::<Self>(&prepared.input)?;
+
+            let (solve1, solve2) = pool.install(|| {
+                rayon::join(
+                    || {
+                        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            span.in_scope(|| solve_part1::<Self>(&input))
+                        }))
+                    },
+                    || {
+                        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            span.in_scope(|| solve_part2::<Self>(&input))
+                        }))
+                    },
+                )
+            });
+            let solved = (
+                solve1.map_err(|panic| Panicked::in_part(panic, 1))?,
+                solve2.map_err(|panic| Panicked::in_part(panic, 2))?,
+            );
+
+            finish::<Self>(Some(&prepared), parse_time, solved)
+        })
+    }
+}
+
+/// Parses an input with its [FromStr] implementation, for a [Solution::parse] that is only
+/// `input.parse()`. The error is reported as a [SolutionError::ParseError] naming the type.
+///
+/// See [crate::implement_fromstr!] to generate the whole [Solution::parse].
+///
+/// # Example
+/// ```
+/// use std::str::FromStr;
+/// use aoc::solution::{parse_fromstr, Result, SolutionError};
+/// use aoc::Solution;
+///
+/// struct Report(Vec<u32>);
+/// impl FromStr for Report {
+///     type Err = std::num::ParseIntError;
+///
+///     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+///         s.lines().map(str::parse).collect::<std::result::Result<_, _>>().map(Report)
+///     }
+/// }
+///
+/// struct DayXX;
+/// impl Solution for DayXX {
+///     // -- snip --
+///#     const TITLE: &'static str = "";const DAY: u8 = 0;
+///#     type P1 = usize; type P2 = usize;
+///#     fn part1(input: &Self::Input) -> Option<Self::P1> { Some(input.0.len()) }
+///#     fn part2(input: &Self::Input) -> Option<Self::P2> { None }
+///     type Input = Report;
+///
+///     fn parse(input: &str) -> Result<Self::Input> {
+///         parse_fromstr(input)
+///     }
+/// }
+///
+/// assert_eq!(DayXX::parse("1721\n979").unwrap().0, vec![1721, 979]);
+/// assert!(matches!(DayXX::parse("NaN"), Err(SolutionError::ParseError { .. })));
+/// ```
+pub fn parse_fromstr<T: FromStr>(input: &str) -> Result<T> {
+    input
+        .parse()
+        .map_err(|_| SolutionError::parse(format!("not a valid {}", type_name::<T>())))
+}
+
+/// Parses a time budget in humantime syntax (`50ms`, `1s 500ms`), quoted or not.
+/// Used by [crate::test_budget!]
+///
+/// # Panics
+/// When `budget` is not a valid duration.
+pub fn parse_budget(budget: &str) -> Duration {
+    let budget = budget.trim().trim_matches('"');
+
+    humantime::parse_duration(budget)
+        .unwrap_or_else(|e| panic!("Invalid time budget {:?}: {}", budget, e))
+}
+
+/// Runs both parts of `S` in their own thread. See [Solution::run_par]
+fn run_threads<S: Solution + ?Sized>() -> Result<SolutionResult<S::P1, S::P2>> {
+    let span = phase::run_span(S::DAY, S::YEAR);
+
+    span.in_scope(|| {
+        let prepared = prepare::<S>(S::get_input()?)?;
+        let (input, parse_time) = parse_input::<S>(&prepared.input)?;
+
+        let scope = crossbeam_utils::thread::scope(|s| {
+            let solve1 = s
+                .builder()
+                .name(part_thread(S::DAY, 1))
+                .spawn(|_| span.in_scope(|| solve_part1::<S>(&input)));
+            let solve2 = s
+                .builder()
+                .name(part_thread(S::DAY, 2))
+                .spawn(|_| span.in_scope(|| solve_part2::<S>(&input)));
+
+            let solve1 = solve1.map(|solve| solve.join());
+            let solve2 = solve2.map(|solve| solve.join());
+
+            (solve1, solve2)
+        })
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+
+        let solved = (joined(scope.0, 1)?, joined(scope.1, 2)?);
+
+        finish::<S>(Some(&prepared), parse_time, solved)
+    })
+}
+
+/// Name of the thread running a part in [Solution::run_par], eg: `day7-part2`, shown in the
+/// panic messages and the profilers.
+fn part_thread(day: u8, part: u8) -> String {
+    format!("day{}-part{}", day, part)
+}
+
+/// The answer of a part run on its own thread, or why it has none: the thread failed to spawn
+/// or panicked.
+fn joined<T>(solve: std::io::Result<std::thread::Result<T>>, part: u8) -> Result<T> {
+    match solve {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(panic)) => Err(Panicked::in_part(panic, part)),
+        Err(e) => Err(SolutionError::Part {
+            part,
+            source: Arc::new(e),
+        }),
+    }
+}
+
+fn check_not_empty<S: Solution + ?Sized>(input: &str) -> Result<()> {
+    if S::ALLOW_EMPTY_INPUT || !input.trim().is_empty() {
+        return Ok(());
+    }
+
+    let path_or_source = match S::input_path() {
+        Ok(path) => path.display().to_string(),
+        Err(_) => "get_input()".to_owned(),
+    };
+
+    Err(SolutionError::EmptyInput { path_or_source })
+}
+
+/// Compares an input read from [Solution::input_path] with its recorded checksum.
+/// (See [input::verify_checksum])
+///
+/// A mismatch is a loud warning, or an error with `AOC_STRICT_INPUT=1`.
+/// Returns whether the checksum is still to be recorded.
+fn check_integrity<S: Solution + ?Sized>(input: &str) -> Result<bool> {
+    if !S::input_path().is_ok_and(|path| path.is_file()) {
+        return Ok(false);
+    }
+
+    match input::verify_checksum(S::YEAR, S::DAY, input) {
+        Ok(recorded) => Ok(!recorded),
+        Err(e) if input::strict_input() => Err(e),
+        Err(e) => {
+            logging::warn(format_args!(
+                "WARNING: {}\n  Is this the input of another day? Set {}=1 to fail instead, \
+                 or remove the day from {} once the input is fixed.",
+                e,
+                input::STRICT_INPUT_ENV,
+                input::CHECKSUMS_FILE
+            ));
+            Ok(false)
+        }
+    }
+}
+
+/// Records the checksum of an input after its first successful run. See [check_integrity]
+fn record_integrity<S: Solution + ?Sized>(unrecorded: bool, input: &str) {
+    if !unrecorded {
+        return;
+    }
+
+    if let Err(e) = input::record_checksum(S::YEAR, S::DAY, input) {
+        logging::warn(format_args!(
+            "Note: couldn't record the input checksum of day {}: {}",
+            S::DAY,
+            e
+        ));
+    }
+}
+
+/// [Solution::TITLE], or the title of the puzzle page when empty. See [crate::client::fetch_title]
+fn title<S: Solution + ?Sized>() -> &'static str {
+    #[cfg(feature = "download")]
+    if let (true, Some(year), 1..=25) = (S::TITLE.is_empty(), S::YEAR, S::DAY) {
+        return crate::client::display_title(year, S::DAY);
+    }
+
+    S::TITLE
+}
+
+fn parse<S: Solution + ?Sized>(input: &str) -> (Result<S::Input>, Duration) {
+    if S::IDENTITY_PARSE {
+        (S::parse_ctx(input, S::DAY, S::YEAR), Duration::ZERO)
+    } else {
+        phase::timed(S::DAY, S::YEAR, Phase::Parse, || {
+            S::parse_ctx(input, S::DAY, S::YEAR)
+        })
+    }
+}
+
+/// Submits the answer of a part, printing each step. `None` when there is no answer.
+#[cfg(feature = "fetch")]
+fn submit_part(
+    client: &crate::client::Client,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Option<String>,
+    duration: Duration,
+) -> Result<Option<crate::client::SubmitOutcome>> {
+    let Some(answer) = answer else {
+        logging::info(format_args!(
+            "Day {:02}: no part {} answer to submit",
+            day, part
+        ));
+        return Ok(None);
+    };
+
+    logging::info(format_args!(
+        "Day {:02}: submitting part {} '{}' (solved in {})",
+        day,
+        part,
+        answer,
+        format_duration(duration)
+    ));
+    let outcome = client.submit(year, day, part, answer)?;
+    logging::info(format_args!("Day {:02}: part {} is {}", day, part, outcome));
+
+    Ok(Some(outcome))
+}
+
+/// The answers of both parts, with their durations.
+type Solved<P1, P2> = ((Option<P1>, Duration), (Option<P2>, Duration));
+
+/// An input read by a runner, checked by [prepare].
+struct Prepared {
+    input: String,
+    /// Whether the checksum of the input is still to be recorded. See [check_integrity]
+    unrecorded: bool,
+}
+
+/// Checks an input read by [Solution::get_input]: it isn't empty, and it matches its recorded
+/// checksum.
+fn prepare<S: Solution + ?Sized>(input: String) -> Result<Prepared> {
+    check_not_empty::<S>(&input)?;
+    let unrecorded = check_integrity::<S>(&input)?;
+
+    Ok(Prepared { input, unrecorded })
+}
+
+/// Parses an input, writing a reduced repro of it when it doesn't parse.
+/// (See [debug::reduce_input])
+fn parse_input<S: Solution + ?Sized>(input: &str) -> Result<(S::Input, Duration)> {
+    match parse::<S>(input) {
+        (Ok(parsed), parse_time) => Ok((parsed, parse_time)),
+        (Err(e), _) => {
+            debug::reduce_on_parse_error::<S>(|| Some(input.to_owned()));
+            crate::error::keep_failed_input(S::YEAR, S::DAY, &e, input);
+            Err(e)
+        }
+    }
+}
+
+fn solve_part1<S: Solution + ?Sized>(input: &S::Input) -> (Option<S::P1>, Duration) {
+    phase::timed(S::DAY, S::YEAR, Phase::Part1, || {
+        S::part1_iter(input).next()
+    })
+}
+
+fn solve_part2<S: Solution + ?Sized>(input: &S::Input) -> (Option<S::P2>, Duration) {
+    phase::timed(S::DAY, S::YEAR, Phase::Part2, || S::part2(input))
+}
+
+/// Solves both parts sequentially on an already parsed input.
+fn solve<S: Solution + ?Sized>(input: &S::Input) -> Solved<S::P1, S::P2> {
+    (solve_part1::<S>(input), solve_part2::<S>(input))
+}
+
+/// The result of a run, without its known answers.
+fn unverified<S: Solution + ?Sized>(
+    parse_duration: Duration,
+    ((part1, part1_duration), (part2, part2_duration)): Solved<S::P1, S::P2>,
+) -> SolutionResult<S::P1, S::P2> {
+    SolutionResult {
+        title: title::<S>(),
+        day: S::DAY,
+        parse_duration,
+        part1,
+        part1_duration,
+        part2,
+        part2_duration,
+        verified: Default::default(),
+        expected: ExpectedAnswers::default(),
+        output: String::new(),
+        submitted: (None, None),
+    }
+}
+
+/// Ends a run: records the checksum of its `prepared` input, checks the answers with
+/// [Solution::validate_answers] and attaches the known ones.
+fn finish<S: Solution + ?Sized>(
+    prepared: Option<&Prepared>,
+    parse_duration: Duration,
+    solved: Solved<S::P1, S::P2>,
+) -> Result<SolutionResult<S::P1, S::P2>> {
+    if let Some(prepared) = prepared {
+        record_integrity::<S>(prepared.unrecorded, &prepared.input);
+    }
+
+    validated::<S>(SolutionResult {
+        expected: expected_answers::<S>(),
+        ..unverified::<S>(parse_duration, solved)
+    })
+}
+
+/// Checks the answers of `result` with [Solution::validate_answers].
+fn validated<S: Solution + ?Sized>(
+    result: SolutionResult<S::P1, S::P2>,
+) -> Result<SolutionResult<S::P1, S::P2>> {
+    S::validate_answers(&result.part1, &result.part2).map_err(|e| match e {
+        SolutionError::InvalidAnswers(_) => e,
+        e => SolutionError::InvalidAnswers(e.to_string()),
+    })?;
+
+    Ok(result)
+}
+
+/// Known answers of the day: [Solution::EXPECTED_P1] and [Solution::EXPECTED_P2] when set,
+/// or else the answers file unless [Solution::VERIFY_ANSWERS] is off. See [crate::answers]
+fn expected_answers<S: Solution + ?Sized>() -> ExpectedAnswers {
+    expected_answers_in::<S>(Path::new(answers::ANSWERS_DIR))
+}
+
+fn expected_answers_in<S: Solution + ?Sized>(dir: &Path) -> ExpectedAnswers {
+    let file = if S::VERIFY_ANSWERS {
+        ExpectedAnswers::load(dir, S::DAY, S::YEAR).unwrap_or_else(|e| {
+            logging::warn(format_args!(
+                "Note: the known answers of day {} are unreadable: {}",
+                S::DAY,
+                e
+            ));
+            ExpectedAnswers::default()
+        })
+    } else {
+        ExpectedAnswers::default()
+    };
+
+    ExpectedAnswers {
+        part1: S::EXPECTED_P1.map(str::to_owned).or(file.part1),
+        part2: S::EXPECTED_P2.map(str::to_owned).or(file.part2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::env_lock;
+
+    struct Demo;
+    impl Solution for Demo {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = usize;
+        type P1 = usize;
+        type P2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input * 2)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(input.to_string())
+        }
+    }
+
+    static FLAKY_RUNS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+    struct Flaky<const FLAKY: bool>;
+    impl<const FLAKY: bool> Solution for Flaky<FLAKY> {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn get_input() -> Result<String> {
+            Ok("21".to_owned())
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input * 2)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            if !FLAKY {
+                return Some(*input);
+            }
+
+            Some(input + FLAKY_RUNS.fetch_add(1, std::sync::atomic::Ordering::Relaxed) % 2)
+        }
+    }
+
+    #[test]
+    fn repeated_runs() {
+        let result = Flaky::<false>::run_repeated(5).unwrap().view();
+        assert_eq!(result.part1.as_deref(), Some("42"));
+        assert_eq!(result.part2.as_deref(), Some("21"));
+        assert!(Flaky::<false>::run_repeated(0).is_ok());
+
+        let error = Flaky::<true>::run_repeated(5).err().unwrap();
+        assert!(matches!(
+            error,
+            SolutionError::Nondeterministic { run: 2, .. }
+        ));
+        assert!(error.to_string().starts_with("Answers differ between runs"));
+    }
+
+    /// Part `n` answers when its mode is 0, has no answer when it is 1, and is skipped when 2.
+    struct Strict<const P1: u8, const P2: u8>;
+    impl<const P1: u8, const P2: u8> Solution for Strict<P1, P2> {
+        const TITLE: &'static str = "Strict";
+        const DAY: u8 = 25;
+        const PART1: PartStatus = Self::status(P1);
+        const PART2: PartStatus = Self::status(P2);
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn get_input() -> Result<String> {
+            Ok("21".to_owned())
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            (P1 == 0).then_some(input * 2)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            (P2 == 0).then_some(input * 3)
+        }
+    }
+
+    impl<const P1: u8, const P2: u8> Strict<P1, P2> {
+        const fn status(mode: u8) -> PartStatus {
+            match mode {
+                2 => PartStatus::Skipped,
+                _ => PartStatus::Implemented,
+            }
+        }
+
+        /// The unsolved part reported by both strict runners, if any.
+        fn unsolved() -> Option<(u8, ResultView)> {
+            let strict = Self::run_strict();
+            let par_strict = Self::run_par_strict();
+
+            match (strict, par_strict) {
+                (Ok(_), Ok(_)) => None,
+                (
+                    Err(SolutionError::MissingAnswer { part, partial }),
+                    Err(SolutionError::MissingAnswer { part: par_part, .. }),
+                ) => {
+                    assert_eq!(part, par_part);
+                    Some((part, *partial))
+                }
+                (strict, par_strict) => panic!(
+                    "unexpected outcomes: {:?} / {:?}",
+                    strict.err(),
+                    par_strict.err()
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn strict_runs() {
+        assert!(Strict::<0, 0>::unsolved().is_none());
+        assert!(Strict::<0, 2>::unsolved().is_none());
+        assert!(Strict::<2, 0>::unsolved().is_none());
+        assert!(Strict::<2, 2>::unsolved().is_none());
+
+        let (part, partial) = Strict::<0, 1>::unsolved().unwrap();
+        assert_eq!(part, 2);
+        assert_eq!(partial.part1.as_deref(), Some("42"));
+        assert_eq!(partial.part2, None);
+
+        let (part, partial) = Strict::<1, 0>::unsolved().unwrap();
+        assert_eq!(part, 1);
+        assert_eq!(partial.part2.as_deref(), Some("63"));
+
+        assert_eq!(Strict::<1, 1>::unsolved().unwrap().0, 1);
+        assert_eq!(Strict::<1, 2>::unsolved().unwrap().0, 1);
+        assert_eq!(Strict::<2, 1>::unsolved().unwrap().0, 2);
+
+        let error = Strict::<1, 1>::run_strict().err().unwrap();
+        assert_eq!(error.to_string(), "No answer for part 1 of day 25");
+        assert_eq!(
+            error.hint().as_deref(),
+            Some("Solution::part1 returned None: solve it, or set PART1 to PartStatus::Skipped")
+        );
+        assert!(Strict::<1, 1>::run().is_ok());
+    }
+
+    #[test]
+    fn solution_core() {
+        use crate::solution_core::SolutionCore;
+
+        let answers = <Flaky<false> as SolutionCore>::solve("5").unwrap();
+        assert_eq!((answers.part1, answers.part2), (Some(10), Some(5)));
+        assert_eq!(answers.timings, None);
+
+        let error = <Flaky<false> as SolutionCore>::solve("x").err().unwrap();
+        assert_eq!(error, ErrorKind::Parse);
+    }
+
+    #[test]
+    fn bench_history() {
+        let path = crate::test_util::temp_dir("bench").join("history.csv");
+
+        let first = Flaky::<false>::bench_and_record(3, &path, f64::INFINITY).unwrap();
+        assert_eq!(first.record.day, 0);
+        assert_eq!(first.regression, None);
+
+        let second = Flaky::<false>::bench_and_record(0, &path, f64::INFINITY).unwrap();
+        assert_eq!(second.regression, None);
+        assert_eq!(
+            crate::bench::read_history(&path).unwrap(),
+            [first.record, second.record]
+        );
+    }
+
+    #[test]
+    fn quiet_env_values() {
+        assert!(is_quiet(Some("1")));
+        assert!(is_quiet(Some("true")));
+        assert!(!is_quiet(Some("0")));
+        assert!(!is_quiet(Some("")));
+        assert!(!is_quiet(None));
+    }
+
+    #[test]
+    fn silent_variants_return_the_same_values() {
+        let (loud, _) = Demo::test_part1("21").unwrap();
+        let (silent, _) = Demo::test_part1_silent("21").unwrap();
+        assert_eq!(loud, silent);
+        assert_eq!(silent, Some(42));
+
+        let (loud, _) = Demo::test_part2("21").unwrap();
+        let (silent, _) = Demo::test_part2_silent("21").unwrap();
+        assert_eq!(loud, silent);
+        assert_eq!(silent, Some("21".to_owned()));
+
+        assert!(Demo::test_part1_silent("NaN").is_err());
+    }
+
+    #[test]
+    fn parse_error_message() {
+        let error = Demo::test_part1("NaN").unwrap_err();
+
+        assert!(
+            matches!(&error, SolutionError::ParseError { message, .. } if message == "not a number")
+        );
+        assert_eq!(error.to_string(), "Invalid Puzzle input: not a number");
+    }
+
+    #[test]
+    fn run_many_inputs() {
+        let results = Demo::run_many(&["21", "NaN", "4000\r\n", ""]);
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap().view().part1.as_deref(),
+            Some("42")
+        );
+        assert!(matches!(results[1], Err(SolutionError::ParseError { .. })));
+        assert_eq!(
+            results[2].as_ref().unwrap().view().part2.as_deref(),
+            Some("4000")
+        );
+        assert!(matches!(results[3], Err(SolutionError::EmptyInput { .. })));
+
+        let table = SideBySide::new(&results).to_string();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines[0], "Input | Part 1 | Part 2 | Time");
+        assert!(
+            lines[1].starts_with("#1    | 42     | 21     | "),
+            "{}",
+            table
+        );
+        assert_eq!(lines[2], "#2    | Invalid Puzzle input: not a number");
+        assert!(
+            lines[3].starts_with("#3    | 8000   | 4000   | "),
+            "{}",
+            table
+        );
+        assert_eq!(lines[4], "#4    | Empty Puzzle input: run_with_input()");
+    }
+
+    #[test]
+    fn single_cpu_detection() {
+        assert!(is_single_cpu(NonZeroUsize::new(1)));
+        assert!(!is_single_cpu(NonZeroUsize::new(8)));
+        assert!(!is_single_cpu(None));
+    }
+
+    #[test]
+    fn run_par_matches_run() {
+        struct Input;
+        impl Solution for Input {
+            const TITLE: &'static str = "";
+            const DAY: u8 = 0;
+            type Input = usize;
+            type P1 = usize;
+            type P2 = String;
+
+            fn parse(input: &str) -> Result<Self::Input> {
+                Demo::parse(input)
+            }
+
+            fn part1(input: &Self::Input) -> Option<Self::P1> {
+                Demo::part1(input)
+            }
+
+            fn part2(input: &Self::Input) -> Option<Self::P2> {
+                Demo::part2(input)
+            }
+
+            fn get_input() -> Result<String> {
+                Ok("21".to_owned())
+            }
+        }
+
+        let sequential = Input::run().unwrap().view();
+        let parallel = Input::run_par().unwrap().view();
+
+        assert_eq!(sequential.part1, parallel.part1);
+        assert_eq!(sequential.part2, parallel.part2);
+
+        #[cfg(feature = "rayon")]
+        {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap();
+            let pooled = Input::run_par_with(&pool).unwrap().view();
+
+            assert_eq!(sequential.part1, pooled.part1);
+            assert_eq!(sequential.part2, pooled.part2);
+        }
+    }
+
+    struct Crashing;
+    impl Solution for Crashing {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn get_input() -> Result<String> {
+            Ok("21".to_owned())
+        }
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.parse()?)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            panic!("boom on {}", input)
+        }
+    }
+
+    struct ThreadNames;
+    impl Solution for ThreadNames {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 7;
+        type Input = ();
+        type P1 = String;
+        type P2 = String;
+
+        fn get_input() -> Result<String> {
+            Ok("-".to_owned())
+        }
+
+        fn parse(_input: &str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part1(_input: &Self::Input) -> Option<Self::P1> {
+            std::thread::current().name().map(str::to_owned)
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            std::thread::current().name().map(str::to_owned)
+        }
+    }
+
+    #[test]
+    fn part_thread_names() {
+        let result = run_threads::<ThreadNames>().unwrap().view();
+
+        assert_eq!(result.part1.as_deref(), Some("day7-part1"));
+        assert_eq!(result.part2.as_deref(), Some("day7-part2"));
+    }
+
+    #[test]
+    fn part_errors() {
+        use std::error::Error;
+
+        let error = run_threads::<Crashing>().err().unwrap();
+        assert!(matches!(error, SolutionError::Part { part: 2, .. }));
+        assert_eq!(
+            error.to_string(),
+            "Error while running part 2: panicked: boom on 21"
+        );
+        assert_eq!(error.source().unwrap().to_string(), "panicked: boom on 21");
+        assert_eq!(
+            crate::logging::error_chain(&error),
+            "Error while running part 2: panicked: boom on 21"
+        );
+
+        #[cfg(feature = "rayon")]
+        {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap();
+            let error = Crashing::run_par_with(&pool).err().unwrap();
+
+            assert!(matches!(error, SolutionError::Part { part: 2, .. }));
+            assert!(error.to_string().ends_with("boom on 21"));
+        }
+    }
+
+    /// The kind of each variant, without a wildcard so that a new variant gets a case below.
+    #[allow(deprecated)]
+    fn expected_kind(error: &SolutionError) -> ErrorKind {
+        match error {
+            SolutionError::ParseError { .. }
+            | SolutionError::InvalidLine { .. }
+            | SolutionError::InvalidCell { .. }
+            | SolutionError::RaggedRow { .. }
+            | SolutionError::InvalidBlock { .. } => ErrorKind::Parse,
+            SolutionError::Located { source, .. } => expected_kind(source),
+            SolutionError::PuzzleInput { .. }
+            | SolutionError::MissingInput(_)
+            | SolutionError::EmptyInput { .. }
+            | SolutionError::InputTemplate(_)
+            | SolutionError::Encryption(_)
+            | SolutionError::InputChecksum { .. } => ErrorKind::Input,
+            SolutionError::Run
+            | SolutionError::Part { .. }
+            | SolutionError::Nondeterministic { .. } => ErrorKind::Run,
+            SolutionError::Network(_) | SolutionError::Keyring(_) => ErrorKind::Network,
+            SolutionError::WrongAnswer { .. }
+            | SolutionError::MissingAnswer { .. }
+            | SolutionError::InvalidAnswers(_)
+            | SolutionError::RefusedAnswer(_) => ErrorKind::Answer,
+            SolutionError::Skipped(_) => ErrorKind::Skipped,
+            SolutionError::Storage { .. } => ErrorKind::Storage,
+        }
+    }
+
+    #[test]
+    fn error_kinds() {
+        let parse = || Box::new(SolutionError::parse("odd"));
+        let io = || Arc::new(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        #[allow(deprecated)]
+        let run = SolutionError::Run;
+
+        let errors = [
+            *parse(),
+            SolutionError::InvalidLine {
+                line: 1,
+                text: "x".to_owned(),
+            },
+            SolutionError::InvalidCell {
+                line: 1,
+                column: 1,
+                found: 'x',
+            },
+            SolutionError::RaggedRow {
+                line: 2,
+                width: 1,
+                expected: 2,
+            },
+            SolutionError::InvalidBlock {
+                block: 1,
+                source: parse(),
+            },
+            SolutionError::Located {
+                location: crate::parse::Location::line(1),
+                source: parse(),
+            },
+            SolutionError::Located {
+                location: crate::parse::Location::line(1),
+                source: Box::new(SolutionError::Keyring("locked".to_owned())),
+            },
+            SolutionError::PuzzleInput {
+                path: "inputs/DAY_01.txt".into(),
+                source: io(),
+            },
+            SolutionError::MissingInput(input::MissingInput {
+                kind: InputKind::Real,
+                attempted: vec![],
+                cwd: "/aoc".into(),
+                created: None,
+            }),
+            SolutionError::EmptyInput {
+                path_or_source: "-".to_owned(),
+            },
+            run,
+            SolutionError::Part {
+                part: 1,
+                source: io(),
+            },
+            SolutionError::Network("offline".to_owned()),
+            SolutionError::InputTemplate("{dya}".to_owned()),
+            SolutionError::Keyring("locked".to_owned()),
+            SolutionError::Encryption("no key".to_owned()),
+            SolutionError::WrongAnswer {
+                day: 1,
+                part: 1,
+                expected: "1".to_owned(),
+                actual: "2".to_owned(),
+            },
+            SolutionError::Nondeterministic {
+                run: 2,
+                first: "1".to_owned(),
+                other: "2".to_owned(),
+            },
+            SolutionError::MissingAnswer {
+                part: 2,
+                partial: Box::new(Flaky::<false>::run().unwrap().view()),
+            },
+            SolutionError::InvalidAnswers("too high".to_owned()),
+            SolutionError::RefusedAnswer("already solved".to_owned()),
+            SolutionError::Skipped(crate::runner::SkipReason::NotYetUnlocked),
+            SolutionError::InputChecksum {
+                day: 1,
+                recorded: "a".to_owned(),
+                actual: "b".to_owned(),
+            },
+            SolutionError::Storage {
+                path: "bench.csv".into(),
+                source: io(),
+            },
+        ];
+
+        for error in errors {
+            let kind = expected_kind(&error);
+            assert_eq!(error, kind, "{}", error);
+            assert_eq!(kind, error);
+        }
+    }
+
+    #[test]
+    fn error_chains() {
+        let error = SolutionError::InvalidBlock {
+            block: 2,
+            source: Box::new(SolutionError::from("x".parse::<u32>().unwrap_err())),
+        };
+        assert_eq!(
+            crate::logging::error_chain(&error),
+            "Invalid Puzzle input in block 2: Invalid Puzzle input: invalid digit found in string"
+        );
+
+        let error = SolutionError::Part {
+            part: 1,
+            source: Arc::new(SolutionError::Located {
+                location: crate::parse::Location::line(3),
+                source: Box::new(SolutionError::parse("odd")),
+            }),
+        };
+        assert_eq!(
+            crate::logging::error_chain(&error),
+            "Error while running part 1: Invalid Puzzle input: odd at line 3"
+        );
+
+        let error = SolutionError::ParseError {
+            message: "bad size".to_owned(),
+            source: Some(Arc::new("x".parse::<u32>().unwrap_err())),
+        };
+        assert_eq!(
+            crate::logging::error_chain(&error),
+            "Invalid Puzzle input: bad size\n  caused by: invalid digit found in string"
+        );
+    }
+
+    struct Empty;
+    impl Solution for Empty {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(*input)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok(std::env::var("AOC_TEST_EMPTY_INPUT").unwrap_or_default())
+        }
+    }
+
+    struct AllowEmpty;
+    impl Solution for AllowEmpty {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        const ALLOW_EMPTY_INPUT: bool = true;
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Empty::parse(input)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(*input)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok(" \n".to_owned())
+        }
+    }
+
+    #[test]
+    fn empty_input_is_rejected() {
+        let _lock = env_lock();
+
+        for input in ["", " \n\t\n"] {
+            std::env::set_var("AOC_TEST_EMPTY_INPUT", input);
+            let sequential = Empty::run().map(|result| result.view());
+            let parallel = Empty::run_par().map(|result| result.view());
+            std::env::remove_var("AOC_TEST_EMPTY_INPUT");
+
+            assert!(matches!(sequential, Err(SolutionError::EmptyInput { .. })));
+            assert!(matches!(parallel, Err(SolutionError::EmptyInput { .. })));
+        }
+
+        assert_eq!(Empty::test_part1_silent("").unwrap().0, Some(0));
+    }
+
+    struct Shared;
+    impl Solution for Shared {
+        const TITLE: &'static str = "Shared";
+        const DAY: u8 = 12;
+        const YEAR: Option<u16> = Some(2021);
+        type Input = String;
+        type P1 = String;
+        type P2 = String;
+
+        fn parse(_input: &str) -> Result<Self::Input> {
+            Err(SolutionError::parse("parse_ctx only"))
+        }
+
+        fn parse_ctx(input: &str, day: u8, year: Option<u16>) -> Result<Self::Input> {
+            Ok(format!("{} {} {:?}", input, day, year))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input.clone())
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    #[test]
+    fn parse_ctx_gets_the_day() {
+        let result = Shared::run_with_input("input").unwrap();
+
+        assert_eq!(result.view().part1.as_deref(), Some("input 12 Some(2021)"));
+        assert_eq!(
+            Shared::test_part1_silent("example").unwrap().0.as_deref(),
+            Some("example 12 Some(2021)")
+        );
+    }
+
+    fn with_answers(content: Option<&str>) -> SolutionResult<usize, String> {
+        let dir = crate::test_util::temp_dir("known-answers");
+        if let Some(content) = content {
+            std::fs::write(dir.join("DAY_00.txt"), content).unwrap();
+        }
+
+        SolutionResult {
+            expected: ExpectedAnswers::load(&dir, 0, None).unwrap(),
+            ..Demo::run_with_input("21").unwrap()
+        }
+    }
+
+    #[test]
+    fn known_answers() {
+        let matching = with_answers(Some("42\n21  \n"));
+        assert_eq!(
+            matching.verification(),
+            (Verification::Verified, Verification::Verified)
+        );
+        assert_eq!(matching.verified(), Some((true, true)));
+        assert!(matching.to_string().contains("Part 2: '21' ✓\n"));
+
+        let mismatch = with_answers(Some("41\n21"));
+        assert_eq!(mismatch.verified(), Some((false, true)));
+        assert!(mismatch
+            .to_string()
+            .contains("Part 1: '42' ✗ (expected 41)\n"));
+
+        let partial = with_answers(Some("42"));
+        assert_eq!(
+            partial.verification(),
+            (Verification::Verified, Verification::Unknown)
+        );
+        assert_eq!(partial.verified(), None);
+        assert!(partial.to_string().contains("Part 2: '21'\n"));
+
+        let absent = with_answers(None);
+        assert_eq!(absent.verification(), Default::default());
+        assert!(!absent.to_string().contains('✓') && !absent.to_string().contains('✗'));
+    }
+
+    struct Pinned;
+    impl Solution for Pinned {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        const EXPECTED_P1: Option<&'static str> = Some("42");
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            input
+                .parse()
+                .map_err(|_| SolutionError::parse("not a number"))
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input * 2)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            Some(*input)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok("21".to_owned())
+        }
+    }
+
+    #[test]
+    fn expected_constants() {
+        let result = Pinned::run().unwrap();
+        assert_eq!(
+            result.verification().0,
+            Verification::Verified,
+            "EXPECTED_P1 is checked"
+        );
+
+        let dir = crate::test_util::temp_dir("expected-constants");
+        assert_eq!(
+            expected_answers_in::<Pinned>(&dir).part1.as_deref(),
+            Some("42")
+        );
+
+        std::fs::write(dir.join("DAY_00.txt"), "41\n21\n").unwrap();
+        let expected = expected_answers_in::<Pinned>(&dir);
+        assert_eq!(
+            expected.part1.as_deref(),
+            Some("42"),
+            "the constant wins over the answers file"
+        );
+        assert_eq!(
+            expected.part2.as_deref(),
+            Some("21"),
+            "the answers file fills the parts without a constant"
+        );
+    }
+
+    #[test]
+    fn strict_answers() {
+        let _lock = env_lock();
+
+        std::env::set_var(answers::STRICT_ANSWERS_ENV, "1");
+        let strict = with_answers(Some("41\n21")).checked();
+        let partial = with_answers(Some("42")).checked();
+        std::env::remove_var(answers::STRICT_ANSWERS_ENV);
+        let lenient = with_answers(Some("41\n21")).checked();
+
+        assert_eq!(
+            strict.err().unwrap().to_string(),
+            "Wrong answer for part 1 of day 00: got 42, expected 41"
+        );
+        assert!(partial.is_ok());
+        assert!(lenient.is_ok());
+    }
+
+    #[test]
+    fn error_hints() {
+        let missing = SolutionError::MissingInput(input::MissingInput {
+            kind: input::InputKind::Real,
+            attempted: vec!["inputs/DAY_07.txt".into(), "inputs/2022/DAY_07.txt".into()],
+            cwd: "/aoc".into(),
+            created: None,
+        });
+
+        assert_eq!(missing.hint().as_deref(), Some("Create inputs/DAY_07.txt"));
+        assert!(SolutionError::parse("not a number")
+            .hint()
+            .unwrap()
+            .starts_with("parse() failed"));
+        #[allow(deprecated)]
+        let run = SolutionError::Run;
+        assert!(run.hint().unwrap().contains("panicked"));
+        assert_eq!(SolutionError::Network("offline".to_owned()).hint(), None);
+    }
+
+    struct Checked<const INPUT: u32>;
+    impl<const INPUT: u32> Solution for Checked<INPUT> {
+        const TITLE: &'static str = "";
+        const DAY: u8 = 0;
+        type Input = u32;
+        type P1 = u32;
+        type P2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            parse_fromstr(input)
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(*input)
+        }
+
+        fn part2(input: &Self::Input) -> Option<Self::P2> {
+            input.checked_sub(1)
+        }
+
+        fn get_input() -> Result<String> {
+            Ok(INPUT.to_string())
+        }
+
+        fn validate_answers(p1: &Option<u32>, p2: &Option<u32>) -> Result<()> {
+            match (p1, p2) {
+                (_, None) => Err(SolutionError::parse("no part 2")),
+                (Some(p1), _) if *p1 > 100 => Err(SolutionError::InvalidAnswers(
+                    "part 1 is too high".to_owned(),
+                )),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn validated_answers() {
+        assert!(Checked::<42>::run().is_ok());
+        assert!(Checked::<42>::run_par().is_ok());
+        assert_eq!(
+            Checked::<420>::run().err().unwrap().to_string(),
+            "Answers failed validation: part 1 is too high"
+        );
+        assert!(matches!(
+            Checked::<420>::run_par(),
+            Err(SolutionError::InvalidAnswers(_))
+        ));
+        assert!(
+            matches!(
+                Checked::<0>::run(),
+                Err(SolutionError::InvalidAnswers(reason)) if reason == "Invalid Puzzle input: no part 2"
+            ),
+            "other errors are wrapped"
+        );
+    }
+
+    #[test]
+    fn timeline_offsets() {
+        let (result, timeline) = Checked::<42>::run_timeline().unwrap();
+        let stages = timeline.stages();
+
+        assert_eq!(result.view().part1.as_deref(), Some("42"));
+        for pair in stages.windows(2) {
+            let ((previous, before), (name, after)) = (pair[0], pair[1]);
+            assert!(
+                after.offset >= before.end(),
+                "{} starts before the end of {}",
+                name,
+                previous
+            );
+        }
+        assert!(timeline.total() >= timeline.part1.duration + timeline.part2.duration);
+        assert!(matches!(
+            Checked::<420>::run_timeline(),
+            Err(SolutionError::InvalidAnswers(_))
+        ));
+    }
+
+    #[test]
+    fn cloned_errors() {
+        let io = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        let message = SolutionError::from(std::io::Error::new(io.kind(), "denied")).to_string();
+        let results: Vec<Result<SolutionResult<usize, String>>> =
+            vec![Demo::run_with_input("21"), Err(io.into())];
+        let cloned = results.clone();
+
+        assert_eq!(
+            message,
+            "Missing Puzzle input at \"<unknown path>\": denied"
+        );
+        let error = cloned[1].as_ref().err().unwrap();
+        assert_eq!(error.io_kind(), Some(std::io::ErrorKind::PermissionDenied));
+        assert_eq!(error.path(), Some(Path::new(input::UNKNOWN_PATH)));
+        assert_eq!(
+            cloned[0].as_ref().unwrap().to_string(),
+            results[0].as_ref().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn std_parse_errors() {
+        use std::error::Error;
+
+        fn parse(input: &str) -> Result<(u32, f64, char, String)> {
+            let [int, float, code, bytes] = input.split(' ').collect::<Vec<_>>()[..] else {
+                return Err(SolutionError::parse("expected 4 fields"));
+            };
+
+            Ok((
+                int.parse()?,
+                float.parse()?,
+                char::try_from(code.parse::<u32>()?)?,
+                std::str::from_utf8(bytes.as_bytes())?.parse()?,
+            ))
+        }
+
+        assert_eq!(
+            parse("1 2.5 65 abc").unwrap(),
+            (1, 2.5, 'A', "abc".to_owned())
+        );
+
+        let error = parse("one 2.5 65 abc").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid Puzzle input: invalid digit found in string"
+        );
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "invalid digit found in string"
+        );
+
+        let source = |error: SolutionError| match error {
+            SolutionError::ParseError {
+                source: Some(source),
+                ..
+            } => source,
+            error => panic!("no source in {:?}", error),
+        };
+        assert!(source(parse("1 two 65 abc").unwrap_err()).is::<std::num::ParseFloatError>());
+        assert!(source(parse("1 2.5 55296 abc").unwrap_err()).is::<std::char::CharTryFromError>());
+
+        let mut invalid = b"a".to_vec();
+        invalid.push(0xff);
+        let error = SolutionError::from(std::str::from_utf8(&invalid).unwrap_err());
+        assert!(error.clone().source().is_some());
+        assert!(source(error).is::<std::str::Utf8Error>());
+
+        assert!(SolutionError::parse("no source").source().is_none());
+    }
+
+    #[test]
+    fn run_file() {
+        let dir = crate::test_util::temp_dir("run-file");
+        let path = dir.join("other.txt");
+        std::fs::write(&path, "21\r\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        assert_eq!(Demo::get_input_file(path).unwrap(), "21");
+        assert_eq!(
+            Demo::run_file(path).unwrap().view().part1.as_deref(),
+            Some("42")
+        );
+
+        std::fs::write(dir.join("empty.txt"), "").unwrap();
+        let empty = dir.join("empty.txt");
+        assert!(matches!(
+            Demo::run_file(empty.to_str().unwrap()),
+            Err(SolutionError::EmptyInput { path_or_source }) if path_or_source.ends_with("empty.txt")
+        ));
+
+        let missing = dir.join("missing.txt");
+        let error = Demo::run_file(missing.to_str().unwrap()).err().unwrap();
+        assert!(error.to_string().contains("missing.txt"), "{}", error);
+    }
+
+    #[test]
+    fn empty_input_opt_out() {
+        let result = AllowEmpty::run().unwrap().view();
+
+        assert_eq!(result.part1.as_deref(), Some("1"));
+    }
+
+    fn result(part2: u32, millis: [u64; 3]) -> SolutionResult<u32, u32> {
+        SolutionResult {
+            title: "",
+            day: 0,
+            part1: Some(10),
+            part2: Some(part2),
+            parse_duration: Duration::from_millis(millis[0]),
+            part1_duration: Duration::from_millis(millis[1]),
+            part2_duration: Duration::from_millis(millis[2]),
+            verified: Default::default(),
+            expected: ExpectedAnswers::default(),
+            output: String::new(),
+            submitted: (None, None),
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let before = result(10, [0, 100, 40]);
+        let after = result(12, [1, 68, 50]);
+
+        let diff = before.diff(&after);
+
+        assert!(!diff.is_unchanged());
+        assert_eq!(diff.part1.answer, AnswerDiff::Unchanged);
+        assert_eq!(diff.parse_time_delta, None);
+        assert_eq!(
+            diff.to_string(),
+            "parse: n/a time; part1: unchanged, -32% time; part2: CHANGED 10 -> 12, +25% time"
+        );
+        assert!(before.diff(&before).is_unchanged());
+    }
+
+    #[test]
+    fn budgets() {
+        assert_eq!(parse_budget("50ms"), Duration::from_millis(50));
+        assert_eq!(parse_budget("1s 500ms"), Duration::from_millis(1_500));
+        assert_eq!(parse_budget("\"2s\""), Duration::from_secs(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid time budget")]
+    fn invalid_budget() {
+        parse_budget("fast");
+    }
+
+    #[cfg(feature = "fetch")]
+    mod submit_all {
+        use super::*;
+        use crate::client::mock_server::serve;
+        use crate::client::Client;
+
+        const CORRECT: &str = "<main><article><p>That's the right answer!</p></article></main>";
+        const INCORRECT: &str =
+            "<main><article><p>That's not the right answer.</p></article></main>";
+
+        struct Submitted;
+        impl Solution for Submitted {
+            const TITLE: &'static str = "Submitted";
+            const DAY: u8 = 3;
+            const YEAR: Option<u16> = Some(2022);
+            type Input = usize;
+            type P1 = usize;
+            type P2 = usize;
+
+            fn parse(input: &str) -> Result<Self::Input> {
+                input
+                    .parse()
+                    .map_err(|_| SolutionError::parse("not a number"))
+            }
+
+            fn part1(input: &Self::Input) -> Option<Self::P1> {
+                Some(input * 2)
+            }
+
+            fn part2(input: &Self::Input) -> Option<Self::P2> {
+                Some(input * 3)
+            }
+
+            fn get_input() -> Result<String> {
+                Ok("21".to_owned())
+            }
+        }
+
+        fn client(name: &str, url: &str) -> Client {
+            Client::new(name)
+                .with_base_url(url)
+                .with_answers_dir(&crate::test_util::temp_dir(name))
+                .with_wait(false)
+        }
+
+        #[test]
+        fn submits_both_parts() {
+            let (url, requests) = serve(vec![(200, vec![], CORRECT), (200, vec![], CORRECT)]);
+
+            let result = Submitted::run_and_submit_all_with(&client("submit-all", &url)).unwrap();
+
+            assert_eq!(result.submitted(), (Some("correct"), Some("correct")));
+            assert_eq!(requests.recv().unwrap().body, "level=1&answer=42");
+            assert_eq!(requests.recv().unwrap().body, "level=2&answer=63");
+            assert!(result
+                .to_string()
+                .contains("Part 2: '63' (submitted: correct)\n"));
+        }
+
+        #[test]
+        fn stops_on_wrong_part1() {
+            let (url, requests) = serve(vec![(200, vec![], INCORRECT), (200, vec![], CORRECT)]);
+
+            let result =
+                Submitted::run_and_submit_all_with(&client("submit-all-wrong", &url)).unwrap();
+
+            assert_eq!(result.submitted(), (Some("wrong"), None));
+            assert_eq!(result.view().part2.as_deref(), Some("63"));
+            requests.recv().unwrap();
+            assert!(
+                requests.try_recv().is_err(),
+                "part 2 should not be submitted"
+            );
+            assert!(result
+                .to_string()
+                .contains("Part 1: '42' (submitted: wrong)\n"));
+        }
+
+        #[test]
+        fn skips_a_solved_part1() {
+            let (url, requests) = serve(vec![(200, vec![], CORRECT), (200, vec![], CORRECT)]);
+            let client = client("submit-all-again", &url);
+
+            client.submit(2022, 3, 1, "42").unwrap();
+            requests.recv().unwrap();
+            let result = Submitted::run_and_submit_all_with(&client).unwrap();
+
+            assert_eq!(
+                result.submitted(),
+                (Some("already solved"), Some("correct"))
+            );
+            assert_eq!(requests.recv().unwrap().body, "level=2&answer=63");
+            assert!(requests.try_recv().is_err(), "part 1 should not be posted");
         }
     }
 }
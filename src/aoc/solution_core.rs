@@ -0,0 +1,186 @@
+//! The solving part of a day, without files, clocks nor threads
+//!
+//! [SolutionCore] only parses an input and solves both parts, so it works in `no_std`, eg: to
+//! run a day on an embedded target. Disable the default `std` feature to keep only this module:
+//!
+//! ```toml
+//! aoc-runner = { version = "1", default-features = false }
+//! ```
+//!
+//! Every [crate::Solution] is also a [SolutionCore], whose error is a
+//! [crate::solution::SolutionError]. The durations are only measured with
+//! [SolutionCore::solve_timed], on the clock given to it.
+
+use core::time::Duration;
+
+/// Durations of the phases of a run. See [crate::solution::SolutionResult::into_parts]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    /// Duration of the whole run.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+/// Answers of [SolutionCore::solve], with the durations of [SolutionCore::solve_timed].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers<P1, P2> {
+    pub part1: Option<P1>,
+    pub part2: Option<P2>,
+    pub timings: Option<Timings>,
+}
+
+/// A day reduced to its input parsing and its two parts, available without `std`.
+///
+/// # Example
+/// ```
+/// use aoc::solution_core::SolutionCore;
+///
+/// struct Day01;
+/// impl SolutionCore for Day01 {
+///     type Input = [u32; 3];
+///     type P1 = u32;
+///     type P2 = u32;
+///     type Error = core::num::ParseIntError;
+///
+///     fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+///         let mut numbers = [0; 3];
+///         for (number, line) in numbers.iter_mut().zip(input.lines()) {
+///             *number = line.parse()?;
+///         }
+///         Ok(numbers)
+///     }
+///
+///     fn part1(input: &Self::Input) -> Option<Self::P1> {
+///         input.iter().copied().max()
+///     }
+///
+///     fn part2(input: &Self::Input) -> Option<Self::P2> {
+///         Some(input.iter().sum())
+///     }
+/// }
+///
+/// let answers = Day01::solve("3\n9\n4").unwrap();
+///
+/// assert_eq!((answers.part1, answers.part2, answers.timings), (Some(9), Some(16), None));
+/// ```
+pub trait SolutionCore {
+    /// Puzzle input type, the output of [SolutionCore::parse].
+    type Input;
+
+    /// Part 1 answer type.
+    type P1;
+
+    /// Part 2 answer type.
+    type P2;
+
+    /// Why the input doesn't parse.
+    type Error;
+
+    /// Parses the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    /// Solves part 1.
+    fn part1(input: &Self::Input) -> Option<Self::P1>;
+
+    /// Solves part 2.
+    fn part2(input: &Self::Input) -> Option<Self::P2>;
+
+    /// Parses `input` and solves both parts, without measuring them.
+    fn solve(input: &str) -> Result<Answers<Self::P1, Self::P2>, Self::Error> {
+        let input = Self::parse(input)?;
+
+        Ok(Answers {
+            part1: Self::part1(&input),
+            part2: Self::part2(&input),
+            timings: None,
+        })
+    }
+
+    /// Same as [SolutionCore::solve], measuring each phase with `now`, the time elapsed since
+    /// any fixed instant, eg: read from a hardware timer.
+    fn solve_timed(
+        input: &str,
+        mut now: impl FnMut() -> Duration,
+    ) -> Result<Answers<Self::P1, Self::P2>, Self::Error> {
+        let start = now();
+        let input = Self::parse(input)?;
+        let parsed = now();
+        let part1 = Self::part1(&input);
+        let solved1 = now();
+        let part2 = Self::part2(&input);
+        let solved2 = now();
+
+        Ok(Answers {
+            part1,
+            part2,
+            timings: Some(Timings {
+                parse: parsed.saturating_sub(start),
+                part1: solved1.saturating_sub(parsed),
+                part2: solved2.saturating_sub(solved1),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct Bytes;
+    impl SolutionCore for Bytes {
+        type Input = usize;
+        type P1 = usize;
+        type P2 = usize;
+        type Error = ();
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+            match input.len() {
+                0 => Err(()),
+                len => Ok(len),
+            }
+        }
+
+        fn part1(input: &Self::Input) -> Option<Self::P1> {
+            Some(input * 2)
+        }
+
+        fn part2(_input: &Self::Input) -> Option<Self::P2> {
+            None
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let answers = Bytes::solve("abc").unwrap();
+
+        assert_eq!(answers.part1, Some(6));
+        assert_eq!(answers.part2, None);
+        assert_eq!(answers.timings, None);
+        assert_eq!(Bytes::solve(""), Err(()));
+    }
+
+    #[test]
+    fn solve_timed() {
+        let ticks = Cell::new(0);
+        let now = || {
+            ticks.set(ticks.get() + 1);
+            Duration::from_millis(ticks.get() * ticks.get())
+        };
+
+        let timings = Bytes::solve_timed("abc", now).unwrap().timings.unwrap();
+
+        assert_eq!(timings.parse, Duration::from_millis(3));
+        assert_eq!(timings.part1, Duration::from_millis(5));
+        assert_eq!(timings.part2, Duration::from_millis(7));
+        assert_eq!(timings.total(), Duration::from_millis(15));
+    }
+}
@@ -0,0 +1,94 @@
+//! Timeline of a run: when each stage started and how long it took, from a single baseline
+//!
+//! Unlike the durations of [crate::solution::SolutionResult], the stages of a [Timeline] are
+//! placed relative to the same [Instant], so they can be lined up with external traces.
+//! (See [crate::Solution::run_timeline])
+
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// A stage of a [Timeline].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stage {
+    /// Time between the start of the timeline and the start of the stage.
+    pub offset: Duration,
+    pub duration: Duration,
+}
+
+impl Stage {
+    /// Time between the start of the timeline and the end of the stage.
+    pub fn end(&self) -> Duration {
+        self.offset + self.duration
+    }
+}
+
+/// Stages of a run, relative to [Timeline::start].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeline {
+    /// The baseline of the offsets, taken before reading the input.
+    pub start: Instant,
+    /// Reading the input.
+    pub input: Stage,
+    pub parse: Stage,
+    pub part1: Stage,
+    pub part2: Stage,
+}
+
+impl Timeline {
+    /// Empty timeline starting now.
+    pub fn new() -> Self {
+        Timeline {
+            start: Instant::now(),
+            input: Stage::default(),
+            parse: Stage::default(),
+            part1: Stage::default(),
+            part2: Stage::default(),
+        }
+    }
+
+    /// The stages by name, in order.
+    pub fn stages(&self) -> [(&'static str, Stage); 4] {
+        [
+            ("input", self.input),
+            ("parse", self.parse),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ]
+    }
+
+    /// Time between the start of the timeline and the end of the last stage.
+    pub fn total(&self) -> Duration {
+        self.part2.end()
+    }
+
+    /// Runs `f` as a stage starting now.
+    pub(crate) fn stage<T>(&self, f: impl FnOnce() -> T) -> (T, Stage) {
+        let offset = self.start.elapsed();
+        let started = Instant::now();
+        let result = f();
+
+        (
+            result,
+            Stage {
+                offset,
+                duration: started.elapsed(),
+            },
+        )
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Timeline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, stage) in self.stages() {
+            writeln!(f, "{:<8}+{:<12?}{:?}", name, stage.offset, stage.duration)?;
+        }
+
+        write!(f, "{:<8}{:?}", "total", self.total())
+    }
+}
@@ -0,0 +1,82 @@
+//! Encrypts every input of the input directory, so they can be committed.
+//!
+//! ```shell
+//! AOC_INPUT_KEY=<secret> aoc-encrypt-inputs [<input directory>]
+//! ```
+//!
+//! Each `.txt` file gets an encrypted `.txt.enc` copy next to it. (See [aoc::input::encrypt_file])
+//! The directory defaults to `AOC_INPUT_DIR`, or `inputs`.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc::input;
+
+const USAGE: &str = "usage: aoc-encrypt-inputs [<input directory>]";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let dir = match (args.next(), args.next()) {
+        (None, _) => input::input_dir(),
+        (Some(dir), None) if !dir.starts_with('-') => PathBuf::from(dir),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let key = match input::input_key() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            eprintln!(
+                "No key: set {} or {}",
+                input::INPUT_KEY_ENV,
+                input::INPUT_KEY_FILE_ENV
+            );
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut inputs = Vec::new();
+    if let Err(e) = collect_inputs(&dir, &mut inputs) {
+        eprintln!("{}: {}", dir.display(), e);
+        return ExitCode::FAILURE;
+    }
+    inputs.sort();
+
+    let mut failed = false;
+    for path in inputs {
+        match input::encrypt_file(&path, &key) {
+            Ok(encrypted) => println!("{}", encrypted.display()),
+            Err(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Plain `.txt` inputs of `dir` and its sub directories.
+fn collect_inputs(dir: &Path, inputs: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_inputs(&path, inputs)?;
+        } else if path.extension().is_some_and(|extension| extension == "txt") {
+            inputs.push(path);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+//! Manages the Advent of Code session cookie stored in the OS keyring.
+//!
+//! ```shell
+//! aoc-session set [--year <year>] [<session cookie>]   # reads stdin without a cookie
+//! aoc-session show [--year <year>]
+//! aoc-session clear [--year <year>]
+//! ```
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use aoc::auth;
+
+const USAGE: &str = "usage: aoc-session <set|show|clear> [--year <year>] [<session cookie>]";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let command = args.next();
+    let mut year = None;
+    let mut token = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => match args.next().and_then(|year| year.parse().ok()) {
+                Some(value) => year = Some(value),
+                None => return usage(),
+            },
+            _ if token.is_none() => token = Some(arg),
+            _ => return usage(),
+        }
+    }
+
+    let result = match command.as_deref() {
+        Some("set") => {
+            let token = match token {
+                Some(token) => token,
+                None => {
+                    let mut token = String::new();
+                    if let Err(e) = std::io::stdin().read_to_string(&mut token) {
+                        eprintln!("{}", e);
+                        return ExitCode::FAILURE;
+                    }
+                    token
+                }
+            };
+
+            auth::store_session_for(year, &token)
+                .map(|_| println!("Session stored for {}", auth::user(year)))
+        }
+        Some("show") if token.is_none() => auth::load_session_for(year).map(|token| match token {
+            Some(token) => println!("{}", token),
+            None => eprintln!("No session stored for {}", auth::user(year)),
+        }),
+        Some("clear") if token.is_none() => auth::clear_session_for(year)
+            .map(|_| println!("Session cleared for {}", auth::user(year))),
+        _ => return usage(),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{}", USAGE);
+    ExitCode::from(2)
+}
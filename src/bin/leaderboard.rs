@@ -0,0 +1,42 @@
+//! Prints the standings of a private leaderboard.
+//!
+//! ```shell
+//! leaderboard <year> <board id>
+//! ```
+//!
+//! The board id is the number at the end of the leaderboard's URL. The session of the year is
+//! used (See `aoc::client::find_session_for`), and the standings are cached for 15 minutes.
+
+use std::process::ExitCode;
+
+use aoc::client;
+
+const USAGE: &str = "usage: leaderboard <year> <board id>";
+
+fn main() -> ExitCode {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+
+    let (year, board_id) = match args.as_slice() {
+        [year, board_id] => match (year.parse(), board_id.parse()) {
+            (Ok(year), Ok(board_id)) => (year, board_id),
+            _ => return usage(),
+        },
+        _ => return usage(),
+    };
+
+    match client::fetch_leaderboard(year, board_id) {
+        Ok(leaderboard) => {
+            print!("{}", leaderboard);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn usage() -> ExitCode {
+    eprintln!("{}", USAGE);
+    ExitCode::from(2)
+}
@@ -0,0 +1,82 @@
+//! Inputs embedded with `aoc::embed`, using the fixtures of `tests/inputs`.
+#![cfg(feature = "embed-inputs")]
+
+use std::path::Path;
+use std::process::Command;
+
+use aoc::solution::Result;
+use aoc::Solution;
+
+struct Day07;
+impl Solution for Day07 {
+    const TITLE: &'static str = "";
+    const DAY: u8 = 7;
+    const YEAR: Option<u16> = Some(2022);
+    type Input = Vec<String>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(input.len())
+    }
+
+    fn part2(_input: &Self::Input) -> Option<Self::P2> {
+        None
+    }
+}
+
+fn fixtures(year: Option<u16>, day: u8) -> Option<&'static str> {
+    match (year, day) {
+        (None, 7) => Some(include_str!("inputs/DAY_07.txt")),
+        (Some(2022), 1) => Some(include_str!("inputs/2022/DAY_01.txt")),
+        _ => None,
+    }
+}
+
+#[test]
+fn generated_lookup_compiles() {
+    let out_dir = std::env::temp_dir().join(format!("aoc-runner-embed-{}", std::process::id()));
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/inputs");
+
+    let generated = aoc::embed::generate_from(&inputs, &out_dir, 1024).unwrap();
+    std::fs::write(
+        out_dir.join("main.rs"),
+        r#"include!("embedded_inputs.rs");
+
+fn main() {
+    assert_eq!(embedded_input(None, 7), Some("$ cd /\r\n$ ls\r\n"));
+    assert_eq!(embedded_input(Some(2022), 1), Some("1000\n2000\n\n3000\n"));
+    assert_eq!(embedded_input(None, 1), None);
+}
+"#,
+    )
+    .unwrap();
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let binary = out_dir.join("embedded");
+    let status = Command::new(rustc)
+        .args(["--edition", "2021", "-o"])
+        .arg(&binary)
+        .arg(out_dir.join("main.rs"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "{} should compile", generated.display());
+    assert!(Command::new(&binary).status().unwrap().success());
+
+    let _ = std::fs::remove_dir_all(&out_dir);
+}
+
+#[test]
+fn get_input_reads_embedded_inputs() {
+    aoc::embed::register(fixtures);
+
+    assert_eq!(aoc::embed::embedded(Some(2022), 1), fixtures(Some(2022), 1));
+    assert_eq!(aoc::embed::embedded(None, 1), None);
+    assert_eq!(Day07::get_input().unwrap(), "$ cd /\n$ ls");
+    assert_eq!(Day07::run().unwrap().view().part1.as_deref(), Some("2"));
+}
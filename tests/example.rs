@@ -0,0 +1,107 @@
+//! Tests generated by `aoc::example!`.
+#![cfg(feature = "std")]
+
+use aoc::input::InputKind;
+use aoc::solution::Result;
+use aoc::Solution;
+
+/// Answers the day and year its input was parsed for.
+struct Context;
+impl Solution for Context {
+    const TITLE: &'static str = "Context";
+    const DAY: u8 = 2;
+    const YEAR: Option<u16> = Some(2019);
+    type Input = (u8, Option<u16>);
+    type P1 = u8;
+    type P2 = u16;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_ctx(input, Self::DAY, Self::YEAR)
+    }
+
+    fn parse_ctx(_input: &str, day: u8, year: Option<u16>) -> Result<Self::Input> {
+        Ok((day, year))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(input.0)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::P2> {
+        input.1
+    }
+}
+
+aoc::example! {
+    [Context]
+    default: "1,0,0,0,99" => Some(2) => Some(2019)
+    day_only { day: 5 }: "3,0,4,0,99" => Some(5) => Some(2019)
+    day_and_year { day: 9, year: 2021, }: "109,1,204,-1" => Some(9) => Some(2021)
+    year_only { year: 2022 }: "" => Some(2)
+}
+
+/// Reads its example file, as its real input.
+struct Doubled;
+impl Solution for Doubled {
+    const TITLE: &'static str = "Doubled";
+    const DAY: u8 = 3;
+    type Input = u32;
+    type P1 = u32;
+    type P2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(input * 2)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::P2> {
+        Some(input * 4)
+    }
+
+    fn get_input_kind(kind: InputKind) -> Result<String> {
+        match kind {
+            InputKind::Example => Ok("21".to_owned()),
+            _ => Self::get_input(),
+        }
+    }
+}
+
+/// Same, tested in another module: each day's example file gets its own.
+struct Tripled;
+impl Solution for Tripled {
+    const TITLE: &'static str = "Tripled";
+    const DAY: u8 = 4;
+    type Input = u32;
+    type P1 = u32;
+    type P2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(input * 3)
+    }
+
+    fn part2(_input: &Self::Input) -> Option<Self::P2> {
+        None
+    }
+
+    fn get_input_kind(kind: InputKind) -> Result<String> {
+        match kind {
+            InputKind::Example => Ok("14".to_owned()),
+            _ => Self::get_input(),
+        }
+    }
+}
+
+aoc::example! {
+    [Doubled] => Some(42) => Some(84)
+}
+
+aoc::example! {
+    [Tripled] => Some(42)
+}
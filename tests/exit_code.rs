@@ -0,0 +1,76 @@
+//! Exit codes of the binaries made with `aoc::run!`, using the `exit_code_fixture` example.
+#![cfg(feature = "std")]
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// The fixture, built by `cargo test` along with the other examples, or here when only this test
+/// is built, eg: `cargo test --test exit_code`.
+fn fixture() -> PathBuf {
+    let examples = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .and_then(|deps| deps.parent())
+        .unwrap()
+        .join("examples");
+    let fixture = examples.join(format!("exit_code_fixture{}", std::env::consts::EXE_SUFFIX));
+
+    if !fixture.exists() {
+        let status = Command::new(env!("CARGO"))
+            .args(["build", "--example", "exit_code_fixture"])
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .status()
+            .unwrap();
+        assert!(status.success(), "the fixture doesn't build");
+    }
+
+    fixture
+}
+
+/// Runs the fixture in an empty directory.
+fn run_fixture(args: &[&str]) -> Output {
+    let dir = std::env::temp_dir().join(format!("aoc-runner-exit-code-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    Command::new(fixture())
+        .args(args)
+        .current_dir(&dir)
+        .env_remove("AOC_FORMAT")
+        .env_remove("AOC_STRICT_ANSWERS")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn failing_day_exits_with_1() {
+    let missing: PathBuf = ["missing", "DAY_00.txt"].iter().collect();
+    let output = run_fixture(&["--input", missing.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error: Missing Puzzle input"), "{}", stderr);
+}
+
+#[test]
+fn unsolved_strict_day_exits_with_1() {
+    let input =
+        std::env::temp_dir().join(format!("aoc-runner-unsolved-{}.txt", std::process::id()));
+    std::fs::write(&input, "102").unwrap();
+    let output = run_fixture(&["--input", input.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("error: No answer for part 2 of day 00"),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn solved_day_exits_with_0() {
+    let output = run_fixture(&[]);
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("120"));
+}
@@ -0,0 +1,17 @@
+//! Binary run by `tests/exit_code.rs` to check the exit codes of `aoc::run!`.
+//!
+//! Part 2 has no answer when the input holds a `0`.
+
+use aoc::OptionExt;
+
+aoc::implement! {
+    name: Day00;
+    title: "addition or product";
+    day: 0;
+    input : "12345".to_owned();
+    parse   -> Vec<u32> : |input: &str| input.chars().map(|c| c.to_digit(10).ok_or_parse("not a digit")).collect();
+    part_1  -> u32      : |input: &Self::Input| Some(input.iter().sum());
+    part_2  -> u32      : |input: &Self::Input| Some(input.iter().product()).filter(|&product| product != 0);
+}
+
+aoc::run!(Day00, strict);
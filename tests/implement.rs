@@ -0,0 +1,45 @@
+//! Compile-pass tests of the days written with `aoc::implement!`.
+#![cfg(feature = "std")]
+
+aoc::implement! {
+    name: Day01;
+    title: "Chronal Calibration";
+    day: 1;
+    input : "+1\n-2\n+3\n+1".to_owned();
+    parse   -> aoc::Lines<i64>;
+    part_1  -> i64      : |input: &Self::Input| Some(input.iter().sum());
+    part_2  -> usize    : |input: &Self::Input| Some(input.len());
+}
+
+#[test]
+fn implement_default_parse() {
+    let result = Day01::run().unwrap().view();
+
+    assert_eq!(result.part1.as_deref(), Some("3"));
+    assert_eq!(result.part2.as_deref(), Some("4"));
+    assert_eq!(
+        Day01::parse("+1\n\n+2\nx").unwrap_err().to_string(),
+        "Invalid Puzzle input at line 4: \"x\""
+    );
+}
+
+// `implement!` imports `Solution`, so a second day needs its own module
+mod part1_only {
+    aoc::implement! {
+        name: Day02;
+        title: "Part 1 only";
+        day: 2;
+        input : "1\n2".to_owned();
+        parse   -> aoc::Lines<u32>;
+        part_1  -> u32      : |input: &Self::Input| input.iter().max().copied();
+    }
+
+    #[test]
+    fn implement_without_part2() {
+        let result = Day02::run().unwrap();
+
+        assert_eq!(result.view().part1.as_deref(), Some("2"));
+        assert_eq!(result.view().part2, None);
+        assert_eq!(Day02::part2(&Day02::parse("1").unwrap()), None);
+    }
+}
@@ -0,0 +1,56 @@
+//! Compile-pass tests of the inputs embedded with `aoc::include_input!`,
+//! using the fixtures of `tests/inputs`.
+#![cfg(feature = "std")]
+
+use aoc::solution::Result;
+use aoc::Solution;
+
+struct Day07;
+impl Solution for Day07 {
+    const TITLE: &'static str = "";
+    const DAY: u8 = 7;
+    type Input = Vec<String>;
+    type P1 = usize;
+    type P2 = usize;
+
+    aoc::include_input!(dir "tests/inputs", day 7);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::P1> {
+        Some(input.len())
+    }
+
+    fn part2(_input: &Self::Input) -> Option<Self::P2> {
+        None
+    }
+}
+
+#[test]
+fn include_input() {
+    assert_eq!(Day07::get_input().unwrap(), "$ cd /\n$ ls");
+}
+
+/// `implement!` imports `Solution` itself, so it gets a module of its own.
+mod implemented {
+    use aoc::solution::SolutionError;
+    use aoc::Solution as _;
+
+    aoc::implement! {
+        name: Day01;
+        title: "Calorie Counting";
+        day: 1;
+        input_file: dir "tests/inputs", year 2022, day 1;
+        parse   -> Vec<u32> : |input: &str| input.split("\n\n").map(|elf| elf.lines().map(|l| l.parse::<u32>().map_err(|_| SolutionError::parse("not a number"))).sum()).collect();
+        part_1  -> u32      : |input: &Self::Input| input.iter().copied().max();
+        part_2  -> u32      : |_input: &Self::Input| None;
+    }
+
+    #[test]
+    fn implement_input_file() {
+        assert_eq!(Day01::get_input().unwrap(), "1000\n2000\n\n3000");
+        assert_eq!(Day01::run().unwrap().view().part1.as_deref(), Some("3000"));
+    }
+}